@@ -156,6 +156,27 @@ pub trait Context {
         Self::Mark::default()
     }
 
+    /// Return a checkpoint of the diagnostics which have been collected by the
+    /// context.
+    ///
+    /// This is used when a failed decoding attempt can be recovered from, like
+    /// when decoding the variants of an untagged enum, so that the diagnostics
+    /// of the attempt can be discarded through [`Context::restore`].
+    #[inline(always)]
+    fn checkpoint(&self) -> Checkpoint {
+        Checkpoint::default()
+    }
+
+    /// Restore the context to a checkpoint returned by
+    /// [`Context::checkpoint`], discarding every diagnostic collected since.
+    ///
+    /// Returns the first discarded diagnostic if the context collects them.
+    #[allow(unused_variables)]
+    #[inline(always)]
+    fn restore(&self, checkpoint: Checkpoint) -> Option<Self::String<'_>> {
+        None
+    }
+
     /// Report that an invalid variant tag was encountered.
     #[inline(always)]
     fn invalid_variant_tag<T>(&self, _: &'static str, tag: &T) -> Self::Error
@@ -189,6 +210,24 @@ pub trait Context {
         self.message(format_args!("Invalid field tag {tag:?}"))
     }
 
+    /// Encountered an unsupported field tag, where `expected` contains the tags
+    /// of every field which is recognized.
+    ///
+    /// By default this calls [`Context::invalid_field_tag`] with a tag whose
+    /// debug representation also lists the expected tags.
+    #[inline(always)]
+    fn invalid_field_tag_expected<T>(
+        &self,
+        name: &'static str,
+        tag: &T,
+        expected: &[&dyn fmt::Debug],
+    ) -> Self::Error
+    where
+        T: ?Sized + fmt::Debug,
+    {
+        self.invalid_field_tag(name, &ExpectedFieldTag { tag, expected })
+    }
+
     /// Expected another field to decode.
     #[inline(always)]
     fn expected_field_adjacent<T, C>(&self, _: &'static str, tag: &T, content: &C) -> Self::Error
@@ -234,6 +273,20 @@ pub trait Context {
         self.message(format_args!("Missing variant tag"))
     }
 
+    /// None of the variants of an untagged enum could be decoded, where
+    /// `errors` contains the name of each variant which was attempted together
+    /// with the error it produced and the first diagnostic it reported as
+    /// returned by [`Context::restore`].
+    #[allow(clippy::type_complexity)]
+    #[inline(always)]
+    fn untagged_mismatch(
+        &self,
+        name: &'static str,
+        errors: &[(&'static str, Self::Error, Option<Self::String<'_>>)],
+    ) -> Self::Error {
+        self.message(UntaggedMismatch { name, errors })
+    }
+
     /// Encountered an unsupported variant field.
     #[allow(unused_variables)]
     #[inline(always)]
@@ -433,3 +486,99 @@ pub trait Context {
     #[inline(always)]
     fn leave_sequence_index(&self) {}
 }
+
+/// A checkpoint of the diagnostics collected by a [`Context`].
+///
+/// See [`Context::checkpoint`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint {
+    errors: usize,
+    depth: usize,
+}
+
+impl Checkpoint {
+    /// Construct a checkpoint from the number of collected errors and the
+    /// depth of the traced path.
+    #[inline]
+    pub const fn new(errors: usize, depth: usize) -> Self {
+        Self { errors, depth }
+    }
+
+    /// The number of collected errors.
+    #[inline]
+    pub const fn errors(&self) -> usize {
+        self.errors
+    }
+
+    /// The depth of the traced path.
+    #[inline]
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+/// Lists the diagnostic reported by each attempted variant of an untagged
+/// enum.
+struct UntaggedMismatch<'a, E, S> {
+    name: &'static str,
+    errors: &'a [(&'static str, E, Option<S>)],
+}
+
+impl<E, S> fmt::Display for UntaggedMismatch<'_, E, S>
+where
+    S: AsRef<str>,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Data did not match any variant of untagged enum {}",
+            self.name
+        )?;
+
+        for (index, (variant, _, message)) in self.errors.iter().enumerate() {
+            let sep = if index == 0 { ": " } else { ", " };
+
+            match message {
+                Some(message) => write!(f, "{sep}variant `{variant}`: {}", message.as_ref())?,
+                None => write!(f, "{sep}variant `{variant}`")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Lists the expected fields when an invalid field is encountered.
+struct ExpectedFieldTag<'a, T>
+where
+    T: ?Sized,
+{
+    tag: &'a T,
+    expected: &'a [&'a dyn fmt::Debug],
+}
+
+impl<T> fmt::Debug for ExpectedFieldTag<'_, T>
+where
+    T: ?Sized + fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.tag.fmt(f)?;
+
+        if self.expected.is_empty() {
+            return write!(f, ", expected no fields");
+        }
+
+        for (index, tag) in self.expected.iter().enumerate() {
+            let sep = if index == 0 {
+                ", expected one of "
+            } else {
+                ", "
+            };
+            write!(f, "{sep}{tag:?}")?;
+        }
+
+        Ok(())
+    }
+}
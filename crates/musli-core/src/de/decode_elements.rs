@@ -0,0 +1,43 @@
+use super::{DecodeWith, Decoder};
+
+/// Trait governing how a container is decoded when each of its elements is
+/// decoded through a [`DecodeWith`] adapter.
+///
+/// This is used through the `#[musli(with_elements = <path>)]` attribute of
+/// the [`Decode` derive], and is implemented for [`Option`] and standard
+/// sequences and sets.
+///
+/// [`Decode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+pub trait DecodeElements<'de, M>: Sized {
+    /// The type of an element in the container.
+    type Element;
+
+    /// Decode the container, where each element is decoded through `W`.
+    fn decode_elements<W, D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        W: ?Sized + DecodeWith<'de, M, Self::Element>,
+        D: Decoder<'de, Mode = M>;
+}
+
+/// Trait governing how a map is decoded when its keys and values are decoded
+/// through [`DecodeWith`] adapters.
+///
+/// This is used through the `#[musli(with_keys = <path>)]` and
+/// `#[musli(with_values = <path>)]` attributes of the [`Decode` derive], and
+/// is implemented for standard maps.
+///
+/// [`Decode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+pub trait DecodeEntries<'de, M>: Sized {
+    /// The type of a key in the map.
+    type Key;
+    /// The type of a value in the map.
+    type Value;
+
+    /// Decode the map, where each key is decoded through `K` and each value
+    /// through `V`.
+    fn decode_entries<K, V, D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        K: ?Sized + DecodeWith<'de, M, Self::Key>,
+        V: ?Sized + DecodeWith<'de, M, Self::Value>,
+        D: Decoder<'de, Mode = M>;
+}
@@ -0,0 +1,79 @@
+use crate::de::Decoder;
+use crate::Context;
+
+/// Trait governing how a type is decoded when it's flattened into the map of
+/// an enclosing container.
+///
+/// Entries are streamed from the enclosing map. Any entry whose name isn't
+/// claimed by the enclosing container is offered to the flattened value
+/// through [`DecodeFlatten::flatten_tag`], and if accepted its value is
+/// decoded into the partial [`DecodeFlatten::State`]. Once the map has been
+/// exhausted the value is constructed through
+/// [`DecodeFlatten::decode_flatten_finish`].
+///
+/// The type parameter `N` is the type of field names used by the enclosing
+/// container, like `str` for text modes or `usize` for binary modes.
+///
+/// This is used automatically through the `#[musli(flatten)]` attribute
+/// through the [`Decode` derive], which implements this trait for structs
+/// marked with `#[musli(flattenable)]`.
+///
+/// [`Decode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use musli::Decode;
+///
+/// #[derive(Decode)]
+/// #[musli(flattenable, name_all = "name")]
+/// struct Metadata {
+///     created: u64,
+///     updated: u64,
+/// }
+///
+/// #[derive(Decode)]
+/// #[musli(name_all = "name")]
+/// struct Document {
+///     id: u32,
+///     #[musli(flatten)]
+///     metadata: Metadata,
+///     #[musli(flatten)]
+///     other: HashMap<String, u32>,
+/// }
+/// ```
+pub trait DecodeFlatten<'de, M, N>: Sized
+where
+    N: ?Sized,
+{
+    /// A tag identifying an entry accepted by this value.
+    type Tag;
+    /// The partially decoded state of this value.
+    type State;
+
+    /// Construct the initial state of a flattened value.
+    fn flatten_state() -> Self::State;
+
+    /// Test if the field with the given name belongs to this value.
+    fn flatten_tag<C>(cx: &C, name: &N) -> Result<Option<Self::Tag>, C::Error>
+    where
+        C: ?Sized + Context<Mode = M>;
+
+    /// Decode the value of an entry previously accepted through
+    /// [`DecodeFlatten::flatten_tag`].
+    fn decode_flatten_entry<D>(
+        cx: &D::Cx,
+        state: &mut Self::State,
+        tag: Self::Tag,
+        decoder: D,
+    ) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>;
+
+    /// Construct the value once all entries have been decoded.
+    fn decode_flatten_finish<C>(cx: &C, state: Self::State) -> Result<Self, C::Error>
+    where
+        C: ?Sized + Context<Mode = M>;
+}
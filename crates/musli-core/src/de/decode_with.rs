@@ -0,0 +1,50 @@
+use super::Decoder;
+
+/// Trait governing how a value of type `T` is decoded through an adapter.
+///
+/// This is used to decode the elements, keys or values of containers through
+/// a custom `decode` function rather than through their [`Decode`]
+/// implementation, such as through the `#[musli(with_elements = <path>)]`
+/// attribute of the [`Decode` derive].
+///
+/// [`Decode`]: crate::Decode
+/// [`Decode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Decode, Decoder};
+/// use musli::de::{DecodeElements, DecodeWith};
+///
+/// struct Doubled;
+///
+/// impl<'de, M> DecodeWith<'de, M, u32> for Doubled {
+///     fn decode_with<D>(_: &D::Cx, decoder: D) -> Result<u32, D::Error>
+///     where
+///         D: Decoder<'de, Mode = M>,
+///     {
+///         Ok(decoder.decode_u32()? * 2)
+///     }
+/// }
+///
+/// struct Numbers {
+///     values: Vec<u32>,
+/// }
+///
+/// impl<'de, M> Decode<'de, M> for Numbers {
+///     fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+///     where
+///         D: Decoder<'de, Mode = M>,
+///     {
+///         Ok(Self {
+///             values: Vec::decode_elements::<Doubled, D>(cx, decoder)?,
+///         })
+///     }
+/// }
+/// ```
+pub trait DecodeWith<'de, M, T> {
+    /// Decode a value using the given decoder.
+    fn decode_with<D>(cx: &D::Cx, decoder: D) -> Result<T, D::Error>
+    where
+        D: Decoder<'de, Mode = M>;
+}
@@ -1470,6 +1470,65 @@ pub trait Decoder<'de>: Sized {
         )))
     }
 
+    /// Decode a number in its exact textual representation.
+    ///
+    /// This is used by arbitrary-precision number types to avoid going through
+    /// a lossy fixed-size representation. Formats which have a textual number
+    /// representation, like JSON, provide the number exactly as it appears in
+    /// the input. Other formats decode it as a string, which is the default
+    /// behavior.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt;
+    ///
+    /// use musli::{Context, Decode, Decoder};
+    /// use musli::de::UnsizedVisitor;
+    ///
+    /// struct Price {
+    ///     exact: String,
+    /// }
+    ///
+    /// impl<'de, M> Decode<'de, M> for Price {
+    ///     #[inline]
+    ///     fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    ///     where
+    ///         D: Decoder<'de>,
+    ///     {
+    ///         struct Visitor;
+    ///
+    ///         impl<'de, C> UnsizedVisitor<'de, C, str> for Visitor
+    ///         where
+    ///             C: ?Sized + Context,
+    ///         {
+    ///             type Ok = String;
+    ///
+    ///             #[inline]
+    ///             fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///                 write!(f, "exact number")
+    ///             }
+    ///
+    ///             #[inline]
+    ///             fn visit_ref(self, _: &C, number: &str) -> Result<Self::Ok, C::Error> {
+    ///                 Ok(number.to_owned())
+    ///             }
+    ///         }
+    ///
+    ///         Ok(Self {
+    ///             exact: decoder.decode_raw_number(Visitor)?,
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn decode_raw_number<V>(self, visitor: V) -> Result<V::Ok, <Self::Cx as Context>::Error>
+    where
+        V: UnsizedVisitor<'de, Self::Cx, str>,
+    {
+        self.decode_string(visitor)
+    }
+
     /// Decode dynamically through a [`Visitor`].
     #[inline]
     fn decode_any<V>(self, visitor: V) -> Result<V::Ok, <Self::Cx as Context>::Error>
@@ -29,6 +29,19 @@ pub trait EntryDecoder<'de> {
         SizeHint::any()
     }
 
+    /// Test if entries which are not recognized by the type being decoded
+    /// should be rejected rather than skipped.
+    ///
+    /// This is consulted by derived implementations of [`Decode`], where an
+    /// unrecognized entry results in a
+    /// [`Context::invalid_field_tag_expected`] error.
+    ///
+    /// [`Decode`]: crate::Decode
+    #[inline]
+    fn deny_unknown_fields(&self) -> bool {
+        false
+    }
+
     /// Return the decoder for the first value in the pair.
     ///
     /// If this is a map the first value would be the key of the map, if this is
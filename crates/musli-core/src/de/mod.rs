@@ -33,6 +33,12 @@ pub use self::decode::Decode;
 mod decode_bytes;
 pub use self::decode_bytes::DecodeBytes;
 
+mod decode_elements;
+pub use self::decode_elements::{DecodeElements, DecodeEntries};
+
+mod decode_flatten;
+pub use self::decode_flatten::DecodeFlatten;
+
 mod decode_packed;
 pub use self::decode_packed::DecodePacked;
 
@@ -45,6 +51,9 @@ pub use self::decode_unsized::DecodeUnsized;
 mod decode_unsized_bytes;
 pub use self::decode_unsized_bytes::DecodeUnsizedBytes;
 
+mod decode_with;
+pub use self::decode_with::DecodeWith;
+
 mod decoder;
 pub use self::decoder::Decoder;
 
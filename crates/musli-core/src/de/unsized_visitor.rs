@@ -50,6 +50,29 @@ where
         self.visit_ref(cx, value)
     }
 
+    /// Visit a value which is borrowed from a shared [`Bytes`] buffer.
+    ///
+    /// This is called by readers which operate over a shared buffer instead of
+    /// [`visit_borrowed`], where `value` is guaranteed to be a sub-slice of
+    /// `shared`. This allows the visitor to hold on to the value without
+    /// copying it by using [`Bytes::slice_ref`].
+    ///
+    /// [`Bytes`]: bytes::Bytes
+    /// [`Bytes::slice_ref`]: bytes::Bytes::slice_ref
+    /// [`visit_borrowed`]: UnsizedVisitor::visit_borrowed
+    #[cfg(feature = "bytes")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+    #[allow(unused_variables)]
+    #[inline]
+    fn visit_shared(
+        self,
+        cx: &C,
+        shared: &'de bytes::Bytes,
+        value: &'de T,
+    ) -> Result<Self::Ok, C::Error> {
+        self.visit_borrowed(cx, value)
+    }
+
     /// Visit a value reference that is provided from the decoder in any manner
     /// possible. Which might require additional decoding work.
     #[inline]
@@ -182,6 +182,21 @@ pub trait Visitor<'de, C: ?Sized + Context>: Sized {
         )))
     }
 
+    /// Indicates that the visited type is a number in its exact textual
+    /// representation, which could not be represented by any of the fixed-size
+    /// numerical types without loss of precision.
+    ///
+    /// This is provided by formats which support arbitrary-precision numbers,
+    /// like JSON when it has been configured to do so. By default it is parsed
+    /// as an `f64` and passed on to [`Visitor::visit_f64`].
+    #[inline]
+    fn visit_raw_number(self, cx: &C, number: &str) -> Result<Self::Ok, C::Error> {
+        match number.parse::<f64>() {
+            Ok(value) => self.visit_f64(cx, value),
+            Err(error) => Err(cx.message(error)),
+        }
+    }
+
     /// Indicates that the visited type is an optional type.
     #[inline]
     fn visit_option<D>(self, cx: &C, _: Option<D>) -> Result<Self::Ok, C::Error>
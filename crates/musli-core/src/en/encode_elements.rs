@@ -0,0 +1,43 @@
+use super::{EncodeWith, Encoder};
+
+/// Trait governing how a container is encoded when each of its elements is
+/// encoded through an [`EncodeWith`] adapter.
+///
+/// This is used through the `#[musli(with_elements = <path>)]` attribute of
+/// the [`Encode` derive], and is implemented for [`Option`] and standard
+/// sequences and sets.
+///
+/// [`Encode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+pub trait EncodeElements<M> {
+    /// The type of an element in the container.
+    type Element;
+
+    /// Encode the container, where each element is encoded through `W`.
+    fn encode_elements<W, E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        W: ?Sized + EncodeWith<M, Self::Element>,
+        E: Encoder<Mode = M>;
+}
+
+/// Trait governing how a map is encoded when its keys and values are encoded
+/// through [`EncodeWith`] adapters.
+///
+/// This is used through the `#[musli(with_keys = <path>)]` and
+/// `#[musli(with_values = <path>)]` attributes of the [`Encode` derive], and
+/// is implemented for standard maps.
+///
+/// [`Encode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+pub trait EncodeEntries<M> {
+    /// The type of a key in the map.
+    type Key;
+    /// The type of a value in the map.
+    type Value;
+
+    /// Encode the map, where each key is encoded through `K` and each value
+    /// through `V`.
+    fn encode_entries<K, V, E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        K: ?Sized + EncodeWith<M, Self::Key>,
+        V: ?Sized + EncodeWith<M, Self::Value>,
+        E: Encoder<Mode = M>;
+}
@@ -0,0 +1,46 @@
+use crate::en::MapEncoder;
+use crate::Context;
+
+/// Trait governing how a type is encoded when it's flattened into the map of
+/// an enclosing container.
+///
+/// This is used automatically through the `#[musli(flatten)]` attribute
+/// through the [`Encode` derive], which implements this trait for structs
+/// marked with `#[musli(flattenable)]`.
+///
+/// [`Encode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+///
+/// # Examples
+///
+/// ```
+/// use musli::Encode;
+///
+/// #[derive(Encode)]
+/// #[musli(flattenable, name_all = "name")]
+/// struct Metadata {
+///     created: u64,
+///     updated: u64,
+/// }
+///
+/// #[derive(Encode)]
+/// #[musli(name_all = "name")]
+/// struct Document {
+///     id: u32,
+///     #[musli(flatten)]
+///     metadata: Metadata,
+/// }
+/// ```
+pub trait EncodeFlatten<M> {
+    /// The number of entries that [`EncodeFlatten::encode_flatten`] will
+    /// insert into the map.
+    fn flatten_len(&self) -> usize;
+
+    /// Encode the entries of the current value into the given map encoder.
+    fn encode_flatten<E>(
+        &self,
+        cx: &E::Cx,
+        encoder: &mut E,
+    ) -> Result<(), <E::Cx as Context>::Error>
+    where
+        E: MapEncoder<Cx: Context<Mode = M>>;
+}
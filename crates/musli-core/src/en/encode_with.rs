@@ -0,0 +1,51 @@
+use super::Encoder;
+
+/// Trait governing how a value of type `T` is encoded through an adapter.
+///
+/// This is used to encode the elements, keys or values of containers through
+/// a custom `encode` function rather than through their [`Encode`]
+/// implementation, such as through the `#[musli(with_elements = <path>)]`
+/// attribute of the [`Encode` derive].
+///
+/// [`Encode`]: crate::Encode
+/// [`Encode` derive]: https://docs.rs/musli/latest/musli/help/derives/
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Encode, Encoder};
+/// use musli::en::{EncodeElements, EncodeWith};
+///
+/// struct AsString;
+///
+/// impl<M> EncodeWith<M, u32> for AsString {
+///     fn encode_with<E>(value: &u32, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+///     where
+///         E: Encoder<Mode = M>,
+///     {
+///         encoder.collect_string(value)
+///     }
+/// }
+///
+/// struct Numbers {
+///     values: Vec<u32>,
+/// }
+///
+/// impl<M> Encode<M> for Numbers {
+///     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+///     where
+///         E: Encoder<Mode = M>,
+///     {
+///         self.values.encode_elements::<AsString, E>(cx, encoder)
+///     }
+/// }
+/// ```
+pub trait EncodeWith<M, T>
+where
+    T: ?Sized,
+{
+    /// Encode the given value using the given encoder.
+    fn encode_with<E>(value: &T, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>;
+}
@@ -948,6 +948,39 @@ pub trait Encoder: Sized {
         )))
     }
 
+    /// Encode a number from its exact textual representation.
+    ///
+    /// This is used by arbitrary-precision number types to avoid going through
+    /// a lossy fixed-size representation. Formats which have a textual number
+    /// representation, like JSON, emit `number` verbatim. Other formats encode
+    /// it as a string, which is the default behavior.
+    ///
+    /// The provided `number` is expected to follow the JSON number grammar,
+    /// like `-12.5e3`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Encode, Encoder};
+    ///
+    /// struct Price {
+    ///     exact: String,
+    /// }
+    ///
+    /// impl<M> Encode<M> for Price {
+    ///     fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    ///     where
+    ///         E: Encoder,
+    ///     {
+    ///         encoder.encode_raw_number(self.exact.as_str())
+    ///     }
+    /// }
+    /// ```
+    #[inline]
+    fn encode_raw_number(self, number: &str) -> Result<Self::Ok, <Self::Cx as Context>::Error> {
+        self.encode_string(number)
+    }
+
     /// Encode an optional value that is present.
     ///
     /// # Examples
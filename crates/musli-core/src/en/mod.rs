@@ -30,12 +30,21 @@ pub use self::encode::Encode;
 mod encode_bytes;
 pub use self::encode_bytes::EncodeBytes;
 
+mod encode_elements;
+pub use self::encode_elements::{EncodeElements, EncodeEntries};
+
+mod encode_flatten;
+pub use self::encode_flatten::EncodeFlatten;
+
 mod encode_packed;
 pub use self::encode_packed::EncodePacked;
 
 mod encode_trace;
 pub use self::encode_trace::EncodeTrace;
 
+mod encode_with;
+pub use self::encode_with::EncodeWith;
+
 mod encoder;
 pub use self::encoder::Encoder;
 
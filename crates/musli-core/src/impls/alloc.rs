@@ -1,6 +1,6 @@
 use core::ffi::CStr;
 use core::fmt;
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", feature = "indexmap", feature = "hashbrown"))]
 use core::hash::{BuildHasher, Hash};
 
 use rust_alloc::borrow::{Cow, ToOwned};
@@ -19,13 +19,18 @@ use std::ffi::{OsStr, OsString};
 #[cfg(all(feature = "std", any(unix, windows)))]
 use std::path::{Path, PathBuf};
 
+#[cfg(feature = "hashbrown")]
+use hashbrown::{HashMap as HashbrownMap, HashSet as HashbrownSet};
+#[cfg(feature = "indexmap")]
+use indexmap::{IndexMap, IndexSet};
+
 use crate::de::{
-    Decode, DecodeBytes, DecodeTrace, Decoder, EntryDecoder, MapDecoder, SequenceDecoder,
-    UnsizedVisitor,
+    Decode, DecodeBytes, DecodeElements, DecodeEntries, DecodeFlatten, DecodeTrace, DecodeWith,
+    Decoder, EntryDecoder, MapDecoder, SequenceDecoder, UnsizedVisitor,
 };
 use crate::en::{
-    Encode, EncodeBytes, EncodePacked, EncodeTrace, Encoder, EntryEncoder, MapEncoder,
-    SequenceEncoder,
+    Encode, EncodeBytes, EncodeElements, EncodeEntries, EncodeFlatten, EncodePacked, EncodeTrace,
+    EncodeWith, Encoder, EntryEncoder, MapEncoder, SequenceEncoder,
 };
 use crate::hint::{MapHint, SequenceHint};
 use crate::internal::size_hint;
@@ -296,6 +301,67 @@ macro_rules! sequence {
                 })
             }
         }
+
+        $(#[$($meta)*])*
+        impl<M, T $(, $extra)*> EncodeElements<M> for $ty<T $(, $extra)*>
+        where
+            $($extra: $extra_bound0 $(+ $extra_bound)*),*
+        {
+            type Element = T;
+
+            #[inline]
+            fn encode_elements<W, E>(&self, $cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+            where
+                W: ?Sized + EncodeWith<M, T>,
+                E: Encoder<Mode = M>,
+            {
+                let hint = SequenceHint::with_size(self.len());
+
+                encoder.encode_sequence_fn(&hint, |seq| {
+                    let mut index = 0;
+
+                    for value in self {
+                        $cx.enter_sequence_index(index);
+                        W::encode_with(value, $cx, seq.encode_next()?)?;
+                        $cx.leave_sequence_index();
+                        index = index.wrapping_add(1);
+                    }
+
+                    Ok(())
+                })
+            }
+        }
+
+        $(#[$($meta)*])*
+        impl<'de, M, T $(, $extra)*> DecodeElements<'de, M> for $ty<T $(, $extra)*>
+        where
+            $(T: $trait0 $(+ $trait)*,)*
+            $($extra: $extra_bound0 $(+ $extra_bound)*),*
+        {
+            type Element = T;
+
+            #[inline]
+            fn decode_elements<W, D>($cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            where
+                W: ?Sized + DecodeWith<'de, M, T>,
+                D: Decoder<'de, Mode = M>,
+            {
+                decoder.decode_sequence(|$access| {
+                    let mut out = $factory;
+
+                    let mut index = 0;
+
+                    while let Some(value) = $access.try_decode_next()? {
+                        $cx.enter_sequence_index(index);
+                        out.$insert(W::decode_with($cx, value)?);
+                        $cx.leave_sequence_index();
+                        index = index.wrapping_add(1);
+                    }
+
+                    Ok(out)
+                })
+            }
+        }
     }
 }
 
@@ -330,6 +396,24 @@ sequence!(
     seq,
     BinaryHeap::with_capacity(size_hint::cautious(seq.size_hint()))
 );
+sequence!(
+    #[cfg(feature = "indexmap")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "indexmap")))]
+    cx,
+    IndexSet<T: Eq + Hash, S: BuildHasher + Default>,
+    insert,
+    seq,
+    IndexSet::with_capacity_and_hasher(size_hint::cautious(seq.size_hint()), S::default())
+);
+sequence!(
+    #[cfg(feature = "hashbrown")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "hashbrown")))]
+    cx,
+    HashbrownSet<T: Eq + Hash, S: BuildHasher + Default>,
+    insert,
+    seq,
+    HashbrownSet::with_capacity_and_hasher(size_hint::cautious(seq.size_hint()), S::default())
+);
 
 macro_rules! map {
     (
@@ -337,7 +421,8 @@ macro_rules! map {
         $cx:ident,
         $ty:ident<K $(: $key_bound0:ident $(+ $key_bound:ident)*)?, V $(, $extra:ident: $extra_bound0:ident $(+ $extra_bound:ident)*)*>,
         $access:ident,
-        $with_capacity:expr
+        $with_capacity:expr,
+        $new:expr
     ) => {
         $(#[$($meta)*])*
         impl<'de, M, K, V $(, $extra)*> Encode<M> for $ty<K, V $(, $extra)*>
@@ -444,10 +529,153 @@ macro_rules! map {
                 })
             }
         }
+
+        $(#[$($meta)*])*
+        impl<M, K, V $(, $extra)*> EncodeFlatten<M> for $ty<K, V $(, $extra)*>
+        where
+            K: Encode<M>,
+            V: Encode<M>,
+            $($extra: $extra_bound0 $(+ $extra_bound)*),*
+        {
+            #[inline]
+            fn flatten_len(&self) -> usize {
+                self.len()
+            }
+
+            #[inline]
+            fn encode_flatten<E>(
+                &self,
+                _: &E::Cx,
+                encoder: &mut E,
+            ) -> Result<(), <E::Cx as Context>::Error>
+            where
+                E: MapEncoder<Cx: Context<Mode = M>>,
+            {
+                for (k, v) in self {
+                    encoder.insert_entry(k, v)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        $(#[$($meta)*])*
+        impl<M, K, V $(, $extra)*> EncodeEntries<M> for $ty<K, V $(, $extra)*>
+        where
+            $($extra: $extra_bound0 $(+ $extra_bound)*),*
+        {
+            type Key = K;
+            type Value = V;
+
+            #[inline]
+            fn encode_entries<KW, VW, E>(&self, $cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+            where
+                KW: ?Sized + EncodeWith<M, K>,
+                VW: ?Sized + EncodeWith<M, V>,
+                E: Encoder<Mode = M>,
+            {
+                let hint = MapHint::with_size(self.len());
+
+                encoder.encode_map_fn(&hint, |map| {
+                    for (k, v) in self {
+                        map.encode_entry_fn(|entry| {
+                            KW::encode_with(k, $cx, entry.encode_key()?)?;
+                            VW::encode_with(v, $cx, entry.encode_value()?)?;
+                            Ok(())
+                        })?;
+                    }
+
+                    Ok(())
+                })
+            }
+        }
+
+        $(#[$($meta)*])*
+        impl<'de, M, K, V $(, $extra)*> DecodeEntries<'de, M> for $ty<K, V $(, $extra)*>
+        where
+            $(K: $key_bound0 $(+ $key_bound)*,)*
+            $($extra: $extra_bound0 $(+ $extra_bound)*),*
+        {
+            type Key = K;
+            type Value = V;
+
+            #[inline]
+            fn decode_entries<KW, VW, D>($cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+            where
+                KW: ?Sized + DecodeWith<'de, M, K>,
+                VW: ?Sized + DecodeWith<'de, M, V>,
+                D: Decoder<'de, Mode = M>,
+            {
+                decoder.decode_map(|$access| {
+                    let mut out = $with_capacity;
+
+                    while let Some(mut entry) = $access.decode_entry()? {
+                        let key = KW::decode_with($cx, entry.decode_key()?)?;
+                        let value = VW::decode_with($cx, entry.decode_value()?)?;
+                        out.insert(key, value);
+                    }
+
+                    Ok(out)
+                })
+            }
+        }
+
+        $(#[$($meta)*])*
+        impl<'de, M, N, K, V $(, $extra)*> DecodeFlatten<'de, M, N> for $ty<K, V $(, $extra)*>
+        where
+            N: ?Sized + ToOwned<Owned = K>,
+            K: $($key_bound0 $(+ $key_bound)*)*,
+            V: Decode<'de, M>,
+            $($extra: $extra_bound0 $(+ $extra_bound)*),*
+        {
+            type Tag = K;
+            type State = Self;
+
+            #[inline]
+            fn flatten_state() -> Self::State {
+                $new
+            }
+
+            #[inline]
+            fn flatten_tag<C>(_: &C, name: &N) -> Result<Option<Self::Tag>, C::Error>
+            where
+                C: ?Sized + Context<Mode = M>,
+            {
+                Ok(Some(name.to_owned()))
+            }
+
+            #[inline]
+            fn decode_flatten_entry<D>(
+                _: &D::Cx,
+                state: &mut Self::State,
+                tag: Self::Tag,
+                decoder: D,
+            ) -> Result<(), D::Error>
+            where
+                D: Decoder<'de, Mode = M>,
+            {
+                state.insert(tag, decoder.decode()?);
+                Ok(())
+            }
+
+            #[inline]
+            fn decode_flatten_finish<C>(_: &C, state: Self::State) -> Result<Self, C::Error>
+            where
+                C: ?Sized + Context<Mode = M>,
+            {
+                Ok(state)
+            }
+        }
     }
 }
 
-map!(_cx, BTreeMap<K: Ord, V>, map, BTreeMap::new());
+map!(
+    _cx,
+    BTreeMap<K: Ord, V>,
+    map,
+    BTreeMap::new(),
+    BTreeMap::new()
+);
 
 map!(
     #[cfg(feature = "std")]
@@ -455,7 +683,28 @@ map!(
     _cx,
     HashMap<K: Eq + Hash, V, S: BuildHasher + Default>,
     map,
-    HashMap::with_capacity_and_hasher(size_hint::cautious(map.size_hint()), S::default())
+    HashMap::with_capacity_and_hasher(size_hint::cautious(map.size_hint()), S::default()),
+    HashMap::with_hasher(S::default())
+);
+
+map!(
+    #[cfg(feature = "indexmap")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "indexmap")))]
+    _cx,
+    IndexMap<K: Eq + Hash, V, S: BuildHasher + Default>,
+    map,
+    IndexMap::with_capacity_and_hasher(size_hint::cautious(map.size_hint()), S::default()),
+    IndexMap::with_hasher(S::default())
+);
+
+map!(
+    #[cfg(feature = "hashbrown")]
+    #[cfg_attr(doc_cfg, doc(cfg(feature = "hashbrown")))]
+    _cx,
+    HashbrownMap<K: Eq + Hash, V, S: BuildHasher + Default>,
+    map,
+    HashbrownMap::with_capacity_and_hasher(size_hint::cautious(map.size_hint()), S::default()),
+    HashbrownMap::with_hasher(S::default())
 );
 
 impl<M> Encode<M> for CString {
@@ -0,0 +1,149 @@
+use core::fmt;
+
+use arrayvec::{ArrayString, ArrayVec};
+
+use crate::de::{Decode, DecodeBytes, Decoder, SequenceDecoder, UnsizedVisitor};
+use crate::en::{Encode, EncodeBytes, EncodePacked, Encoder, SequenceEncoder};
+use crate::Context;
+
+impl<M, T, const CAP: usize> Encode<M> for ArrayVec<T, CAP>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.as_slice().encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T, const CAP: usize> Decode<'de, M> for ArrayVec<T, CAP>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_sequence(|seq| {
+            let mut out = ArrayVec::new();
+
+            let mut index = 0;
+
+            while let Some(value) = seq.try_decode_next()? {
+                cx.enter_sequence_index(index);
+                out.try_push(T::decode(cx, value)?)
+                    .map_err(cx.map_message())?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(out)
+        })
+    }
+}
+
+impl<M, T, const CAP: usize> EncodePacked<M> for ArrayVec<T, CAP>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode_packed<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            let mut index = 0;
+
+            for value in self {
+                cx.enter_sequence_index(index);
+                pack.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<M, const CAP: usize> EncodeBytes<M> for ArrayVec<u8, CAP> {
+    #[inline]
+    fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_bytes(self.as_slice())
+    }
+}
+
+impl<'de, M, const CAP: usize> DecodeBytes<'de, M> for ArrayVec<u8, CAP> {
+    #[inline]
+    fn decode_bytes<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor<const CAP: usize>;
+
+        impl<'de, C, const CAP: usize> UnsizedVisitor<'de, C, [u8]> for Visitor<CAP>
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = ArrayVec<u8, CAP>;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "bytes with a capacity of {CAP}")
+            }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                let mut out = ArrayVec::new();
+                out.try_extend_from_slice(bytes).map_err(cx.map_message())?;
+                Ok(out)
+            }
+        }
+
+        decoder.decode_bytes(Visitor)
+    }
+}
+
+impl<M, const CAP: usize> Encode<M> for ArrayString<CAP> {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.as_str().encode(cx, encoder)
+    }
+}
+
+impl<'de, M, const CAP: usize> Decode<'de, M> for ArrayString<CAP> {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_unsized(|string: &str| ArrayString::from(string).map_err(cx.map_message()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Decode, Encode};
+
+    use arrayvec::{ArrayString, ArrayVec};
+
+    #[derive(Encode, Decode)]
+    #[musli(crate)]
+    #[allow(dead_code)]
+    struct Container {
+        values: ArrayVec<u32, 4>,
+        #[musli(bytes)]
+        bytes: ArrayVec<u8, 16>,
+        string: ArrayString<16>,
+    }
+}
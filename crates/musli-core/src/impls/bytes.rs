@@ -0,0 +1,220 @@
+use core::fmt;
+
+use bytes::{Bytes, BytesMut};
+use rust_alloc::vec::Vec;
+
+use crate::de::{Decode, DecodeBytes, Decoder, UnsizedVisitor};
+use crate::en::{Encode, EncodeBytes, Encoder};
+use crate::Context;
+
+impl<M> Encode<M> for Bytes {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_bytes(self.as_ref())
+    }
+}
+
+impl<M> EncodeBytes<M> for Bytes {
+    #[inline]
+    fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_bytes(self.as_ref())
+    }
+}
+
+/// Decoding [`Bytes`] slices the shared buffer being read from if the reader
+/// supports it, otherwise the bytes are copied.
+impl<'de, M> Decode<'de, M> for Bytes {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor;
+
+        impl<'de, C> UnsizedVisitor<'de, C, [u8]> for Visitor
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = Bytes;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "bytes")
+            }
+
+            #[inline]
+            fn visit_owned(self, _: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
+                Ok(Bytes::from(bytes))
+            }
+
+            #[inline]
+            fn visit_shared(
+                self,
+                _: &C,
+                shared: &'de Bytes,
+                bytes: &'de [u8],
+            ) -> Result<Self::Ok, C::Error> {
+                Ok(shared.slice_ref(bytes))
+            }
+
+            #[inline]
+            fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                Ok(Bytes::copy_from_slice(bytes))
+            }
+        }
+
+        decoder.decode_bytes(Visitor)
+    }
+}
+
+impl<'de, M> DecodeBytes<'de, M> for Bytes {
+    #[inline]
+    fn decode_bytes<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        Decode::decode(cx, decoder)
+    }
+}
+
+impl<M> Encode<M> for BytesMut {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_bytes(self.as_ref())
+    }
+}
+
+impl<M> EncodeBytes<M> for BytesMut {
+    #[inline]
+    fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_bytes(self.as_ref())
+    }
+}
+
+/// Since [`BytesMut`] uniquely owns its buffer, decoding it always copies.
+impl<'de, M> Decode<'de, M> for BytesMut {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor;
+
+        impl<'de, C> UnsizedVisitor<'de, C, [u8]> for Visitor
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = BytesMut;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "bytes")
+            }
+
+            #[inline]
+            fn visit_owned(self, _: &C, bytes: Vec<u8>) -> Result<Self::Ok, C::Error> {
+                Ok(BytesMut::from(Bytes::from(bytes)))
+            }
+
+            #[inline]
+            fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                Ok(BytesMut::from(bytes))
+            }
+        }
+
+        decoder.decode_bytes(Visitor)
+    }
+}
+
+impl<'de, M> DecodeBytes<'de, M> for BytesMut {
+    #[inline]
+    fn decode_bytes<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        Decode::decode(cx, decoder)
+    }
+}
+
+#[cfg(feature = "bytestring")]
+mod bytestring {
+    use core::fmt;
+
+    use bytes::Bytes;
+    use bytestring::ByteString;
+    use rust_alloc::string::String;
+
+    use crate::de::{Decode, Decoder, UnsizedVisitor};
+    use crate::en::{Encode, Encoder};
+    use crate::Context;
+
+    impl<M> Encode<M> for ByteString {
+        #[inline]
+        fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+        where
+            E: Encoder<Mode = M>,
+        {
+            encoder.encode_string(self)
+        }
+    }
+
+    /// Decoding a [`ByteString`] slices the shared buffer being read from if
+    /// the reader supports it, otherwise the string is copied.
+    impl<'de, M> Decode<'de, M> for ByteString {
+        #[inline]
+        fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+        where
+            D: Decoder<'de, Mode = M>,
+        {
+            struct Visitor;
+
+            impl<'de, C> UnsizedVisitor<'de, C, str> for Visitor
+            where
+                C: ?Sized + Context,
+            {
+                type Ok = ByteString;
+
+                #[inline]
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "string")
+                }
+
+                #[inline]
+                fn visit_owned(self, _: &C, string: String) -> Result<Self::Ok, C::Error> {
+                    Ok(ByteString::from(string))
+                }
+
+                #[inline]
+                fn visit_shared(
+                    self,
+                    _: &C,
+                    shared: &'de Bytes,
+                    string: &'de str,
+                ) -> Result<Self::Ok, C::Error> {
+                    let bytes = shared.slice_ref(string.as_bytes());
+                    // SAFETY: The slice was sourced from a valid string.
+                    Ok(unsafe { ByteString::from_bytes_unchecked(bytes) })
+                }
+
+                #[inline]
+                fn visit_ref(self, _: &C, string: &str) -> Result<Self::Ok, C::Error> {
+                    Ok(ByteString::from(string))
+                }
+            }
+
+            decoder.decode_string(Visitor)
+        }
+    }
+}
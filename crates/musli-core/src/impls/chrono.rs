@@ -0,0 +1,115 @@
+use chrono::{DateTime, FixedOffset, SecondsFormat, Utc};
+
+use crate::context::Context;
+use crate::de::{Decode, Decoder, SequenceDecoder};
+use crate::en::{Encode, Encoder, SequenceEncoder};
+use crate::mode::{Binary, Text};
+
+impl Encode<Binary> for DateTime<Utc> {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            pack.push(self.timestamp())?;
+            pack.push(self.timestamp_subsec_nanos())?;
+            Ok(())
+        })
+    }
+}
+
+impl Encode<Text> for DateTime<Utc> {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        encoder.encode_string(&self.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+}
+
+impl<'de> Decode<'de, Binary> for DateTime<Utc> {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        let (secs, nanos) = decoder.decode_pack(|p| Ok((p.next()?, p.next()?)))?;
+
+        match DateTime::from_timestamp(secs, nanos) {
+            Some(date_time) => Ok(date_time),
+            None => Err(cx.message("Timestamp out of range")),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Text> for DateTime<Utc> {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        decoder.decode_unsized(|string: &str| {
+            let date_time = DateTime::parse_from_rfc3339(string).map_err(cx.map_message())?;
+            Ok(date_time.with_timezone(&Utc))
+        })
+    }
+}
+
+impl Encode<Binary> for DateTime<FixedOffset> {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            pack.push(self.timestamp())?;
+            pack.push(self.timestamp_subsec_nanos())?;
+            pack.push(self.offset().local_minus_utc())?;
+            Ok(())
+        })
+    }
+}
+
+impl Encode<Text> for DateTime<FixedOffset> {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        encoder.encode_string(&self.to_rfc3339_opts(SecondsFormat::AutoSi, false))
+    }
+}
+
+impl<'de> Decode<'de, Binary> for DateTime<FixedOffset> {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        let (secs, nanos, offset) =
+            decoder.decode_pack(|p| Ok((p.next()?, p.next()?, p.next()?)))?;
+
+        let Some(offset) = FixedOffset::east_opt(offset) else {
+            return Err(cx.message("Offset out of range"));
+        };
+
+        match DateTime::from_timestamp(secs, nanos) {
+            Some(date_time) => Ok(date_time.with_timezone(&offset)),
+            None => Err(cx.message("Timestamp out of range")),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Text> for DateTime<FixedOffset> {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        decoder.decode_unsized(|string: &str| {
+            DateTime::parse_from_rfc3339(string).map_err(cx.map_message())
+        })
+    }
+}
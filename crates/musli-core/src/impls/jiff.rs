@@ -0,0 +1,51 @@
+use jiff::Timestamp;
+
+use crate::context::Context;
+use crate::de::{Decode, Decoder, SequenceDecoder};
+use crate::en::{Encode, Encoder, SequenceEncoder};
+use crate::mode::{Binary, Text};
+
+impl Encode<Binary> for Timestamp {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            pack.push(self.as_second())?;
+            pack.push(self.subsec_nanosecond())?;
+            Ok(())
+        })
+    }
+}
+
+impl Encode<Text> for Timestamp {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        encoder.collect_string(self)
+    }
+}
+
+impl<'de> Decode<'de, Binary> for Timestamp {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        let (secs, nanos) = decoder.decode_pack(|p| Ok((p.next()?, p.next()?)))?;
+        Timestamp::new(secs, nanos).map_err(cx.map_message())
+    }
+}
+
+impl<'de> Decode<'de, Text> for Timestamp {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        decoder.decode_unsized(|string: &str| string.parse().map_err(cx.map_message()))
+    }
+}
@@ -1,24 +1,58 @@
 #[cfg(feature = "alloc")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 mod alloc;
+#[cfg(feature = "arrayvec")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "arrayvec")))]
+mod arrayvec;
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+mod bytes;
+#[cfg(feature = "chrono")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "chrono")))]
+mod chrono;
+#[cfg(feature = "jiff")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "jiff")))]
+mod jiff;
 #[cfg(feature = "std")]
 #[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
 mod net;
 mod range;
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "rust_decimal")))]
+mod rust_decimal;
+#[cfg(feature = "smallvec")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "smallvec")))]
+mod smallvec;
+#[cfg(feature = "std")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "std")))]
+mod sync;
+mod time;
+#[cfg(feature = "time")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "time")))]
+mod time_crate;
 mod tuples;
+#[cfg(feature = "uuid")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "uuid")))]
+mod uuid;
 
+use core::cell::{Cell, RefCell};
+use core::cmp::{Ordering, Reverse};
 use core::ffi::CStr;
 use core::num::{
     NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
-    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Wrapping,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
 };
+use core::ops::{Bound, ControlFlow};
 use core::{fmt, marker};
 
 use crate::de::{
-    Decode, DecodeBytes, DecodePacked, DecodeUnsized, DecodeUnsizedBytes, Decoder, SequenceDecoder,
-    UnsizedVisitor, VariantDecoder,
+    Decode, DecodeBytes, DecodeElements, DecodePacked, DecodeUnsized, DecodeUnsizedBytes,
+    DecodeWith, Decoder, SequenceDecoder, UnsizedVisitor, VariantDecoder,
+};
+use crate::en::{
+    Encode, EncodeBytes, EncodeElements, EncodePacked, EncodeWith, Encoder, SequenceEncoder,
+    VariantEncoder,
 };
-use crate::en::{Encode, EncodeBytes, EncodePacked, Encoder, SequenceEncoder, VariantEncoder};
 use crate::hint::SequenceHint;
 use crate::Context;
 
@@ -518,6 +552,39 @@ where
     }
 }
 
+impl<M, T> EncodeElements<M> for Option<T> {
+    type Element = T;
+
+    #[inline]
+    fn encode_elements<W, E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        W: ?Sized + EncodeWith<M, T>,
+        E: Encoder<Mode = M>,
+    {
+        match self {
+            Some(value) => W::encode_with(value, cx, encoder.encode_some()?),
+            None => encoder.encode_none(),
+        }
+    }
+}
+
+impl<'de, M, T> DecodeElements<'de, M> for Option<T> {
+    type Element = T;
+
+    #[inline]
+    fn decode_elements<W, D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        W: ?Sized + DecodeWith<'de, M, T>,
+        D: Decoder<'de, Mode = M>,
+    {
+        if let Some(decoder) = decoder.decode_option()? {
+            Ok(Some(W::decode_with(cx, decoder)?))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
 #[derive(Encode, Decode)]
 #[musli(crate)]
 enum ResultTag {
@@ -593,6 +660,265 @@ where
     }
 }
 
+impl<T, M> Encode<M> for Saturating<T>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.0.encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Saturating<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        Ok(Saturating(decoder.decode()?))
+    }
+}
+
+impl<T, M> Encode<M> for Reverse<T>
+where
+    T: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.0.encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Reverse<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        Ok(Reverse(decoder.decode()?))
+    }
+}
+
+impl<T, M> Encode<M> for Cell<T>
+where
+    T: Copy + Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.get().encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Cell<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode().map(Cell::new)
+    }
+}
+
+impl<T, M> Encode<M> for RefCell<T>
+where
+    T: ?Sized + Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        self.try_borrow()
+            .map_err(cx.map_message())?
+            .encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for RefCell<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode().map(RefCell::new)
+    }
+}
+
+#[derive(Encode, Decode)]
+#[musli(crate)]
+enum BoundTag {
+    Included,
+    Excluded,
+    Unbounded,
+}
+
+impl<T, M> Encode<M> for Bound<T>
+where
+    T: Encode<M>,
+    BoundTag: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let variant = encoder.encode_variant()?;
+
+        match self {
+            Bound::Included(value) => variant.insert_variant(BoundTag::Included, value),
+            Bound::Excluded(value) => variant.insert_variant(BoundTag::Excluded, value),
+            Bound::Unbounded => variant.insert_variant(BoundTag::Unbounded, ()),
+        }
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Bound<T>
+where
+    T: Decode<'de, M>,
+    BoundTag: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_variant(|variant| {
+            let tag = variant.decode_tag()?.decode()?;
+
+            Ok(match tag {
+                BoundTag::Included => Bound::Included(variant.decode_value()?.decode()?),
+                BoundTag::Excluded => Bound::Excluded(variant.decode_value()?.decode()?),
+                BoundTag::Unbounded => {
+                    variant.decode_value()?.decode_empty()?;
+                    Bound::Unbounded
+                }
+            })
+        })
+    }
+}
+
+#[derive(Encode, Decode)]
+#[musli(crate)]
+enum ControlFlowTag {
+    Continue,
+    Break,
+}
+
+impl<B, C, M> Encode<M> for ControlFlow<B, C>
+where
+    B: Encode<M>,
+    C: Encode<M>,
+    ControlFlowTag: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let variant = encoder.encode_variant()?;
+
+        match self {
+            ControlFlow::Continue(value) => variant.insert_variant(ControlFlowTag::Continue, value),
+            ControlFlow::Break(value) => variant.insert_variant(ControlFlowTag::Break, value),
+        }
+    }
+}
+
+impl<'de, M, B, C> Decode<'de, M> for ControlFlow<B, C>
+where
+    B: Decode<'de, M>,
+    C: Decode<'de, M>,
+    ControlFlowTag: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_variant(|variant| {
+            let tag = variant.decode_tag()?.decode()?;
+
+            Ok(match tag {
+                ControlFlowTag::Continue => {
+                    ControlFlow::Continue(variant.decode_value()?.decode()?)
+                }
+                ControlFlowTag::Break => ControlFlow::Break(variant.decode_value()?.decode()?),
+            })
+        })
+    }
+}
+
+#[derive(Encode, Decode)]
+#[musli(crate)]
+enum OrderingTag {
+    Less,
+    Equal,
+    Greater,
+}
+
+impl<M> Encode<M> for Ordering
+where
+    OrderingTag: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let tag = match self {
+            Ordering::Less => OrderingTag::Less,
+            Ordering::Equal => OrderingTag::Equal,
+            Ordering::Greater => OrderingTag::Greater,
+        };
+
+        encoder.encode_unit_variant(&tag)
+    }
+}
+
+impl<'de, M> Decode<'de, M> for Ordering
+where
+    OrderingTag: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_variant(|variant| {
+            let tag = variant.decode_tag()?.decode()?;
+            variant.decode_value()?.decode_empty()?;
+
+            Ok(match tag {
+                OrderingTag::Less => Ordering::Less,
+                OrderingTag::Equal => Ordering::Equal,
+                OrderingTag::Greater => Ordering::Greater,
+            })
+        })
+    }
+}
+
 impl<M> Encode<M> for CStr {
     #[inline]
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
@@ -0,0 +1,74 @@
+use core::fmt;
+
+use rust_decimal::Decimal;
+
+use crate::context::Context;
+use crate::de::{Decode, Decoder, UnsizedVisitor};
+use crate::en::{Encode, Encoder};
+use crate::mode::{Binary, Text};
+
+impl Encode<Binary> for Decimal {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        encoder.encode_array(&self.serialize())
+    }
+}
+
+impl Encode<Text> for Decimal {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        let string = cx.collect_string(self)?;
+        encoder.encode_raw_number(string.as_ref())
+    }
+}
+
+impl<'de> Decode<'de, Binary> for Decimal {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        decoder.decode_array::<16>().map(Decimal::deserialize)
+    }
+}
+
+impl<'de> Decode<'de, Text> for Decimal {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        struct Visitor;
+
+        impl<'de, C> UnsizedVisitor<'de, C, str> for Visitor
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = Decimal;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a decimal number")
+            }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, number: &str) -> Result<Self::Ok, C::Error> {
+                let result = if number.contains(['e', 'E']) {
+                    Decimal::from_scientific(number)
+                } else {
+                    Decimal::from_str_exact(number)
+                };
+
+                result.map_err(cx.map_message())
+            }
+        }
+
+        decoder.decode_raw_number(Visitor)
+    }
+}
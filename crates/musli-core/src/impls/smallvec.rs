@@ -0,0 +1,134 @@
+use core::{fmt, marker};
+
+use smallvec::{Array, SmallVec};
+
+use crate::de::{Decode, DecodeBytes, Decoder, SequenceDecoder, UnsizedVisitor};
+use crate::en::{Encode, EncodeBytes, EncodePacked, Encoder, SequenceEncoder};
+use crate::hint::SequenceHint;
+use crate::internal::size_hint;
+use crate::Context;
+
+impl<M, A> Encode<M> for SmallVec<A>
+where
+    A: Array,
+    A::Item: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let hint = SequenceHint::with_size(self.len());
+
+        encoder.encode_sequence_fn(&hint, |seq| {
+            let mut index = 0;
+
+            for value in self {
+                cx.enter_sequence_index(index);
+                seq.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<'de, M, A> Decode<'de, M> for SmallVec<A>
+where
+    A: Array,
+    A::Item: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode_sequence(|seq| {
+            let mut out = SmallVec::with_capacity(size_hint::cautious(seq.size_hint()));
+
+            let mut index = 0;
+
+            while let Some(value) = seq.try_decode_next()? {
+                cx.enter_sequence_index(index);
+                out.push(A::Item::decode(cx, value)?);
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(out)
+        })
+    }
+}
+
+impl<M, A> EncodePacked<M> for SmallVec<A>
+where
+    A: Array,
+    A::Item: Encode<M>,
+{
+    #[inline]
+    fn encode_packed<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            let mut index = 0;
+
+            for value in self {
+                cx.enter_sequence_index(index);
+                pack.push(value)?;
+                cx.leave_sequence_index();
+                index = index.wrapping_add(1);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<M, A> EncodeBytes<M> for SmallVec<A>
+where
+    A: Array<Item = u8>,
+{
+    #[inline]
+    fn encode_bytes<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_bytes(self.as_slice())
+    }
+}
+
+impl<'de, M, A> DecodeBytes<'de, M> for SmallVec<A>
+where
+    A: Array<Item = u8>,
+{
+    #[inline]
+    fn decode_bytes<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor<A>(marker::PhantomData<A>);
+
+        impl<'de, C, A> UnsizedVisitor<'de, C, [u8]> for Visitor<A>
+        where
+            C: ?Sized + Context,
+            A: Array<Item = u8>,
+        {
+            type Ok = SmallVec<A>;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "bytes")
+            }
+
+            #[inline]
+            fn visit_ref(self, _: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
+                Ok(SmallVec::from_slice(bytes))
+            }
+        }
+
+        decoder.decode_bytes(Visitor(marker::PhantomData))
+    }
+}
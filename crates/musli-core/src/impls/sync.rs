@@ -0,0 +1,75 @@
+use core::fmt;
+use std::sync::{Mutex, RwLock};
+
+use crate::context::Context;
+use crate::de::{Decode, Decoder};
+use crate::en::{Encode, Encoder};
+
+struct Poisoned(&'static str);
+
+impl fmt::Display for Poisoned {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: lock is poisoned", self.0)
+    }
+}
+
+impl<M, T> Encode<M> for Mutex<T>
+where
+    T: ?Sized + Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let Ok(guard) = self.lock() else {
+            return Err(cx.message(Poisoned("Mutex")));
+        };
+
+        guard.encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for Mutex<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode().map(Mutex::new)
+    }
+}
+
+impl<M, T> Encode<M> for RwLock<T>
+where
+    T: ?Sized + Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let Ok(guard) = self.read() else {
+            return Err(cx.message(Poisoned("RwLock")));
+        };
+
+        guard.encode(cx, encoder)
+    }
+}
+
+impl<'de, M, T> Decode<'de, M> for RwLock<T>
+where
+    T: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        decoder.decode().map(RwLock::new)
+    }
+}
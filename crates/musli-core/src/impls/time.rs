@@ -0,0 +1,176 @@
+use core::fmt;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::context::Context;
+use crate::de::{Decode, Decoder, SequenceDecoder};
+use crate::en::{Encode, Encoder, SequenceEncoder};
+use crate::mode::{Binary, Text};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+/// The serialized representation of a [`SystemTime`], relative to the
+/// [`UNIX_EPOCH`].
+///
+/// Times before the epoch are represented with a negative number of seconds,
+/// and the nanoseconds are always counted forward from the specified second.
+#[cfg(feature = "std")]
+#[derive(Encode, Decode)]
+#[musli(crate)]
+#[musli(mode = Binary, packed)]
+struct SystemTimeRepr {
+    secs_since_epoch: i64,
+    nanos_since_epoch: u32,
+}
+
+struct InvalidNanos(u32);
+
+impl fmt::Display for InvalidNanos {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Nanoseconds {} out of range", self.0)
+    }
+}
+
+impl Encode<Binary> for Duration {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            pack.push(self.as_secs())?;
+            pack.push(self.subsec_nanos())?;
+            Ok(())
+        })
+    }
+}
+
+impl Encode<Text> for Duration {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        encoder.encode_f64(self.as_secs_f64())
+    }
+}
+
+impl<'de> Decode<'de, Binary> for Duration {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        let (secs, nanos) = decoder.decode_pack(|p| Ok((p.next()?, p.next()?)))?;
+
+        if nanos >= NANOS_PER_SEC {
+            return Err(cx.message(InvalidNanos(nanos)));
+        }
+
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+impl<'de> Decode<'de, Text> for Duration {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        let secs = decoder.decode_f64()?;
+        Duration::try_from_secs_f64(secs).map_err(cx.map_message())
+    }
+}
+
+#[cfg(feature = "std")]
+impl<M> Encode<M> for SystemTime
+where
+    SystemTimeRepr: Encode<M>,
+{
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        let repr = match self.duration_since(UNIX_EPOCH) {
+            Ok(after) => SystemTimeRepr {
+                secs_since_epoch: i64::try_from(after.as_secs()).map_err(cx.map())?,
+                nanos_since_epoch: after.subsec_nanos(),
+            },
+            Err(error) => {
+                let before = error.duration();
+                let secs = i64::try_from(before.as_secs()).map_err(cx.map())?;
+
+                match before.subsec_nanos() {
+                    0 => SystemTimeRepr {
+                        secs_since_epoch: -secs,
+                        nanos_since_epoch: 0,
+                    },
+                    nanos => SystemTimeRepr {
+                        secs_since_epoch: -secs - 1,
+                        nanos_since_epoch: NANOS_PER_SEC - nanos,
+                    },
+                }
+            }
+        };
+
+        encoder.encode(repr)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<'de, M> Decode<'de, M> for SystemTime
+where
+    SystemTimeRepr: Decode<'de, M>,
+{
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        let SystemTimeRepr {
+            secs_since_epoch,
+            nanos_since_epoch,
+        } = decoder.decode()?;
+
+        if nanos_since_epoch >= NANOS_PER_SEC {
+            return Err(cx.message(InvalidNanos(nanos_since_epoch)));
+        }
+
+        let secs = Duration::from_secs(secs_since_epoch.unsigned_abs());
+        let nanos = Duration::from_nanos(u64::from(nanos_since_epoch));
+
+        let time = if secs_since_epoch < 0 {
+            UNIX_EPOCH
+                .checked_sub(secs)
+                .and_then(|time| time.checked_add(nanos))
+        } else {
+            UNIX_EPOCH
+                .checked_add(secs)
+                .and_then(|time| time.checked_add(nanos))
+        };
+
+        match time {
+            Some(time) => Ok(time),
+            None => Err(cx.message("System time out of range")),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use crate::{Decode, Encode};
+
+    use core::time::Duration;
+    use std::time::SystemTime;
+
+    #[derive(Encode, Decode)]
+    #[musli(crate)]
+    #[allow(dead_code)]
+    struct Container {
+        duration: Duration,
+        system_time: SystemTime,
+    }
+}
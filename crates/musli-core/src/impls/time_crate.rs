@@ -0,0 +1,183 @@
+use core::fmt::{self, Write};
+use core::str;
+
+use time::format_description::well_known::Rfc3339;
+use time::{OffsetDateTime, UtcOffset};
+
+use crate::context::Context;
+use crate::de::{Decode, Decoder, SequenceDecoder};
+use crate::en::{Encode, Encoder, SequenceEncoder};
+use crate::mode::{Binary, Text};
+
+impl Encode<Binary> for OffsetDateTime {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Binary>,
+    {
+        encoder.encode_pack_fn(|pack| {
+            pack.push(self.unix_timestamp())?;
+            pack.push(self.nanosecond())?;
+            pack.push(self.offset().whole_seconds())?;
+            Ok(())
+        })
+    }
+}
+
+impl Encode<Text> for OffsetDateTime {
+    #[inline]
+    fn encode<E>(&self, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = Text>,
+    {
+        let mut buf = Rfc3339Buf::new();
+        buf.format(cx, self)?;
+        encoder.encode_string(buf.as_str())
+    }
+}
+
+impl<'de> Decode<'de, Binary> for OffsetDateTime {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Binary>,
+    {
+        let (secs, nanos, offset) =
+            decoder.decode_pack(|p| Ok((p.next()?, p.next()?, p.next()?)))?;
+
+        let offset = UtcOffset::from_whole_seconds(offset).map_err(cx.map_message())?;
+
+        let date_time = OffsetDateTime::from_unix_timestamp(secs)
+            .and_then(|date_time| date_time.replace_nanosecond(nanos))
+            .map_err(cx.map_message())?;
+
+        match date_time.checked_to_offset(offset) {
+            Some(date_time) => Ok(date_time),
+            None => Err(cx.message("Date time out of range")),
+        }
+    }
+}
+
+impl<'de> Decode<'de, Text> for OffsetDateTime {
+    #[inline]
+    fn decode<D>(cx: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = Text>,
+    {
+        decoder.decode_unsized(|string: &str| {
+            OffsetDateTime::parse(string, &Rfc3339).map_err(cx.map_message())
+        })
+    }
+}
+
+/// Stack buffer used to format a date time as RFC 3339 without allocating,
+/// since formatting through the `time` crate requires `std`.
+///
+/// The longest representation is `YYYY-MM-DDTHH:MM:SS.NNNNNNNNN+HH:MM`.
+struct Rfc3339Buf {
+    data: [u8; 35],
+    len: usize,
+}
+
+impl Rfc3339Buf {
+    #[inline]
+    fn new() -> Self {
+        Self {
+            data: [0; 35],
+            len: 0,
+        }
+    }
+
+    /// Format the date time in the same way as the `Rfc3339` well-known
+    /// format does.
+    fn format<C>(&mut self, cx: &C, date_time: &OffsetDateTime) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        if !(0..10_000).contains(&date_time.year()) {
+            return Err(cx.message("Year out of range for RFC 3339"));
+        }
+
+        let (offset_hours, offset_minutes, offset_seconds) = date_time.offset().as_hms();
+
+        if offset_hours.unsigned_abs() > 23 || offset_seconds != 0 {
+            return Err(cx.message("Offset out of range for RFC 3339"));
+        }
+
+        self.write(
+            cx,
+            format_args!(
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+                date_time.year(),
+                u8::from(date_time.month()),
+                date_time.day(),
+                date_time.hour(),
+                date_time.minute(),
+                date_time.second(),
+            ),
+        )?;
+
+        let nanos = date_time.nanosecond();
+
+        if nanos != 0 {
+            let mut digits = 9;
+            let mut fraction = nanos;
+
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                digits -= 1;
+            }
+
+            self.write(cx, format_args!(".{fraction:0digits$}"))?;
+        }
+
+        if date_time.offset().is_utc() {
+            return self.write(cx, format_args!("Z"));
+        }
+
+        let sign = if date_time.offset().is_negative() {
+            '-'
+        } else {
+            '+'
+        };
+
+        self.write(
+            cx,
+            format_args!(
+                "{sign}{:02}:{:02}",
+                offset_hours.unsigned_abs(),
+                offset_minutes.unsigned_abs()
+            ),
+        )
+    }
+
+    #[inline]
+    fn write<C>(&mut self, cx: &C, args: fmt::Arguments<'_>) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        match self.write_fmt(args) {
+            Ok(()) => Ok(()),
+            Err(fmt::Error) => Err(cx.message("Date time does not fit RFC 3339")),
+        }
+    }
+
+    #[inline]
+    fn as_str(&self) -> &str {
+        // SAFETY: Only string data is written to the buffer.
+        unsafe { str::from_utf8_unchecked(&self.data[..self.len]) }
+    }
+}
+
+impl Write for Rfc3339Buf {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let Some(target) = self.data.get_mut(self.len..self.len + s.len()) else {
+            return Err(fmt::Error);
+        };
+
+        target.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
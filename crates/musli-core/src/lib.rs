@@ -20,7 +20,7 @@ pub mod alloc;
 
 mod context;
 #[doc(inline)]
-pub use self::context::Context;
+pub use self::context::{Checkpoint, Context};
 
 pub mod de;
 #[doc(inline)]
@@ -187,12 +187,51 @@ pub mod __priv {
     use crate::context::Context;
     use crate::de::{Decoder, EntryDecoder};
 
+    pub use ::core::clone::Clone;
+    pub use ::core::convert::{From, Into, TryFrom};
     pub use ::core::fmt;
+    pub use ::core::marker::PhantomData;
     pub use ::core::option::Option;
     pub use ::core::result::Result;
 
+    #[cfg(feature = "alloc")]
+    pub use rust_alloc::borrow::ToOwned;
+
     pub use crate::never::Never;
 
+    /// Adapter which encodes and decodes values through their [`Encode`] and
+    /// [`Decode`] implementations.
+    ///
+    /// [`Encode`]: crate::Encode
+    /// [`Decode`]: crate::Decode
+    pub enum DefaultWith {}
+
+    impl<M, T> crate::en::EncodeWith<M, T> for DefaultWith
+    where
+        T: ?Sized + crate::Encode<M>,
+    {
+        #[inline(always)]
+        fn encode_with<E>(value: &T, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+        where
+            E: crate::Encoder<Mode = M>,
+        {
+            value.encode(cx, encoder)
+        }
+    }
+
+    impl<'de, M, T> crate::de::DecodeWith<'de, M, T> for DefaultWith
+    where
+        T: crate::Decode<'de, M>,
+    {
+        #[inline(always)]
+        fn decode_with<D>(cx: &D::Cx, decoder: D) -> Result<T, D::Error>
+        where
+            D: Decoder<'de, Mode = M>,
+        {
+            T::decode(cx, decoder)
+        }
+    }
+
     #[inline(always)]
     pub fn default<T>() -> T
     where
@@ -201,6 +240,25 @@ pub mod __priv {
         ::core::default::Default::default()
     }
 
+    /// Perform a decoding attempt, where the error is captured so that another
+    /// alternative can be tried.
+    ///
+    /// Diagnostics reported by a failed attempt are discarded from the
+    /// context, and the first one is returned together with the error.
+    #[inline(always)]
+    pub fn attempt<C, T, F>(cx: &C, f: F) -> Result<T, (C::Error, Option<C::String<'_>>)>
+    where
+        C: ?Sized + Context,
+        F: FnOnce() -> Result<T, C::Error>,
+    {
+        let checkpoint = cx.checkpoint();
+
+        match f() {
+            Ok(value) => Ok(value),
+            Err(error) => Err((error, cx.restore(checkpoint))),
+        }
+    }
+
     /// Note that this returns `true` if skipping was unsupported.
     #[inline(always)]
     pub fn skip<'de, D>(decoder: D) -> Result<bool, D::Error>
@@ -4,10 +4,10 @@ use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::Token;
 
-use crate::expander::{NameMethod, StructKind};
+use crate::expander::{Expander, NameMethod, StructKind};
 use crate::internals::apply;
 use crate::internals::attr::{EnumTagging, Packing};
-use crate::internals::build::{Body, Build, BuildData, Enum, Field, Variant};
+use crate::internals::build::{AdapterKind, Body, Build, BuildData, Enum, Field, Variant};
 use crate::internals::tokens::Tokens;
 use crate::internals::Result;
 
@@ -15,6 +15,7 @@ struct Ctxt<'a> {
     ctx_var: &'a Ident,
     decoder_var: &'a Ident,
     name_var: &'a Ident,
+    lt: &'a syn::Lifetime,
     trace: bool,
     trace_body: bool,
 }
@@ -23,6 +24,23 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
     e.validate_decode()?;
     e.cx.reset();
 
+    // Figure out which lifetime to use for what. We use the first lifetime in
+    // the type (if any is available) as the decoder lifetime. Else we generate
+    // a new anonymous lifetime `'de` to use for the `Decode` impl.
+    let mut generics = e.input.generics.clone();
+    let type_ident = &e.input.ident;
+
+    let (lt, exists) = if let Some(existing) = generics.lifetimes().next() {
+        (existing.clone(), true)
+    } else {
+        let lt = syn::LifetimeParam::new(syn::Lifetime::new("'de", e.input.span()));
+        (lt, false)
+    };
+
+    if !exists {
+        generics.params.push(lt.clone().into());
+    }
+
     let ctx_var = e.cx.ident("ctx");
     let root_decoder_var = e.cx.ident("decoder");
     let tag_var = e.cx.ident("tag");
@@ -32,36 +50,21 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
         ctx_var: &ctx_var,
         decoder_var: &root_decoder_var,
         name_var: &tag_var,
+        lt: &lt.lifetime,
         trace: true,
         trace_body: true,
     };
 
-    let body = match &e.data {
-        BuildData::Struct(st) => decode_struct(&cx, &e, st)?,
-        BuildData::Enum(en) => decode_enum(&cx, &e, en)?,
+    let body = match (e.from.or(e.try_from), &e.data) {
+        (Some((_, ty)), _) => decode_from(&cx, &e, ty),
+        (None, BuildData::Struct(st)) => decode_struct(&cx, &e, st)?,
+        (None, BuildData::Enum(en)) => decode_enum(&cx, &e, en)?,
     };
 
     if e.cx.has_errors() {
         return Err(());
     }
 
-    // Figure out which lifetime to use for what. We use the first lifetime in
-    // the type (if any is available) as the decoder lifetime. Else we generate
-    // a new anonymous lifetime `'de` to use for the `Decode` impl.
-    let mut generics = e.input.generics.clone();
-    let type_ident = &e.input.ident;
-
-    let (lt, exists) = if let Some(existing) = generics.lifetimes().next() {
-        (existing.clone(), true)
-    } else {
-        let lt = syn::LifetimeParam::new(syn::Lifetime::new("'de", e.input.span()));
-        (lt, false)
-    };
-
-    if !exists {
-        generics.params.push(lt.clone().into());
-    }
-
     let Tokens {
         context_t,
         result,
@@ -70,6 +73,37 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
         ..
     } = e.tokens;
 
+    let body = match e.validate_path {
+        Some((span, validate)) => {
+            let Tokens {
+                result_err,
+                result_ok,
+                ..
+            } = e.tokens;
+
+            let mark_var = e.cx.ident("validate_mark");
+            let value_var = e.cx.ident("value");
+            let error_var = e.cx.ident("validate_error");
+
+            // The body might return early, so it's evaluated in a closure to
+            // capture its output before it's validated.
+            quote_spanned! {*span=>
+                let #mark_var = #context_t::mark(#ctx_var);
+
+                let #value_var = (|| -> #result<Self, <#d_param::Cx as #context_t>::Error> {
+                    #body
+                })()?;
+
+                if let #result_err(#error_var) = #validate(#ctx_var, &#value_var) {
+                    return #result_err(#context_t::marked_message(#ctx_var, #mark_var, #error_var));
+                }
+
+                #result_ok(#value_var)
+            }
+        }
+        None => body,
+    };
+
     if !e.bounds.is_empty() && !e.decode_bounds.is_empty() {
         generics.make_where_clause().predicates.extend(
             e.bounds
@@ -79,6 +113,8 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
         );
     }
 
+    let adapters = decode_adapters(&cx, &e, &generics);
+
     let (impl_generics, _, where_clause) = generics.split_for_impl();
     let (_, type_generics, _) = e.input.generics.split_for_impl();
 
@@ -90,23 +126,206 @@ pub(crate) fn expand_decode_entry(e: Build<'_>) -> Result<TokenStream> {
 
     let mode_ident = e.expansion.mode_path(e.tokens).as_path();
 
-    Ok(quote! {
-        const _: () = {
+    let flatten = match &e.data {
+        BuildData::Struct(st) if e.flattenable.is_some() => {
+            Some(decode_flatten(&cx, &e, st, &generics))
+        }
+        _ => None,
+    };
+
+    let decode_fn = quote! {
+        #[inline]
+        fn decode<#d_param>(#ctx_var: &#d_param::Cx, #root_decoder_var: #d_param) -> #result<Self, <#d_param::Cx as #context_t>::Error>
+        where
+            #d_param: #decoder_t<#lt, Mode = #mode_ident>,
+        {
+            #body
+        }
+    };
+
+    let decode_impl = match e.remote {
+        // The body is implemented for the foreign type through a local trait,
+        // which is then exposed as an adapter through the mirror type.
+        Some((span, remote)) => {
+            let Tokens { decode_with_t, .. } = e.tokens;
+            let remote_t = e.cx.type_with_span("Remote", *span);
+
+            quote! {
+                trait #remote_t<#lt>: Sized {
+                    fn decode<#d_param>(#ctx_var: &#d_param::Cx, #root_decoder_var: #d_param) -> #result<Self, <#d_param::Cx as #context_t>::Error>
+                    where
+                        #d_param: #decoder_t<#lt, Mode = #mode_ident>;
+                }
+
+                #[automatically_derived]
+                #(#attributes)*
+                impl #impl_generics #remote_t<#lt> for #remote #where_clause {
+                    #decode_fn
+                }
+
+                #[automatically_derived]
+                impl #impl_generics #decode_with_t<#lt, #mode_ident, #remote> for #type_ident #type_generics #where_clause {
+                    #[inline]
+                    fn decode_with<#d_param>(#ctx_var: &#d_param::Cx, #root_decoder_var: #d_param) -> #result<#remote, <#d_param::Cx as #context_t>::Error>
+                    where
+                        #d_param: #decoder_t<#lt, Mode = #mode_ident>,
+                    {
+                        <#remote as #remote_t<#lt>>::decode(#ctx_var, #root_decoder_var)
+                    }
+                }
+            }
+        }
+        None => quote! {
             #[automatically_derived]
             #(#attributes)*
             impl #impl_generics #decode_t<#lt, #mode_ident> for #type_ident #type_generics #where_clause {
-                #[inline]
-                fn decode<#d_param>(#ctx_var: &#d_param::Cx, #root_decoder_var: #d_param) -> #result<Self, <#d_param::Cx as #context_t>::Error>
-                where
-                    #d_param: #decoder_t<#lt, Mode = #mode_ident>,
-                {
-                    #body
-                }
+                #decode_fn
             }
+        },
+    };
+
+    Ok(quote! {
+        const _: () = {
+            #decode_impl
+            #flatten
+            #(#adapters)*
         };
     })
 }
 
+/// Expand the `decode` function of a type with `#[musli(remote = <path>)]`,
+/// which makes it usable with `#[musli(with = <path>)]`.
+pub(crate) fn expand_remote_decode(e: &Expander<'_>, remote: &syn::Path) -> TokenStream {
+    let Tokens {
+        context_t,
+        decode_with_t,
+        decoder_t,
+        result,
+        ..
+    } = &e.tokens;
+
+    let type_ident = &e.input.ident;
+    let vis = &e.input.vis;
+    let (impl_generics, type_generics, where_clause) = e.input.generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #type_ident #type_generics #where_clause {
+            #[doc = "Decode the type mirrored through `#[musli(remote = ..)]`, for use with `#[musli(with = ..)]`."]
+            #[inline]
+            #vis fn decode<'de, D>(cx: &D::Cx, decoder: D) -> #result<#remote, <D::Cx as #context_t>::Error>
+            where
+                D: #decoder_t<'de>,
+                Self: #decode_with_t<'de, D::Mode, #remote>,
+            {
+                <Self as #decode_with_t<'de, D::Mode, #remote>>::decode_with(cx, decoder)
+            }
+        }
+    }
+}
+
+/// Implement the adapters used by fields with `#[musli(with_elements)]`,
+/// `#[musli(with_keys)]` or `#[musli(with_values)]`.
+fn decode_adapters(cx: &Ctxt<'_>, b: &Build<'_>, generics: &syn::Generics) -> Vec<TokenStream> {
+    let Ctxt {
+        ctx_var,
+        decoder_var,
+        lt,
+        ..
+    } = *cx;
+
+    let Tokens {
+        context_t,
+        decode_elements_t,
+        decode_entries_t,
+        decode_with_t,
+        decoder_t,
+        result,
+        ..
+    } = b.tokens;
+
+    let mode_ident = b.expansion.mode_path(b.tokens).as_path();
+    let (_, type_generics, _) = b.input.generics.split_for_impl();
+    let d_param = b.cx.type_with_span("D", Span::call_site());
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let mut output = Vec::new();
+
+    for f in b.fields() {
+        for adapter in &f.adapters {
+            let ty = f.ty;
+            let ident = &adapter.ident;
+            let decode = &adapter.with.decode;
+
+            let (trait_t, assoc) = match adapter.kind {
+                AdapterKind::Elements => (decode_elements_t, quote!(Element)),
+                AdapterKind::Keys => (decode_entries_t, quote!(Key)),
+                AdapterKind::Values => (decode_entries_t, quote!(Value)),
+            };
+
+            let element = quote!(<#ty as #trait_t<#lt, #mode_ident>>::#assoc);
+            let declaration = b.adapter_declaration(ident);
+
+            output.push(quote! {
+                #declaration
+
+                #[automatically_derived]
+                impl #impl_generics #decode_with_t<#lt, #mode_ident, #element> for #ident #type_generics #where_clause {
+                    #[inline]
+                    fn decode_with<#d_param>(#ctx_var: &#d_param::Cx, #decoder_var: #d_param) -> #result<#element, <#d_param::Cx as #context_t>::Error>
+                    where
+                        #d_param: #decoder_t<#lt, Mode = #mode_ident>,
+                    {
+                        #decode(#ctx_var, #decoder_var)
+                    }
+                }
+            });
+        }
+    }
+
+    output
+}
+
+/// Decode a container through the proxy type given by `#[musli(from =
+/// <type>)]` or `#[musli(try_from = <type>)]`.
+fn decode_from(cx: &Ctxt<'_>, b: &Build<'_>, ty: &syn::Type) -> TokenStream {
+    let Ctxt {
+        ctx_var,
+        decoder_var,
+        ..
+    } = *cx;
+
+    let Tokens {
+        context_t,
+        from_t,
+        result_err,
+        result_ok,
+        try_from_t,
+        ..
+    } = b.tokens;
+
+    let decode_t_decode = &b.decode_t_decode;
+    let proxy_var = b.cx.ident("proxy");
+    let value_var = b.cx.ident("value");
+    let error_var = b.cx.ident("error");
+
+    if b.try_from.is_none() {
+        return quote! {
+            let #proxy_var: #ty = #decode_t_decode(#ctx_var, #decoder_var)?;
+            #result_ok(<Self as #from_t<#ty>>::from(#proxy_var))
+        };
+    }
+
+    quote! {
+        let #proxy_var: #ty = #decode_t_decode(#ctx_var, #decoder_var)?;
+
+        match <Self as #try_from_t<#ty>>::try_from(#proxy_var) {
+            #result_ok(#value_var) => #result_ok(#value_var),
+            #result_err(#error_var) => #result_err(#context_t::custom(#ctx_var, #error_var)),
+        }
+    }
+}
+
 fn decode_struct(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream> {
     let Tokens { result_ok, .. } = b.tokens;
 
@@ -130,6 +349,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
     let Tokens {
         as_decoder_t,
+        attempt,
         context_t,
         decoder_t,
         fmt,
@@ -182,7 +402,34 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
     let mut output_arms = Vec::new();
 
+    let capture = en
+        .variants
+        .iter()
+        .find_map(|v| Some((v, v.fallback_capture()?)));
+    let unknown_tag_var = b.cx.ident("unknown_tag");
+
+    // A fallback variant with fields captures the unknown tag and the content
+    // of the variant.
+    let capture_fallback = |tag: &Ident| {
+        let (v, (tag_field, content_field)) = capture?;
+
+        let path = &v.st.path;
+        let tag_member = &tag_field.member;
+        let content_member = &content_field.member;
+        let decode = decode_field(cx, b, content_field, &body_decoder_var);
+
+        Some(quote! {{
+            let #body_decoder_var = #variant_decoder_t::decode_value(#variant_decoder_var)?;
+
+            #path {
+                #tag_member: #tag,
+                #content_member: #decode,
+            }
+        }})
+    };
+
     let mut fallback = match en.fallback {
+        Some(..) if capture.is_some() => capture_fallback(&variant_tag_var).unwrap_or_default(),
         Some(ident) => {
             quote! {{
                 if #skip(#variant_decoder_t::decode_value(#variant_decoder_var)?)? {
@@ -204,6 +451,10 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
     match en.name_method {
         NameMethod::Value => {
             for v in &en.variants {
+                if v.fallback_capture().is_some() {
+                    continue;
+                }
+
                 let arm = output_arm(v.pattern, &v.name, &binding_var);
                 output_arms.push((v, arm, &v.name));
             }
@@ -220,6 +471,10 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
             let output_type = b.cx.type_with_span("VariantTag", en.span);
 
             for v in &en.variants {
+                if v.fallback_capture().is_some() {
+                    continue;
+                }
+
                 let (pat, variant) =
                     unsized_arm(b, v.span, v.index, &v.name, v.pattern, &output_type);
 
@@ -231,12 +486,21 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
             let visit_type = &en.name_type;
             let method = method.as_method_name();
+            let unknown_variant = b.cx.type_with_span("Unknown", en.span);
+
+            let unknown = match capture {
+                Some(..) => {
+                    let to_owned = &b.tokens.to_owned;
+                    quote!(#value_var => #option_some(#output_type::#unknown_variant(#to_owned::to_owned(#value_var))))
+                }
+                None => quote!(_ => #option_none),
+            };
 
             decode_name = quote! {
                 #decoder_t::#method(#variant_decoder_var, |#value_var: &#visit_type| {
                     #result_ok(match #value_var {
                         #(#arms,)*
-                        _ => #option_none,
+                        #unknown,
                     })
                 })
             };
@@ -255,25 +519,45 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
 
             let variants = variants.iter().map(|o| &o.variant);
 
+            let unknown = capture.map(|_| {
+                let to_owned = &b.tokens.to_owned;
+
+                (
+                    quote!(#unknown_variant(<#visit_type as #to_owned>::Owned)),
+                    quote!(#output_type::#unknown_variant(ref #unknown_tag_var) => #fmt::Debug::fmt(#unknown_tag_var, f)),
+                )
+            });
+
+            let unknown_variant_decl = unknown.as_ref().map(|(decl, _)| decl);
+            let unknown_fmt = unknown.as_ref().map(|(_, fmt)| fmt);
+
             output_enum = Some(quote! {
-                enum #output_type { #(#variants,)* }
+                enum #output_type { #(#variants,)* #unknown_variant_decl }
 
                 impl #fmt::Debug for #output_type {
                     #[inline]
                     fn fmt(&self, f: &mut #fmt::Formatter<'_>) -> #fmt::Result {
-                        match *self { #(#fmt_patterns,)* }
+                        match *self { #(#fmt_patterns,)* #unknown_fmt }
                     }
                 }
 
                 impl #fmt::Display for #output_type {
                     #[inline]
                     fn fmt(&self, f: &mut #fmt::Formatter<'_>) -> #fmt::Result {
-                        match *self { #(#fmt_patterns2,)* }
+                        match *self { #(#fmt_patterns2,)* #unknown_fmt }
                     }
                 }
             });
 
-            fallback = quote!(#option_none => { #fallback });
+            fallback = match capture_fallback(&unknown_tag_var) {
+                Some(capture) => quote! {
+                    #option_some(#output_type::#unknown_variant(#unknown_tag_var)) => #capture,
+                    #option_none => {
+                        return #result_err(#context_t::invalid_variant_tag(#ctx_var, #type_name, &#variant_tag_var))
+                    }
+                },
+                None => quote!(#option_none => { #fallback }),
+            };
             name_type = syn::parse_quote!(#option<#output_type>);
         }
     }
@@ -324,7 +608,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
             let arms = output_arms.iter().flat_map(|(v, pat, tag_value)| {
                 let name = &v.st.name;
 
-                let decode = decode_variant(cx, b, v, &body_decoder_var, &variant_tag_var).ok()?;
+                let decode = decode_variant(cx, b, v, &body_decoder_var, Some(&variant_tag_var)).ok()?;
 
                 let enter = cx.trace.then(|| {
                     let (tag_decl, formatted_tag) = en.name_format(&tag_static, tag_value);
@@ -391,7 +675,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                 let name = &v.st.name;
 
                 let decode =
-                    decode_variant(cx, b, v, &buffer_decoder_var, &variant_tag_var).ok()?;
+                    decode_variant(cx, b, v, &buffer_decoder_var, Some(&variant_tag_var)).ok()?;
 
                 let enter = cx.trace.then(|| {
                     let (tag_decl, formatted_tag) = en.name_format(&tag_static, tag_value);
@@ -442,7 +726,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                             }
                             #field_var => {
                                 if #skip_field(#entry_var)? {
-                                    return #result_err(#context_t::invalid_field_tag(#ctx_var, #type_name, &#field_var));
+                                    return #result_err(#context_t::invalid_field_tag_expected(#ctx_var, #type_name, &#field_var, &[&#tag]));
                                 }
                             }
                         }
@@ -533,11 +817,87 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                 #result_ok(#output_var)
             }})
         }
+        EnumTagging::Untagged => {
+            // Variants are attempted over and over again, so tracing is
+            // disabled to avoid leaving partial traces behind.
+            let attempt_cx = Ctxt {
+                trace: false,
+                ..*cx
+            };
+
+            let output_label = syn::Lifetime::new("'output", Span::call_site());
+
+            let mut attempts = Vec::new();
+            let mut errors = Vec::new();
+
+            for v in &en.variants {
+                let is_fallback = en.fallback.is_some_and(|ident| {
+                    v.st.path.segments.last().is_some_and(|s| s.ident == *ident)
+                });
+
+                if is_fallback {
+                    continue;
+                }
+
+                let Ok(decode) = decode_variant(&attempt_cx, b, v, &buffer_decoder_var, None)
+                else {
+                    continue;
+                };
+
+                let error_var = b.cx.ident(&format!("error{}", v.index));
+                let name = &v.st.name;
+
+                attempts.push(quote! {
+                    let #error_var = match #attempt(#ctx_var, || {
+                        let #buffer_decoder_var = #as_decoder_t::as_decoder(&#buffer_var)?;
+                        #result_ok(#decode)
+                    }) {
+                        #result_ok(#output_var) => break #output_label #output_var,
+                        #result_err(#error_var) => #error_var,
+                    };
+                });
+
+                errors.push(quote!((#name, #error_var.0, #error_var.1)));
+            }
+
+            let mismatch = match en.fallback {
+                Some(ident) => quote!(Self::#ident {}),
+                None => quote! {
+                    return #result_err(#context_t::untagged_mismatch(#ctx_var, #type_name, &[#(#errors),*]))
+                },
+            };
+
+            let enter = cx.trace.then(|| {
+                quote! {
+                    #context_t::enter_enum(#ctx_var, #type_name);
+                }
+            });
+
+            let leave = cx.trace.then(|| {
+                quote! {
+                    #context_t::leave_enum(#ctx_var);
+                }
+            });
+
+            Ok(quote! {{
+                #enter
+                let #buffer_var = #decoder_t::decode_buffer(#decoder_var)?;
+
+                let #output_var = #output_label: {
+                    #(#attempts)*
+                    #mismatch
+                };
+
+                #leave
+                #result_ok(#output_var)
+            }})
+        }
         EnumTagging::Adjacent { tag, content } => {
             let arms = output_arms.iter().flat_map(|(v, pat, tag_value)| {
                 let name = &v.st.name;
 
-                let decode = decode_variant(cx, b, v, &body_decoder_var, &variant_tag_var).ok()?;
+                let decode =
+                    decode_variant(cx, b, v, &body_decoder_var, Some(&variant_tag_var)).ok()?;
 
                 let enter = cx.trace.then(|| {
                     let (tag_decl, formatted_tag) = en.name_format(&tag_static, tag_value);
@@ -600,7 +960,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                             }
                             #field_var => {
                                 if #skip_field(#entry_var)? {
-                                    return #result_err(#context_t::invalid_field_tag(#ctx_var, #type_name, &#field_var));
+                                    return #result_err(#context_t::invalid_field_tag_expected(#ctx_var, #type_name, &#field_var, &[&#tag, &#content]));
                                 }
                             }
                         }
@@ -635,7 +995,7 @@ fn decode_enum(cx: &Ctxt<'_>, b: &Build<'_>, en: &Enum) -> Result<TokenStream> {
                             }
                             #outcome_type::Content => {
                                 let #option_some(#variant_tag_var) = #name_var else {
-                                    return #result_err(#context_t::invalid_field_tag(#ctx_var, #type_name, &#tag));
+                                    return #result_err(#context_t::invalid_field_tag_expected(#ctx_var, #type_name, &#tag, &[&#tag, &#content]));
                                 };
 
                                 let #body_decoder_var = #struct_field_decoder_t::decode_value(#entry_var)?;
@@ -706,7 +1066,7 @@ fn decode_variant(
     b: &Build,
     v: &Variant<'_>,
     decoder_var: &Ident,
-    variant_tag: &Ident,
+    variant_tag: Option<&Ident>,
 ) -> Result<TokenStream, ()> {
     let cx = Ctxt {
         decoder_var,
@@ -718,7 +1078,7 @@ fn decode_variant(
         (_, Packing::Transparent) => decode_transparent(&cx, b, &v.st)?,
         (_, Packing::Packed) => decode_packed(&cx, b, &v.st)?,
         (StructKind::Empty, _) => decode_empty(&cx, b, &v.st)?,
-        (_, Packing::Tagged) => decode_tagged(&cx, b, &v.st, Some(variant_tag))?,
+        (_, Packing::Tagged) => decode_tagged(&cx, b, &v.st, variant_tag)?,
     })
 }
 
@@ -778,6 +1138,7 @@ fn decode_tagged(
         ctx_var,
         decoder_var,
         name_var,
+        lt,
         ..
     } = *cx;
 
@@ -803,17 +1164,19 @@ fn decode_tagged(
     let type_decoder_var = b.cx.ident("type_decoder");
     let value_var = b.cx.ident("value");
     let binding_var = b.cx.ident("value");
+    let flatten_tag_var = b.cx.ident("flatten_tag");
+    let deny_unknown_var = b.cx.ident("deny_unknown");
 
     let type_name = &st.name;
 
     let mut assigns = Punctuated::<_, Token![,]>::new();
 
     let mut fields_with = Vec::new();
+    let mut flattened = Vec::new();
 
     for f in &st.all_fields {
         let tag = &f.name;
         let var = &f.var;
-        let decode_path = &f.decode_path.1;
 
         let expr = match &f.skip {
             Some(span) => {
@@ -824,37 +1187,18 @@ fn decode_tagged(
                     _ => syn::Expr::Verbatim(quote_spanned!(*span => #default_function::<#ty>())),
                 }
             }
+            None if f.flatten.is_some() => {
+                let flatten_t = flatten_trait(b, lt, f.ty, &st.name_type);
+                flattened.push((&**f, flatten_t.clone()));
+                syn::Expr::Verbatim(quote!(#flatten_t::decode_flatten_finish(#ctx_var, #var)?))
+            }
             None => {
-                let formatted_tag = match &st.name_format_with {
-                    Some((_, path)) => quote!(&#path(&#tag)),
-                    None => quote!(&#tag),
-                };
+                let (enter, leave) = field_trace(cx, b, st, f);
 
-                let enter = cx.trace.then(|| {
-                    let (name, enter) = match &f.member {
-                        syn::Member::Named(name) => (
-                            syn::Lit::Str(syn::LitStr::new(&name.to_string(), name.span())),
-                            Ident::new("enter_named_field", Span::call_site()),
-                        ),
-                        syn::Member::Unnamed(index) => (
-                            syn::Lit::Int(syn::LitInt::from(Literal::u32_suffixed(index.index))),
-                            Ident::new("enter_unnamed_field", Span::call_site()),
-                        ),
-                    };
-
-                    quote! {
-                        #context_t::#enter(#ctx_var, #name, #formatted_tag);
-                    }
-                });
-
-                let leave = cx.trace.then(|| {
-                    quote! {
-                        #context_t::leave_field(#ctx_var);
-                    }
-                });
+                let decode = decode_field(cx, b, f, &struct_decoder_var);
 
                 let decode = quote! {
-                    #var = #option_some(#decode_path(#ctx_var, #struct_decoder_var)?);
+                    #var = #option_some(#decode);
                 };
 
                 fields_with.push((f, decode, (enter, leave)));
@@ -886,22 +1230,82 @@ fn decode_tagged(
         });
     }
 
+    // Fields collecting unknown entries are only offered what no other
+    // flattened field claims.
+    flattened.sort_by_key(|(f, _)| f.unknown_fields.is_some());
+
     let decode_tag;
     let mut output_enum = quote!();
 
+    let expected = fields_with.iter().map(|(f, ..)| {
+        let name = &f.name;
+        quote!(&#name)
+    });
+
+    let expected = quote!(&[#(#expected),*]);
+
     let unsupported = match variant_tag {
         Some(variant_tag) => quote! {
             #context_t::invalid_variant_field_tag(#ctx_var, #type_name, &#variant_tag, &#name_var)
         },
         None => quote! {
-            #context_t::invalid_field_tag(#ctx_var, #type_name, &#name_var)
+            #context_t::invalid_field_tag_expected(#ctx_var, #type_name, &#name_var, #expected)
         },
     };
 
-    let skip_field = quote! {
-        if #skip_field(#struct_decoder_var)? {
-            return #result_err(#unsupported);
+    let unknown = |tag: TokenStream| {
+        quote! {
+            #context_t::invalid_field_tag_expected(#ctx_var, #type_name, #tag, #expected)
+        }
+    };
+
+    // Whether unknown fields are denied is either known statically through
+    // `#[musli(deny_unknown_fields)]`, or is determined by the decoder.
+    let (deny_unknown, check_unknown) = match st.deny_unknown_fields {
+        Some(..) => (None, None),
+        None => (
+            Some(quote! {
+                let #deny_unknown_var = #struct_field_decoder_t::deny_unknown_fields(&#struct_decoder_var);
+            }),
+            Some(quote!(#deny_unknown_var)),
+        ),
+    };
+
+    // Visiting a name always fails if there are no fields to match and unknown
+    // fields are statically denied.
+    let allow_unreachable = st
+        .deny_unknown_fields
+        .is_some()
+        .then(|| quote!(#[allow(unreachable_code)]));
+
+    let skip_field = match (st.name_method, &check_unknown) {
+        (NameMethod::Value, None) => {
+            let unknown = unknown(quote!(&#name_var));
+
+            quote! {
+                return #result_err(#unknown);
+            }
+        }
+        (NameMethod::Value, Some(check_unknown)) => {
+            let unknown = unknown(quote!(&#name_var));
+
+            quote! {
+                if #check_unknown {
+                    return #result_err(#unknown);
+                }
+
+                if #skip_field(#struct_decoder_var)? {
+                    return #result_err(#unsupported);
+                }
+            }
         }
+        // Unknown fields with unsized names are denied while visiting the
+        // name, since the name isn't available after that.
+        (NameMethod::Unsized(..), _) => quote! {
+            if #skip_field(#struct_decoder_var)? {
+                return #result_err(#unsupported);
+            }
+        },
     };
 
     let body;
@@ -924,7 +1328,20 @@ fn decode_tagged(
                 });
             }
 
-            body = quote!(match #name_var { #(#arms,)* _ => { #skip_field } });
+            let mut fallback = quote!({ #skip_field });
+
+            for (f, flatten_t) in flattened.iter().rev() {
+                let var = &f.var;
+
+                fallback = quote! {
+                    if let #option_some(#flatten_tag_var) = #flatten_t::flatten_tag(#ctx_var, &#name_var)? {
+                        let #struct_decoder_var = #struct_field_decoder_t::decode_value(#struct_decoder_var)?;
+                        #flatten_t::decode_flatten_entry(#ctx_var, &mut #var, #flatten_tag_var, #struct_decoder_var)?;
+                    } else #fallback
+                };
+            }
+
+            body = quote!(match #name_var { #(#arms,)* _ => #fallback });
 
             let decode_t_decode = &b.decode_t_decode;
 
@@ -949,7 +1366,16 @@ fn decode_tagged(
                 name_arms.push((name_pat, decode, trace));
             }
 
-            if !name_arms.is_empty() {
+            let mut flatten_outputs = Vec::with_capacity(flattened.len());
+
+            for (f, flatten_t) in &flattened {
+                let variant =
+                    b.cx.type_with_span(format_args!("Flatten{}", f.index), f.span);
+                let param = b.cx.type_with_span(format_args!("Tag{}", f.index), f.span);
+                flatten_outputs.push((*f, flatten_t, variant, param));
+            }
+
+            if !name_arms.is_empty() || !flatten_outputs.is_empty() {
                 let arms = name_arms
                     .into_iter()
                     .map(|(name_pat, decode, (enter, leave))| {
@@ -963,8 +1389,19 @@ fn decode_tagged(
                         }
                     });
 
+                let flatten_arms = flatten_outputs.iter().map(|(f, flatten_t, variant, _)| {
+                    let var = &f.var;
+
+                    quote! {
+                        #option_some(#output_type::#variant(#flatten_tag_var)) => {
+                            let #struct_decoder_var = #struct_field_decoder_t::decode_value(#struct_decoder_var)?;
+                            #flatten_t::decode_flatten_entry(#ctx_var, &mut #var, #flatten_tag_var, #struct_decoder_var)?;
+                        }
+                    }
+                });
+
                 body = quote! {
-                    match #name_var { #(#arms,)* #name_var => { #skip_field } }
+                    match #name_var { #(#arms,)* #(#flatten_arms,)* #name_var => { #skip_field } }
                 }
             } else {
                 body = skip_field;
@@ -975,12 +1412,35 @@ fn decode_tagged(
             let visit_type = &st.name_type;
             let method = method.as_method_name();
 
+            let unknown = unknown(quote!(#value_var));
+
+            let mut fallback = match &check_unknown {
+                Some(check_unknown) => quote! {
+                    if #check_unknown {
+                        return #result_err(#unknown);
+                    } else {
+                        #option_none
+                    }
+                },
+                None => quote!(return #result_err(#unknown)),
+            };
+
+            for (_, flatten_t, variant, _) in flatten_outputs.iter().rev() {
+                fallback = quote! {
+                    if let #option_some(#flatten_tag_var) = #flatten_t::flatten_tag(#ctx_var, #value_var)? {
+                        #option_some(#output_type::#variant(#flatten_tag_var))
+                    } else {
+                        #fallback
+                    }
+                };
+            }
+
             decode_tag = quote! {
                 #decoder_t::#method(#struct_decoder_var, |#value_var: &#visit_type| {
                     #result_ok(match #value_var {
                         #(#arms,)*
                         #value_var => {
-                            #option_none
+                            #fallback
                         }
                     })
                 })?
@@ -988,37 +1448,67 @@ fn decode_tagged(
 
             let variants = outputs.iter().map(|o| &o.variant);
 
+            let flatten_variants = flatten_outputs
+                .iter()
+                .map(|(_, _, variant, param)| quote!(#variant(#param)));
+
+            let params = flatten_outputs
+                .iter()
+                .map(|(_, _, _, param)| param)
+                .collect::<Vec<_>>();
+
+            let generics = (!params.is_empty()).then(|| quote!(<#(#params),*>));
+            let inferred = (!params.is_empty()).then(|| {
+                let params = params.iter().map(|_| quote!(_));
+                quote!(<#(#params),*>)
+            });
+
             let fmt_patterns = outputs.iter().map(|o| {
                 let variant = &o.variant;
                 let tag = o.name;
                 quote!(#output_type::#variant => #fmt::Debug::fmt(&#tag, f))
             });
 
+            let flatten_fmt_patterns = flatten_outputs.iter().map(|(f, _, variant, _)| {
+                let name = match &f.member {
+                    syn::Member::Named(ident) => ident.to_string(),
+                    syn::Member::Unnamed(index) => index.index.to_string(),
+                };
+
+                quote!(#output_type::#variant(..) => #fmt::Debug::fmt(#name, f))
+            });
+
             output_enum = quote! {
-                enum #output_type {
+                enum #output_type #generics {
                     #(#variants,)*
+                    #(#flatten_variants,)*
                 }
 
-                impl #fmt::Debug for #output_type {
+                impl #generics #fmt::Debug for #output_type #generics {
                     #[inline]
                     fn fmt(&self, f: &mut #fmt::Formatter<'_>) -> #fmt::Result {
-                        match *self { #(#fmt_patterns,)* }
+                        match *self { #(#fmt_patterns,)* #(#flatten_fmt_patterns,)* }
                     }
                 }
             };
 
-            name_type = syn::parse_quote!(#option<#output_type>);
+            name_type = syn::parse_quote!(#option<#output_type #inferred>);
         }
     }
 
     let path = &st.path;
     let fields_len = st.unskipped_fields.len();
 
-    let decls = st
-        .unskipped_fields
-        .iter()
-        .map(|f| &**f)
-        .map(|Field { var, ty, .. }| quote!(let mut #var: #option<#ty> = #option_none;));
+    let decls = st.unskipped_fields.iter().map(|f| {
+        let Field { var, ty, .. } = &**f;
+
+        if f.flatten.is_some() {
+            let flatten_t = flatten_trait(b, lt, ty, &st.name_type);
+            quote!(let mut #var = #flatten_t::flatten_state();)
+        } else {
+            quote!(let mut #var: #option<#ty> = #option_none;)
+        }
+    });
 
     let enter = (cx.trace && cx.trace_body).then(|| {
         quote! {
@@ -1042,6 +1532,9 @@ fn decode_tagged(
 
         #decoder_t::decode_map_hint(#decoder_var, &#struct_hint_static, move |#type_decoder_var| {
             while let #option_some(mut #struct_decoder_var) = #map_decoder_t::decode_entry(#type_decoder_var)? {
+                #deny_unknown
+
+                #allow_unreachable
                 let #name_var: #name_type = {
                     let #struct_decoder_var = #struct_field_decoder_t::decode_key(&mut #struct_decoder_var)?;
                     #decode_tag
@@ -1056,6 +1549,355 @@ fn decode_tagged(
     }})
 }
 
+/// Generate the tracing calls used when entering and leaving a field.
+fn field_trace(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    st: &Body<'_>,
+    f: &Field<'_>,
+) -> (Option<TokenStream>, Option<TokenStream>) {
+    let Ctxt { ctx_var, .. } = *cx;
+    let context_t = &b.tokens.context_t;
+    let tag = &f.name;
+
+    let formatted_tag = match &st.name_format_with {
+        Some((_, path)) => quote!(&#path(&#tag)),
+        None => quote!(&#tag),
+    };
+
+    let enter = cx.trace.then(|| {
+        let (name, enter) = match &f.member {
+            syn::Member::Named(name) => (
+                syn::Lit::Str(syn::LitStr::new(&name.to_string(), name.span())),
+                Ident::new("enter_named_field", Span::call_site()),
+            ),
+            syn::Member::Unnamed(index) => (
+                syn::Lit::Int(syn::LitInt::from(Literal::u32_suffixed(index.index))),
+                Ident::new("enter_unnamed_field", Span::call_site()),
+            ),
+        };
+
+        quote! {
+            #context_t::#enter(#ctx_var, #name, #formatted_tag);
+        }
+    });
+
+    let leave = cx.trace.then(|| {
+        quote! {
+            #context_t::leave_field(#ctx_var);
+        }
+    });
+
+    (enter, leave)
+}
+
+/// Implement `DecodeFlatten` for a struct decoded from a map, so that it can be
+/// flattened into an enclosing container.
+fn decode_flatten(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    st: &Body<'_>,
+    generics: &syn::Generics,
+) -> TokenStream {
+    let Ctxt {
+        ctx_var,
+        decoder_var,
+        lt,
+        ..
+    } = *cx;
+
+    let Tokens {
+        context_t,
+        decoder_t,
+        decode_flatten_t,
+        default_function,
+        option_none,
+        option_some,
+        option,
+        phantom_data,
+        result_err,
+        result_ok,
+        result,
+        ..
+    } = b.tokens;
+
+    let mode_ident = b.expansion.mode_path(b.tokens).as_path();
+    let type_ident = &b.input.ident;
+    let type_name = &st.name;
+    let name_type = &st.name_type;
+
+    let state_type = b.cx.type_with_span("FlattenState", type_ident.span());
+    let tag_type = b.cx.type_with_span("FlattenTag", type_ident.span());
+    let kind_type = b.cx.type_with_span("FlattenKind", type_ident.span());
+    let c_param = b.cx.type_with_span("C", Span::call_site());
+    let d_param = b.cx.type_with_span("D", Span::call_site());
+    let state_var = b.cx.ident("state");
+    let name_var = b.cx.ident("name");
+    let tag_var = b.cx.ident("flatten_kind");
+    let nested_tag_var = b.cx.ident("flatten_tag");
+    let binding_var = b.cx.ident("value");
+    let marker_var = b.cx.ident("marker");
+
+    let mut generics = generics.clone();
+
+    let mut state_fields = Vec::new();
+    let mut inits = Vec::new();
+    let mut kind_variants = Vec::new();
+    let mut kind_params = Vec::new();
+    let mut kind_args = Vec::new();
+    let mut tag_arms = Vec::new();
+    let mut nested = Vec::new();
+    let mut entry_arms = Vec::new();
+    let mut assigns = Punctuated::<_, Token![,]>::new();
+
+    for f in &st.all_fields {
+        let var = &f.var;
+        let ty = f.ty;
+
+        let expr = match &f.skip {
+            Some(span) => match &f.default_attr {
+                Some((_, Some(path))) => quote_spanned!(*span => #path()),
+                _ => quote_spanned!(*span => #default_function::<#ty>()),
+            },
+            None if f.flatten.is_some() => {
+                let flatten_t = flatten_trait(b, lt, ty, name_type);
+                let variant =
+                    b.cx.type_with_span(format_args!("Flatten{}", f.index), f.span);
+                let param = b.cx.type_with_span(format_args!("Tag{}", f.index), f.span);
+
+                generics
+                    .make_where_clause()
+                    .predicates
+                    .push(syn::parse_quote!(#ty: #decode_flatten_t<#lt, #mode_ident, #name_type>));
+
+                state_fields.push(quote!(#var: #flatten_t::State));
+                inits.push(quote!(#var: #flatten_t::flatten_state()));
+                kind_variants.push(quote!(#variant(#param)));
+                kind_params.push(param);
+                kind_args.push(quote!(#flatten_t::Tag));
+
+                entry_arms.push(quote! {
+                    #kind_type::#variant(#nested_tag_var) => {
+                        #flatten_t::decode_flatten_entry(#ctx_var, &mut #state_var.#var, #nested_tag_var, #decoder_var)?;
+                    }
+                });
+
+                let expr = quote!(#flatten_t::decode_flatten_finish(#ctx_var, #state_var.#var)?);
+                nested.push((f.unknown_fields.is_some(), flatten_t, variant));
+                expr
+            }
+            None => {
+                let variant =
+                    b.cx.type_with_span(format_args!("Field{}", f.index), f.span);
+                let arm = output_arm(f.pattern, &f.name, &binding_var);
+                let decode = decode_field(cx, b, f, decoder_var);
+                let (enter, leave) = field_trace(cx, b, st, f);
+
+                state_fields.push(quote!(#var: #option<#ty>));
+                inits.push(quote!(#var: #option_none));
+                kind_variants.push(quote!(#variant));
+                tag_arms.push(quote!(#arm => #kind_type::#variant));
+
+                entry_arms.push(quote! {
+                    #kind_type::#variant => {
+                        #enter
+                        #state_var.#var = #option_some(#decode);
+                        #leave
+                    }
+                });
+
+                let tag = &f.name;
+
+                let fallback = match f.default_attr {
+                    Some((span, None)) => quote_spanned!(span => #default_function()),
+                    Some((_, Some(path))) => quote!(#path()),
+                    None => quote! {
+                        return #result_err(#context_t::expected_tag(#ctx_var, #type_name, &#tag))
+                    },
+                };
+
+                quote! {
+                    match #state_var.#var {
+                        #option_some(#var) => #var,
+                        #option_none => #fallback,
+                    }
+                }
+            }
+        };
+
+        assigns.push(syn::FieldValue {
+            attrs: Vec::new(),
+            member: f.member.clone(),
+            colon_token: Some(<Token![:]>::default()),
+            expr: syn::Expr::Verbatim(expr),
+        });
+    }
+
+    let flatten_tag = if kind_variants.is_empty() {
+        quote!(#result_ok(#option_none))
+    } else {
+        let mut fallback = quote!(return #result_ok(#option_none));
+
+        nested.sort_by_key(|(unknown_fields, ..)| *unknown_fields);
+
+        for (_, flatten_t, variant) in nested.iter().rev() {
+            fallback = quote! {
+                if let #option_some(#nested_tag_var) = #flatten_t::flatten_tag(#ctx_var, #name_var)? {
+                    #kind_type::#variant(#nested_tag_var)
+                } else {
+                    #fallback
+                }
+            };
+        }
+
+        let matched = match st.name_method {
+            NameMethod::Value => quote!(*#name_var),
+            NameMethod::Unsized(..) => quote!(#name_var),
+        };
+
+        quote! {
+            let #tag_var = match #matched {
+                #(#tag_arms,)*
+                _ => { #fallback }
+            };
+
+            #result_ok(#option_some(#tag_type(#tag_var, #phantom_data)))
+        }
+    };
+
+    let decode_entry = if entry_arms.is_empty() {
+        quote!(match #tag_var.0 {})
+    } else {
+        quote! {
+            match #tag_var.0 {
+                #(#entry_arms)*
+            }
+
+            #result_ok(())
+        }
+    };
+
+    let path = &st.path;
+    let kind_generics = (!kind_params.is_empty()).then(|| quote!(<#(#kind_params),*>));
+    let kind_args = (!kind_args.is_empty()).then(|| quote!(<#(#kind_args),*>));
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let (_, self_generics, _) = b.input.generics.split_for_impl();
+
+    let mut attributes = Vec::<syn::Attribute>::new();
+
+    if cfg!(not(feature = "verbose")) {
+        attributes.push(syn::parse_quote!(#[allow(clippy::just_underscores_and_digits)]));
+    }
+
+    quote! {
+        enum #kind_type #kind_generics {
+            #(#kind_variants,)*
+        }
+
+        #[doc(hidden)]
+        pub struct #state_type #impl_generics #where_clause {
+            #(#state_fields,)*
+            #marker_var: #phantom_data<fn() -> (&#lt (), #type_ident #self_generics)>,
+        }
+
+        #[doc(hidden)]
+        pub struct #tag_type #impl_generics (
+            #kind_type #kind_args,
+            #phantom_data<fn() -> (&#lt (), #type_ident #self_generics)>,
+        ) #where_clause;
+
+        #[automatically_derived]
+        #(#attributes)*
+        impl #impl_generics #decode_flatten_t<#lt, #mode_ident, #name_type> for #type_ident #self_generics #where_clause {
+            type Tag = #tag_type #type_generics;
+            type State = #state_type #type_generics;
+
+            #[inline]
+            fn flatten_state() -> Self::State {
+                #state_type {
+                    #(#inits,)*
+                    #marker_var: #phantom_data,
+                }
+            }
+
+            #[inline]
+            fn flatten_tag<#c_param>(#ctx_var: &#c_param, #name_var: &#name_type) -> #result<#option<Self::Tag>, #c_param::Error>
+            where
+                #c_param: ?Sized + #context_t<Mode = #mode_ident>,
+            {
+                #flatten_tag
+            }
+
+            #[inline]
+            fn decode_flatten_entry<#d_param>(#ctx_var: &#d_param::Cx, #state_var: &mut Self::State, #tag_var: Self::Tag, #decoder_var: #d_param) -> #result<(), #d_param::Error>
+            where
+                #d_param: #decoder_t<#lt, Mode = #mode_ident>,
+            {
+                #decode_entry
+            }
+
+            #[inline]
+            fn decode_flatten_finish<#c_param>(#ctx_var: &#c_param, #state_var: Self::State) -> #result<Self, #c_param::Error>
+            where
+                #c_param: ?Sized + #context_t<Mode = #mode_ident>,
+            {
+                #result_ok(#path { #assigns })
+            }
+        }
+    }
+}
+
+/// Decode the value of a field, followed by a call to the function specified
+/// through `#[musli(validate = <path>)]` if present.
+///
+/// A validation error is reported as spanning the whole value of the field.
+fn decode_field(cx: &Ctxt<'_>, b: &Build<'_>, f: &Field<'_>, decoder_var: &Ident) -> TokenStream {
+    let ctx_var = cx.ctx_var;
+    let decode_path = &f.decode_path.1;
+
+    let Some((span, validate)) = f.validate else {
+        return quote!(#decode_path(#ctx_var, #decoder_var)?);
+    };
+
+    let Tokens {
+        context_t,
+        result_err,
+        ..
+    } = b.tokens;
+
+    let ty = f.ty;
+    let mark_var = b.cx.ident("validate_mark");
+    let value_var = b.cx.ident("field_value");
+    let error_var = b.cx.ident("validate_error");
+
+    quote_spanned! {*span=>
+        {
+            let #mark_var = #context_t::mark(#ctx_var);
+            let #value_var: #ty = #decode_path(#ctx_var, #decoder_var)?;
+
+            if let #result_err(#error_var) = #validate(#ctx_var, &#value_var) {
+                return #result_err(#context_t::marked_message(#ctx_var, #mark_var, #error_var));
+            }
+
+            #value_var
+        }
+    }
+}
+
+/// Construct a qualified path to the `DecodeFlatten` implementation of the
+/// given type.
+fn flatten_trait(
+    b: &Build<'_>,
+    lt: &syn::Lifetime,
+    ty: &syn::Type,
+    name_type: &syn::Type,
+) -> TokenStream {
+    let decode_flatten_t = &b.tokens.decode_flatten_t;
+    let mode_ident = b.expansion.mode_path(b.tokens).as_path();
+    quote!(<#ty as #decode_flatten_t<#lt, #mode_ident, #name_type>>)
+}
+
 /// Decode a transparent value.
 fn decode_transparent(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream> {
     let Ctxt {
@@ -1072,7 +1914,7 @@ fn decode_transparent(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<Tok
 
     let type_name = &st.name;
     let path = &st.path;
-    let decode_path = &f.decode_path.1;
+    let decode = decode_field(cx, b, f, decoder_var);
     let member = &f.member;
 
     let enter = (cx.trace && cx.trace_body).then(|| {
@@ -1091,7 +1933,7 @@ fn decode_transparent(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<Tok
         #enter
 
         let #output_var = #path {
-            #member: #decode_path(#ctx_var, #decoder_var)?
+            #member: #decode
         };
 
         #leave
@@ -1125,7 +1967,7 @@ fn decode_packed(cx: &Ctxt<'_>, b: &Build<'_>, st_: &Body<'_>) -> Result<TokenSt
             b.packed_default_diagnostics(span);
         }
 
-        let (_, decode_path) = &f.decode_path;
+        let decode = decode_field(cx, b, f, &field_decoder);
         let member = &f.member;
         let field_decoder = &field_decoder;
 
@@ -1133,7 +1975,7 @@ fn decode_packed(cx: &Ctxt<'_>, b: &Build<'_>, st_: &Body<'_>) -> Result<TokenSt
             tokens.extend(quote! {
                 #member: {
                     let #field_decoder = #pack_decoder_t::decode_next(#ident)?;
-                    #decode_path(#ctx_var, #field_decoder)?
+                    #decode
                 }
             })
         });
@@ -3,8 +3,9 @@ use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::Token;
 
+use crate::expander::Expander;
 use crate::internals::attr::{EnumTagging, Packing};
-use crate::internals::build::{Body, Build, BuildData, Enum, Variant};
+use crate::internals::build::{AdapterKind, Body, Build, BuildData, Enum, Field, Variant};
 use crate::internals::tokens::Tokens;
 use crate::internals::Result;
 
@@ -37,9 +38,17 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
         ..
     } = e.tokens;
 
-    let body = match &e.data {
-        BuildData::Struct(st) => encode_map(&cx, &e, st)?,
-        BuildData::Enum(en) => encode_enum(&cx, &e, en)?,
+    let body = match (e.into, &e.data) {
+        (Some((_, ty)), _) => encode_into(&cx, &e, ty),
+        (None, BuildData::Struct(st)) => encode_map(&cx, &e, st)?,
+        (None, BuildData::Enum(en)) => encode_enum(&cx, &e, en)?,
+    };
+
+    let flatten = match &e.data {
+        BuildData::Struct(st) if e.flattenable.is_some() => {
+            Some(encode_flatten(&cx, &e, st, &e_param)?)
+        }
+        _ => None,
     };
 
     if e.cx.has_errors() {
@@ -56,9 +65,15 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
             .extend(e.bounds.iter().map(|(_, v)| v.clone()));
     }
 
+    let adapters = encode_adapters(&cx, &e, &impl_generics);
+
     let (impl_generics, _, where_clause) = impl_generics.split_for_impl();
     let (_, type_generics, _) = e.input.generics.split_for_impl();
 
+    let Tokens {
+        encode_flatten_t, ..
+    } = e.tokens;
+
     let mut attributes = Vec::<syn::Attribute>::new();
 
     if cfg!(not(feature = "verbose")) {
@@ -67,23 +82,239 @@ pub(crate) fn expand_insert_entry(e: Build<'_>) -> Result<TokenStream> {
 
     let mode_ident = e.expansion.mode_path(e.tokens).as_path();
 
-    Ok(quote! {
-        const _: () = {
+    let flatten = flatten.map(|flatten| {
+        quote! {
             #[automatically_derived]
             #(#attributes)*
-            impl #impl_generics #encode_t<#mode_ident> for #type_ident #type_generics #where_clause {
-                #[inline]
-                fn encode<#e_param>(&self, #ctx_var: &#e_param::Cx, #encoder_var: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
-                where
-                    #e_param: #encoder_t<Mode = #mode_ident>,
-                {
-                    #body
+            impl #impl_generics #encode_flatten_t<#mode_ident> for #type_ident #type_generics #where_clause {
+                #flatten
+            }
+        }
+    });
+
+    let encode_fn = quote! {
+        #[inline]
+        fn encode<#e_param>(&self, #ctx_var: &#e_param::Cx, #encoder_var: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
+        where
+            #e_param: #encoder_t<Mode = #mode_ident>,
+        {
+            #body
+        }
+    };
+
+    let encode_impl = match e.remote {
+        // The body is implemented for the foreign type through a local trait,
+        // which is then exposed as an adapter through the mirror type.
+        Some((span, remote)) => {
+            let Tokens { encode_with_t, .. } = e.tokens;
+            let remote_t = e.cx.type_with_span("Remote", *span);
+            let value_var = e.cx.ident("value");
+
+            quote! {
+                trait #remote_t {
+                    fn encode<#e_param>(&self, #ctx_var: &#e_param::Cx, #encoder_var: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
+                    where
+                        #e_param: #encoder_t<Mode = #mode_ident>;
                 }
+
+                #[automatically_derived]
+                #(#attributes)*
+                impl #impl_generics #remote_t for #remote #where_clause {
+                    #encode_fn
+                }
+
+                #[automatically_derived]
+                impl #impl_generics #encode_with_t<#mode_ident, #remote> for #type_ident #type_generics #where_clause {
+                    #[inline]
+                    fn encode_with<#e_param>(#value_var: &#remote, #ctx_var: &#e_param::Cx, #encoder_var: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
+                    where
+                        #e_param: #encoder_t<Mode = #mode_ident>,
+                    {
+                        #remote_t::encode(#value_var, #ctx_var, #encoder_var)
+                    }
+                }
+            }
+        }
+        None => quote! {
+            #[automatically_derived]
+            #(#attributes)*
+            impl #impl_generics #encode_t<#mode_ident> for #type_ident #type_generics #where_clause {
+                #encode_fn
             }
+        },
+    };
+
+    Ok(quote! {
+        const _: () = {
+            #encode_impl
+            #flatten
+            #(#adapters)*
         };
     })
 }
 
+/// Expand the `encode` function of a type with `#[musli(remote = <path>)]`,
+/// which makes it usable with `#[musli(with = <path>)]`.
+pub(crate) fn expand_remote_encode(e: &Expander<'_>, remote: &syn::Path) -> TokenStream {
+    let Tokens {
+        encode_with_t,
+        encoder_t,
+        result,
+        ..
+    } = &e.tokens;
+
+    let type_ident = &e.input.ident;
+    let vis = &e.input.vis;
+    let (impl_generics, type_generics, where_clause) = e.input.generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #type_ident #type_generics #where_clause {
+            #[doc = "Encode the type mirrored through `#[musli(remote = ..)]`, for use with `#[musli(with = ..)]`."]
+            #[inline]
+            #vis fn encode<E>(value: &#remote, cx: &E::Cx, encoder: E) -> #result<<E as #encoder_t>::Ok, <E as #encoder_t>::Error>
+            where
+                E: #encoder_t,
+                Self: #encode_with_t<E::Mode, #remote>,
+            {
+                <Self as #encode_with_t<E::Mode, #remote>>::encode_with(value, cx, encoder)
+            }
+        }
+    }
+}
+
+/// Implement the adapters used by fields with `#[musli(with_elements)]`,
+/// `#[musli(with_keys)]` or `#[musli(with_values)]`.
+fn encode_adapters(cx: &Ctxt<'_>, b: &Build<'_>, generics: &syn::Generics) -> Vec<TokenStream> {
+    let Ctxt {
+        ctx_var,
+        encoder_var,
+        ..
+    } = *cx;
+
+    let Tokens {
+        encode_elements_t,
+        encode_entries_t,
+        encode_with_t,
+        encoder_t,
+        result,
+        ..
+    } = b.tokens;
+
+    let mode_ident = b.expansion.mode_path(b.tokens).as_path();
+    let (_, type_generics, _) = b.input.generics.split_for_impl();
+    let value_var = b.cx.ident("value");
+    let e_param = b.cx.type_with_span("E", Span::call_site());
+
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let mut output = Vec::new();
+
+    for f in b.fields() {
+        for adapter in &f.adapters {
+            let ty = f.ty;
+            let ident = &adapter.ident;
+            let encode = &adapter.with.encode;
+
+            let (trait_t, assoc) = match adapter.kind {
+                AdapterKind::Elements => (encode_elements_t, quote!(Element)),
+                AdapterKind::Keys => (encode_entries_t, quote!(Key)),
+                AdapterKind::Values => (encode_entries_t, quote!(Value)),
+            };
+
+            let element = quote!(<#ty as #trait_t<#mode_ident>>::#assoc);
+            let declaration = b.adapter_declaration(ident);
+
+            output.push(quote! {
+                #declaration
+
+                #[automatically_derived]
+                impl #impl_generics #encode_with_t<#mode_ident, #element> for #ident #type_generics #where_clause {
+                    #[inline]
+                    fn encode_with<#e_param>(#value_var: &#element, #ctx_var: &#e_param::Cx, #encoder_var: #e_param) -> #result<<#e_param as #encoder_t>::Ok, <#e_param as #encoder_t>::Error>
+                    where
+                        #e_param: #encoder_t<Mode = #mode_ident>,
+                    {
+                        #encode(#value_var, #ctx_var, #encoder_var)
+                    }
+                }
+            });
+        }
+    }
+
+    output
+}
+
+/// Encode a container by converting a clone of it into the proxy type given
+/// by `#[musli(into = <type>)]`.
+fn encode_into(cx: &Ctxt<'_>, b: &Build<'_>, ty: &syn::Type) -> TokenStream {
+    let Ctxt {
+        ctx_var,
+        encoder_var,
+        ..
+    } = *cx;
+
+    let Tokens {
+        clone_t, into_t, ..
+    } = b.tokens;
+
+    let encode_t_encode = &b.encode_t_encode;
+    let proxy_var = b.cx.ident("proxy");
+
+    quote! {
+        let #proxy_var: #ty = #into_t::into(#clone_t::clone(self));
+        #encode_t_encode(&#proxy_var, #ctx_var, #encoder_var)
+    }
+}
+
+/// Encode the fields of a struct into the map of an enclosing container.
+fn encode_flatten(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    st: &Body<'_>,
+    e_param: &syn::Ident,
+) -> Result<TokenStream> {
+    let Ctxt {
+        ctx_var,
+        encoder_var,
+        ..
+    } = *cx;
+
+    let Tokens {
+        context_t,
+        map_encoder_t,
+        result_ok,
+        result,
+        ..
+    } = b.tokens;
+
+    let pack_var = b.cx.ident("pack");
+    let (encoders, tests) = insert_fields(cx, b, st, &pack_var)?;
+
+    let len_decls = tests.iter().map(|t| &t.decl);
+    let decls = tests.iter().filter(|t| !t.flatten).map(|t| &t.decl);
+    let len = length_test(st.unskipped_fields.len(), &tests).expressions;
+
+    let mode_ident = b.expansion.mode_path(b.tokens).as_path();
+
+    Ok(quote! {
+        #[inline]
+        fn flatten_len(&self) -> usize {
+            #(#len_decls)*
+            #len
+        }
+
+        #[inline]
+        fn encode_flatten<#e_param>(&self, #ctx_var: &#e_param::Cx, #encoder_var: &mut #e_param) -> #result<(), <#e_param::Cx as #context_t>::Error>
+        where
+            #e_param: #map_encoder_t<Cx: #context_t<Mode = #mode_ident>>,
+        {
+            #(#decls)*
+            #(#encoders)*
+            #result_ok(())
+        }
+    })
+}
+
 /// Encode a struct.
 fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream> {
     let Ctxt {
@@ -168,6 +399,8 @@ fn encode_map(cx: &Ctxt<'_>, b: &Build<'_>, st: &Body<'_>) -> Result<TokenStream
 struct FieldTest<'st> {
     decl: syn::Stmt,
     var: &'st syn::Ident,
+    /// The test is the number of entries in a flattened field.
+    flatten: bool,
 }
 
 fn insert_fields<'st>(
@@ -184,6 +417,7 @@ fn insert_fields<'st>(
 
     let Tokens {
         context_t,
+        encode_flatten_t,
         sequence_encoder_t,
         result_ok,
 
@@ -193,6 +427,7 @@ fn insert_fields<'st>(
     } = b.tokens;
 
     let encode_t_encode = &b.encode_t_encode;
+    let mode_ident = b.expansion.mode_path(b.tokens).as_path();
 
     let sequence_decoder_next_var = b.cx.ident("sequence_decoder_next");
     let pair_encoder_var = b.cx.ident("pair_encoder");
@@ -209,6 +444,26 @@ fn insert_fields<'st>(
         let name = &f.name;
         let name_type = st.name_local_type();
 
+        if f.flatten.is_some() {
+            let var = &f.var;
+
+            let decl = syn::parse_quote! {
+                let #var = #encode_flatten_t::<#mode_ident>::flatten_len(#access);
+            };
+
+            encoders.push(quote! {
+                #encode_flatten_t::<#mode_ident>::encode_flatten(#access, #ctx_var, #encoder_var)?;
+            });
+
+            tests.push(FieldTest {
+                decl,
+                var,
+                flatten: true,
+            });
+
+            continue;
+        }
+
         let mut encode;
 
         let enter = match &f.member {
@@ -271,7 +526,11 @@ fn insert_fields<'st>(
                 }
             };
 
-            tests.push(FieldTest { decl, var })
+            tests.push(FieldTest {
+                decl,
+                var,
+                flatten: false,
+            })
         }
 
         encoders.push(encode);
@@ -323,6 +582,10 @@ fn encode_variant(
     en: &Enum<'_>,
     v: &Variant<'_>,
 ) -> Result<(syn::PatStruct, TokenStream)> {
+    if let Some((tag, content)) = v.fallback_capture() {
+        return Ok(encode_fallback_capture(cx, b, en, v, tag, content));
+    }
+
     let pack_var = b.cx.ident("pack");
 
     let (encoders, tests) = insert_fields(cx, b, &v.st, &pack_var)?;
@@ -366,7 +629,7 @@ fn encode_variant(
                 #encode_t_encode(&#name_static, #ctx_var, #encoder_var)?
             }};
         }
-        EnumTagging::Default => {
+        EnumTagging::Default | EnumTagging::Untagged => {
             match v.st.packing {
                 Packing::Transparent => {
                     let f = &v.st.unskipped_fields[0];
@@ -392,10 +655,10 @@ fn encode_variant(
                         length_test(v.st.unskipped_fields.len(), &tests).build(b);
 
                     encode = quote! {{
+                        #(#decls)*
                         #build_hint
 
                         #encoder_t::encode_map_fn(#encoder_var, &#hint, move |#encoder_var| {
-                            #(#decls)*
                             #(#encoders)*
                             #result_ok(())
                         })?
@@ -403,7 +666,7 @@ fn encode_variant(
                 }
             }
 
-            if let Packing::Tagged = en.enum_packing {
+            if let (EnumTagging::Default, Packing::Tagged) = (&en.enum_tagging, en.enum_packing) {
                 let encode_t_encode = &b.encode_t_encode;
                 let name = &v.name;
                 let static_type = en.static_type();
@@ -436,13 +699,13 @@ fn encode_variant(
             let (build_hint, hint) = len.build(b);
 
             encode = quote! {{
+                #(#decls)*
                 #build_hint
 
                 #encoder_t::encode_map_fn(#encoder_var, &#hint, move |#encoder_var| {
                     static #tag_static: #static_type = #tag;
                     static #name_static: #static_type = #name;
                     #map_encoder_t::insert_entry(#encoder_var, #tag_static, #name_static)?;
-                    #(#decls)*
                     #(#encoders)*
                     #result_ok(())
                 })?
@@ -465,6 +728,7 @@ fn encode_variant(
 
             encode = quote! {{
                 static #hint: #map_hint = #map_hint::with_size(2);
+                #(#decls)*
                 #build_hint
 
                 #encoder_t::encode_map_fn(#encoder_var, &#hint, move |#struct_encoder| {
@@ -481,7 +745,6 @@ fn encode_variant(
                         let #content_struct = #map_entry_encoder_t::encode_value(#pair)?;
 
                         #encoder_t::encode_map_fn(#content_struct, &#inner_hint, move |#encoder_var| {
-                            #(#decls)*
                             #(#encoders)*
                             #result_ok(())
                         })?;
@@ -523,6 +786,64 @@ fn encode_variant(
     Ok((pattern, encode))
 }
 
+/// Encode a fallback variant which captured an unknown tag and its content,
+/// re-emitting them as they were.
+fn encode_fallback_capture(
+    cx: &Ctxt<'_>,
+    b: &Build<'_>,
+    en: &Enum<'_>,
+    v: &Variant<'_>,
+    tag: &Field<'_>,
+    content: &Field<'_>,
+) -> (syn::PatStruct, TokenStream) {
+    let Ctxt {
+        ctx_var,
+        encoder_var,
+        ..
+    } = *cx;
+
+    let Tokens {
+        encoder_t,
+        result_ok,
+        variant_encoder_t,
+        ..
+    } = b.tokens;
+
+    let tag_encoder = b.cx.ident("tag_encoder");
+    let variant_encoder = b.cx.ident("variant_encoder");
+
+    let tag_path = &tag.encode_path.1;
+    let tag_access = &tag.self_access;
+    let content_path = &content.encode_path.1;
+    let content_access = &content.self_access;
+
+    let mut encode = quote!(#content_path(#content_access, #ctx_var, #encoder_var)?);
+
+    if let Packing::Tagged = en.enum_packing {
+        encode = quote! {{
+            #encoder_t::encode_variant_fn(#encoder_var, move |#variant_encoder| {
+                let #tag_encoder = #variant_encoder_t::encode_tag(#variant_encoder)?;
+                #tag_path(#tag_access, #ctx_var, #tag_encoder)?;
+
+                let #encoder_var = #variant_encoder_t::encode_data(#variant_encoder)?;
+                #encode;
+                #result_ok(())
+            })?
+        }};
+    }
+
+    let pattern = syn::PatStruct {
+        attrs: Vec::new(),
+        qself: None,
+        path: v.st.path.clone(),
+        brace_token: syn::token::Brace::default(),
+        fields: v.patterns.clone(),
+        rest: None,
+    };
+
+    (pattern, encode)
+}
+
 struct LengthTest {
     kind: LengthTestKind,
     expressions: Punctuated<TokenStream, Token![+]>,
@@ -561,9 +882,14 @@ fn length_test(count: usize, tests: &[FieldTest<'_>]) -> LengthTest {
     let count = count.saturating_sub(tests.len());
     expressions.push(quote!(#count));
 
-    for FieldTest { var, .. } in tests {
+    for FieldTest { var, flatten, .. } in tests {
         kind = LengthTestKind::Dynamic;
-        expressions.push(quote!(if #var { 1 } else { 0 }))
+
+        if *flatten {
+            expressions.push(quote!(#var));
+        } else {
+            expressions.push(quote!(if #var { 1 } else { 0 }));
+        }
     }
 
     LengthTest { kind, expressions }
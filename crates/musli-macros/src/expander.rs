@@ -160,10 +160,12 @@ impl<'a> Expander<'a> {
             ModeIdent {
                 kind: ModeKind::Binary,
                 ident: syn::Ident::new("Binary", Span::call_site()),
+                inherits: cx.inherits(&ModeKind::Binary),
             },
             ModeIdent {
                 kind: ModeKind::Text,
                 ident: syn::Ident::new("Text", Span::call_site()),
+                inherits: cx.inherits(&ModeKind::Text),
             },
         ];
 
@@ -219,6 +221,10 @@ impl<'a> Expander<'a> {
 
         let mut out = TokenStream::new();
 
+        if let Some((_, remote)) = builds.iter().find_map(|b| b.remote) {
+            out.extend(crate::en::expand_remote_encode(self, remote));
+        }
+
         for build in builds {
             out.extend(crate::en::expand_insert_entry(build)?);
         }
@@ -233,6 +239,10 @@ impl<'a> Expander<'a> {
 
         let mut out = TokenStream::new();
 
+        if let Some((_, remote)) = builds.iter().find_map(|b| b.remote) {
+            out.extend(crate::de::expand_remote_decode(self, remote));
+        }
+
         for build in builds {
             out.extend(crate::de::expand_decode_entry(build)?);
         }
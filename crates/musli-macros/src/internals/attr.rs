@@ -34,6 +34,8 @@ impl ModeKind {
 pub(crate) struct ModeIdent {
     pub(crate) ident: syn::Ident,
     pub(crate) kind: ModeKind,
+    /// Modes this mode inherits attributes from, nearest parent first.
+    pub(crate) inherits: Vec<ModeKind>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -63,6 +65,8 @@ pub(crate) enum EnumTagging<'a> {
         tag: &'a syn::Expr,
         content: &'a syn::Expr,
     },
+    /// The enum is untagged, and each variant is tried in order when decoding.
+    Untagged,
 }
 
 /// If the type is tagged or not.
@@ -117,7 +121,7 @@ macro_rules! layer {
                 A: Copy + Fn(&$layer) -> Option<&O>,
                 O: ?Sized,
             {
-                if let Some(value) = mode.kind.and_then(|m| self.modes.get(m).and_then(access)) {
+                if let Some(value) = mode.kinds().find_map(|m| self.modes.get(m).and_then(access)) {
                     Some(value)
                 } else {
                     access(&self.root)
@@ -152,7 +156,7 @@ macro_rules! layer {
             )*
         }
 
-        #[derive(Default)]
+        #[derive(Default, Clone)]
         struct $new {
             $($(#[$($single_meta)*])* $single: Vec<(Span, $single_ty)>,)*
             $($(#[$($multiple_meta)*])* $multiple: Vec<(Span, $multiple_ty)>,)*
@@ -207,6 +211,22 @@ layer! {
         tag: syn::Expr,
         /// If `#[musli(content = <expr>)]` is specified.
         content: syn::Expr,
+        /// If `#[musli(untagged)]` is specified.
+        untagged: (),
+        /// If `#[musli(deny_unknown_fields)]` is specified.
+        deny_unknown_fields: (),
+        /// If `#[musli(flattenable)]` is specified.
+        flattenable: (),
+        /// `#[musli(from = <type>)]`.
+        proxy_from: syn::Type,
+        /// `#[musli(try_from = <type>)]`.
+        proxy_try_from: syn::Type,
+        /// `#[musli(into = <type>)]`.
+        proxy_into: syn::Type,
+        /// `#[musli(validate = <path>)]`.
+        validate: syn::Path,
+        /// `#[musli(remote = <path>)]`.
+        remote: syn::Path,
         /// `#[musli(packed)]` or `#[musli(transparent)]`.
         packing: Packing,
         @multiple
@@ -227,11 +247,16 @@ impl TypeAttr {
     pub(crate) fn enum_tagging_span(&self, mode: Mode<'_>) -> Option<Span> {
         let tag = self.tag(mode);
         let content = self.content(mode);
-        Some(tag.or(content)?.0)
+        let untagged = self.untagged(mode).map(|&(span, ())| span);
+        tag.or(content).map(|&(span, _)| span).or(untagged)
     }
 
     /// Indicates the state of enum tagging.
     pub(crate) fn enum_tagging(&self, mode: Mode<'_>) -> Option<EnumTagging<'_>> {
+        if self.untagged(mode).is_some() {
+            return Some(EnumTagging::Untagged);
+        }
+
         let (_, tag) = self.tag(mode)?;
 
         Some(match self.content(mode) {
@@ -252,6 +277,16 @@ impl TypeAttr {
     }
 }
 
+/// Parse the type of a proxy, which can either be specified directly or as a
+/// string literal like `"T"`.
+fn parse_proxy_type(meta: &ParseNestedMeta<'_>) -> syn::Result<syn::Type> {
+    if meta.input.peek(syn::LitStr) {
+        return meta.input.parse::<syn::LitStr>()?.parse();
+    }
+
+    meta.input.parse()
+}
+
 pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
     let mut attr = TypeAttr::default();
 
@@ -261,14 +296,28 @@ pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
         }
 
         let mut new = TypeLayerNew::default();
-        let mut mode = None;
+        let mut modes = Vec::new();
+        let mut inherit = None;
         let mut only = None;
 
         let result = a.parse_nested_meta(|meta| {
             // #[musli(mode = <path>)]
             if meta.path.is_ident("mode") {
                 meta.input.parse::<Token![=]>()?;
-                mode = Some(parse_mode(&meta)?);
+                modes.push(parse_mode(&meta)?);
+                return Ok(());
+            }
+
+            // #[musli(modes(<path>, ..))]
+            if meta.path.is_ident("modes") {
+                modes.extend(parse_modes(&meta)?);
+                return Ok(());
+            }
+
+            // #[musli(inherit = <path>)]
+            if meta.path.is_ident("inherit") {
+                meta.input.parse::<Token![=]>()?;
+                inherit = Some(parse_mode(&meta)?);
                 return Ok(());
             }
 
@@ -296,6 +345,62 @@ pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
                 return Ok(());
             }
 
+            // #[musli(untagged)]
+            if meta.path.is_ident("untagged") {
+                new.untagged.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(deny_unknown_fields)]
+            if meta.path.is_ident("deny_unknown_fields") {
+                new.deny_unknown_fields.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(flattenable)]
+            if meta.path.is_ident("flattenable") {
+                new.flattenable.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(validate = <path>)]
+            if meta.path.is_ident("validate") {
+                meta.input.parse::<Token![=]>()?;
+                new.validate.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
+            // #[musli(remote = <path>)]
+            if meta.path.is_ident("remote") {
+                meta.input.parse::<Token![=]>()?;
+                new.remote.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
+            // #[musli(from = <type>)]
+            if meta.path.is_ident("from") {
+                meta.input.parse::<Token![=]>()?;
+                new.proxy_from
+                    .push((meta.path.span(), parse_proxy_type(&meta)?));
+                return Ok(());
+            }
+
+            // #[musli(try_from = <type>)]
+            if meta.path.is_ident("try_from") {
+                meta.input.parse::<Token![=]>()?;
+                new.proxy_try_from
+                    .push((meta.path.span(), parse_proxy_type(&meta)?));
+                return Ok(());
+            }
+
+            // #[musli(into = <type>)]
+            if meta.path.is_ident("into") {
+                meta.input.parse::<Token![=]>()?;
+                new.proxy_into
+                    .push((meta.path.span(), parse_proxy_type(&meta)?));
+                return Ok(());
+            }
+
             // #[musli(crate = <path>)]
             if meta.path.is_ident("crate") {
                 let path = if meta.input.parse::<Option<Token![=]>>()?.is_some() {
@@ -373,16 +478,30 @@ pub(crate) fn type_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> TypeAttr {
             cx.syn_error(error);
         }
 
-        let attr = match mode {
-            Some(mode) => {
-                let modes = attr.modes.entry(mode.kind.clone()).or_default();
-                cx.register_mode(mode);
-                modes
+        if let Some(parent) = inherit {
+            if modes.is_empty() {
+                cx.error_span(
+                    parent.ident.span(),
+                    format_args!("#[{ATTR}(inherit = ..)] Must be used in combination with a mode, like #[{ATTR}(mode = <path>, inherit = {})]", parent.ident),
+                );
             }
-            None => &mut attr.root,
-        };
 
-        attr.merge_with(cx, new, only);
+            for mode in &modes {
+                cx.register_inherit(mode, &parent);
+            }
+
+            cx.register_mode(parent);
+        }
+
+        if modes.is_empty() {
+            attr.root.merge_with(cx, new, only);
+        } else {
+            for mode in modes {
+                let layer = attr.modes.entry(mode.kind.clone()).or_default();
+                layer.merge_with(cx, new.clone(), only);
+                cx.register_mode(mode);
+            }
+        }
     }
 
     attr
@@ -484,14 +603,20 @@ pub(crate) fn variant_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> VariantAttr
         }
 
         let mut new = VariantLayerNew::default();
-        let mut mode = None;
+        let mut modes = Vec::new();
         let mut only = None;
 
         let result = a.parse_nested_meta(|meta| {
             // #[musli(mode = <path>)]
             if meta.path.is_ident("mode") {
                 meta.input.parse::<Token![=]>()?;
-                mode = Some(parse_mode(&meta)?);
+                modes.push(parse_mode(&meta)?);
+                return Ok(());
+            }
+
+            // #[musli(modes(<path>, ..))]
+            if meta.path.is_ident("modes") {
+                modes.extend(parse_modes(&meta)?);
                 return Ok(());
             }
 
@@ -584,16 +709,15 @@ pub(crate) fn variant_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> VariantAttr
             cx.syn_error(error);
         }
 
-        let attr = match mode {
-            Some(mode) => {
-                let out = attr.modes.entry(mode.kind.clone()).or_default();
+        if modes.is_empty() {
+            attr.root.merge_with(cx, new, only);
+        } else {
+            for mode in modes {
+                let layer = attr.modes.entry(mode.kind.clone()).or_default();
+                layer.merge_with(cx, new.clone(), only);
                 cx.register_mode(mode);
-                out
             }
-            None => &mut attr.root,
-        };
-
-        attr.merge_with(cx, new, only);
+        }
     }
 
     attr
@@ -616,6 +740,8 @@ layer! {
         decode_path: syn::Path,
         /// Method to check if we want to skip encoding.
         skip_encoding_if: syn::Path,
+        /// Function used to validate the field after it has been decoded.
+        validate: syn::Path,
         /// Rename a field to the given literal.
         name: syn::Expr,
         /// Pattern used to match the given field when decoding.
@@ -624,12 +750,30 @@ layer! {
         is_default: Option<syn::Path>,
         /// Use a default value for the field if it's not available.
         skip: (),
+        /// Flatten the entries of the field into the enclosing container.
+        flatten: (),
+        /// Collect entries which are not claimed by any other field.
+        unknown_fields: (),
         /// Field encoding to use.
         encoding: FieldEncoding,
+        /// Module used to encode and decode each element of a container.
+        with_elements: WithPaths,
+        /// Module used to encode and decode each key of a map.
+        with_keys: WithPaths,
+        /// Module used to encode and decode each value of a map.
+        with_values: WithPaths,
         @multiple
     }
 }
 
+/// The `encode` and `decode` functions of a module specified through a `with`
+/// attribute.
+#[derive(Clone)]
+pub(crate) struct WithPaths {
+    pub(crate) encode: syn::Path,
+    pub(crate) decode: syn::Path,
+}
+
 impl Field {
     /// Expand encode of the given field.
     pub(crate) fn encode_path_expanded(&self, mode: Mode<'_>, span: Span) -> (Span, syn::Path) {
@@ -658,6 +802,36 @@ impl Field {
     }
 }
 
+/// Construct the paths to the `encode` and `decode` functions in the given
+/// module, where any generic arguments are moved to the functions.
+fn with_paths(mut path: syn::Path) -> WithPaths {
+    let (span, arguments) = match path.segments.last_mut() {
+        Some(s) => (
+            s.span(),
+            mem::replace(&mut s.arguments, syn::PathArguments::None),
+        ),
+        None => (path.span(), syn::PathArguments::None),
+    };
+
+    let mut encode = path.clone();
+
+    encode.segments.push({
+        let mut segment = syn::PathSegment::from(syn::Ident::new("encode", span));
+        segment.arguments = arguments.clone();
+        segment
+    });
+
+    let mut decode = path;
+
+    decode.segments.push({
+        let mut segment = syn::PathSegment::from(syn::Ident::new("decode", span));
+        segment.arguments = arguments;
+        segment
+    });
+
+    WithPaths { encode, decode }
+}
+
 /// Parse field attributes.
 pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
     let mut attr = Field::default();
@@ -668,14 +842,20 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
         }
 
         let mut new = FieldNew::default();
-        let mut mode = None;
+        let mut modes = Vec::new();
         let mut only = None;
 
         let result = a.parse_nested_meta(|meta| {
             // #[musli(mode = <path>)]
             if meta.path.is_ident("mode") {
                 meta.input.parse::<Token![=]>()?;
-                mode = Some(parse_mode(&meta)?);
+                modes.push(parse_mode(&meta)?);
+                return Ok(());
+            }
+
+            // #[musli(modes(<path>, ..))]
+            if meta.path.is_ident("modes") {
+                modes.extend(parse_modes(&meta)?);
                 return Ok(());
             }
 
@@ -692,34 +872,35 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
             // parse #[musli(with = <path>)]
             if meta.path.is_ident("with") {
                 meta.input.parse::<Token![=]>()?;
-                let mut path = meta.input.parse::<syn::Path>()?;
-
-                let (span, arguments) = match path.segments.last_mut() {
-                    Some(s) => (
-                        s.span(),
-                        mem::replace(&mut s.arguments, syn::PathArguments::None),
-                    ),
-                    None => (path.span(), syn::PathArguments::None),
-                };
-
-                let mut encode_path = path.clone();
-
-                encode_path.segments.push({
-                    let mut segment = syn::PathSegment::from(syn::Ident::new("encode", span));
-                    segment.arguments = arguments.clone();
-                    segment
-                });
+                let path = meta.input.parse::<syn::Path>()?;
+                let span = path.span();
+                let WithPaths { encode, decode } = with_paths(path);
+                new.encode_path.push((span, encode));
+                new.decode_path.push((span, decode));
+                return Ok(());
+            }
 
-                let mut decode_path = path.clone();
+            // parse #[musli(with_elements = <path>)]
+            if meta.path.is_ident("with_elements") {
+                meta.input.parse::<Token![=]>()?;
+                let path = meta.input.parse::<syn::Path>()?;
+                new.with_elements.push((meta.path.span(), with_paths(path)));
+                return Ok(());
+            }
 
-                decode_path.segments.push({
-                    let mut segment = syn::PathSegment::from(syn::Ident::new("decode", span));
-                    segment.arguments = arguments;
-                    segment
-                });
+            // parse #[musli(with_keys = <path>)]
+            if meta.path.is_ident("with_keys") {
+                meta.input.parse::<Token![=]>()?;
+                let path = meta.input.parse::<syn::Path>()?;
+                new.with_keys.push((meta.path.span(), with_paths(path)));
+                return Ok(());
+            }
 
-                new.encode_path.push((path.span(), encode_path));
-                new.decode_path.push((path.span(), decode_path));
+            // parse #[musli(with_values = <path>)]
+            if meta.path.is_ident("with_values") {
+                meta.input.parse::<Token![=]>()?;
+                let path = meta.input.parse::<syn::Path>()?;
+                new.with_values.push((meta.path.span(), with_paths(path)));
                 return Ok(());
             }
 
@@ -731,6 +912,13 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
                 return Ok(());
             }
 
+            // #[musli(validate = <path>)]
+            if meta.path.is_ident("validate") {
+                meta.input.parse::<Token![=]>()?;
+                new.validate.push((meta.path.span(), meta.input.parse()?));
+                return Ok(());
+            }
+
             if meta.path.is_ident("rename") {
                 return Err(syn::Error::new_spanned(
                     meta.path,
@@ -771,6 +959,18 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
                 return Ok(());
             }
 
+            // #[musli(flatten)]
+            if meta.path.is_ident("flatten") {
+                new.flatten.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
+            // #[musli(unknown_fields)]
+            if meta.path.is_ident("unknown_fields") {
+                new.unknown_fields.push((meta.path.span(), ()));
+                return Ok(());
+            }
+
             // #[musli(trace)]
             if meta.path.is_ident("trace") {
                 new.encoding.push((meta.path.span(), FieldEncoding::Trace));
@@ -799,16 +999,15 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
             cx.syn_error(error);
         }
 
-        let attr = match mode {
-            Some(mode) => {
-                let out = attr.modes.entry(mode.kind.clone()).or_default();
+        if modes.is_empty() {
+            attr.root.merge_with(cx, new, only);
+        } else {
+            for mode in modes {
+                let layer = attr.modes.entry(mode.kind.clone()).or_default();
+                layer.merge_with(cx, new.clone(), only);
                 cx.register_mode(mode);
-                out
             }
-            None => &mut attr.root,
-        };
-
-        attr.merge_with(cx, new, only);
+        }
     }
 
     attr
@@ -816,6 +1015,10 @@ pub(crate) fn field_attrs(cx: &Ctxt, attrs: &[syn::Attribute]) -> Field {
 
 fn parse_mode(meta: &ParseNestedMeta<'_>) -> syn::Result<ModeIdent> {
     let ident: syn::Ident = meta.input.parse()?;
+    Ok(mode_ident(ident))
+}
+
+fn mode_ident(ident: syn::Ident) -> ModeIdent {
     let s = ident.to_string();
 
     let kind = match s.as_str() {
@@ -824,5 +1027,27 @@ fn parse_mode(meta: &ParseNestedMeta<'_>) -> syn::Result<ModeIdent> {
         other => ModeKind::Custom(other.into()),
     };
 
-    Ok(ModeIdent { ident, kind })
+    ModeIdent {
+        ident,
+        kind,
+        inherits: Vec::new(),
+    }
+}
+
+fn parse_modes(meta: &ParseNestedMeta<'_>) -> syn::Result<Vec<ModeIdent>> {
+    let mut modes = Vec::new();
+
+    meta.parse_nested_meta(|meta| {
+        let Some(ident) = meta.path.get_ident() else {
+            return Err(syn::Error::new_spanned(
+                meta.path,
+                format_args!("#[{ATTR}(modes(..))] Expected a mode identifier"),
+            ));
+        };
+
+        modes.push(mode_ident(ident.clone()));
+        Ok(())
+    })?;
+
+    Ok(modes)
 }
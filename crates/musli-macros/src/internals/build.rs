@@ -1,6 +1,7 @@
 use std::rc::Rc;
 
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
 use syn::punctuated::Punctuated;
 use syn::Token;
 
@@ -10,7 +11,7 @@ use crate::expander::{
     VariantData,
 };
 
-use super::attr::{EnumTagging, FieldEncoding, ModeKind, Packing};
+use super::attr::{EnumTagging, FieldEncoding, Packing, WithPaths};
 use super::name::NameAll;
 use super::tokens::Tokens;
 use super::ATTR;
@@ -27,6 +28,19 @@ pub(crate) struct Build<'a> {
     pub(crate) decode_t_decode: syn::Path,
     pub(crate) encode_t_encode: syn::Path,
     pub(crate) enum_tagging_span: Option<Span>,
+    pub(crate) name_type_span: Option<Span>,
+    /// Implement flattening support for the container.
+    pub(crate) flattenable: Option<Span>,
+    /// Decode through a proxy type converted with `From`.
+    pub(crate) from: Option<&'a (Span, syn::Type)>,
+    /// Decode through a proxy type converted with `TryFrom`.
+    pub(crate) try_from: Option<&'a (Span, syn::Type)>,
+    /// Encode through a proxy type converted with `Into`.
+    pub(crate) into: Option<&'a (Span, syn::Type)>,
+    /// Function used to validate the container after it has been decoded.
+    pub(crate) validate_path: Option<&'a (Span, syn::Path)>,
+    /// The foreign type which the container mirrors.
+    pub(crate) remote: Option<&'a (Span, syn::Path)>,
 }
 
 impl Build<'_> {
@@ -67,6 +81,43 @@ impl Build<'_> {
         self.validate()
     }
 
+    /// Declare the type of a field adapter, which carries all generic
+    /// parameters of the container.
+    pub(crate) fn adapter_declaration(&self, ident: &syn::Ident) -> TokenStream {
+        let generics = &self.input.generics;
+        let where_clause = &generics.where_clause;
+
+        let lifetimes = generics.lifetimes().map(|p| &p.lifetime);
+        let types = generics.type_params().map(|p| &p.ident);
+
+        if generics.lifetimes().next().is_none() && generics.type_params().next().is_none() {
+            return quote! {
+                #[allow(dead_code)]
+                struct #ident #generics #where_clause;
+            };
+        }
+
+        let phantom_data = &self.tokens.phantom_data;
+
+        quote! {
+            #[allow(dead_code)]
+            struct #ident #generics (#phantom_data<fn() -> (#(&#lifetimes (),)* #(*const #types,)*)>) #where_clause;
+        }
+    }
+
+    /// Iterate over all fields in the container, including the fields of every
+    /// variant.
+    pub(crate) fn fields(&self) -> impl Iterator<Item = &Field<'_>> {
+        let (st, variants) = match &self.data {
+            BuildData::Struct(st) => (Some(st), &[][..]),
+            BuildData::Enum(en) => (None, &en.variants[..]),
+        };
+
+        st.into_iter()
+            .chain(variants.iter().map(|v| &v.st))
+            .flat_map(|st| st.all_fields.iter().map(|f| &**f))
+    }
+
     fn validate(&self) -> Result<()> {
         match &self.data {
             BuildData::Struct(..) => {
@@ -74,7 +125,7 @@ impl Build<'_> {
                     self.cx.error_span(
                         span,
                         format_args!(
-                            "#[{ATTR}(tag)] and #[{ATTR}(content)] are only supported on enums"
+                            "#[{ATTR}(tag)], #[{ATTR}(content)] and #[{ATTR}(untagged)] are only supported on enums"
                         ),
                     );
 
@@ -84,6 +135,31 @@ impl Build<'_> {
             BuildData::Enum(..) => (),
         }
 
+        if let Some(span) = self.flattenable {
+            let supported = match &self.data {
+                BuildData::Struct(st) => {
+                    st.packing == Packing::Tagged
+                        && self.name_type_span.is_none()
+                        && self.from.is_none()
+                        && self.try_from.is_none()
+                        && self.into.is_none()
+                        && self.remote.is_none()
+                }
+                BuildData::Enum(..) => false,
+            };
+
+            if !supported {
+                self.cx.error_span(
+                    span,
+                    format_args!(
+                        "#[{ATTR}(flattenable)] is only supported on structs encoded as maps with the default name type"
+                    ),
+                );
+
+                return Err(());
+            }
+        }
+
         Ok(())
     }
 }
@@ -105,6 +181,10 @@ pub(crate) struct Body<'a> {
     pub(crate) packing: Packing,
     pub(crate) kind: StructKind,
     pub(crate) path: syn::Path,
+    /// Reject unknown fields instead of skipping them.
+    pub(crate) deny_unknown_fields: Option<Span>,
+    /// The container can be flattened into other containers.
+    pub(crate) flattenable: Option<Span>,
 }
 
 impl Body<'_> {
@@ -112,6 +192,100 @@ impl Body<'_> {
         if self.packing == Packing::Transparent && !matches!(&self.unskipped_fields[..], [_]) {
             cx.transparent_diagnostics(self.span, &self.unskipped_fields);
         }
+
+        let mut unknown_fields = None;
+
+        for f in &self.unskipped_fields {
+            let Some(span) = f.flatten else {
+                continue;
+            };
+
+            let attr = match f.unknown_fields {
+                Some(span) => {
+                    if self.deny_unknown_fields.is_some() {
+                        cx.error_span(
+                            span,
+                            format_args!(
+                                "#[{ATTR}(unknown_fields)] cannot be used in a container with #[{ATTR}(deny_unknown_fields)]"
+                            ),
+                        );
+                    }
+
+                    if unknown_fields.replace(span).is_some() {
+                        cx.error_span(
+                            span,
+                            format_args!(
+                                "Only one field can be marked with #[{ATTR}(unknown_fields)]"
+                            ),
+                        );
+                    }
+
+                    "unknown_fields"
+                }
+                None => "flatten",
+            };
+
+            match self.packing {
+                Packing::Tagged => (),
+                Packing::Packed => {
+                    cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}({attr})] fields cannot be used in a packed container"
+                        ),
+                    );
+                }
+                Packing::Transparent => {
+                    cx.error_span(
+                        span,
+                        format_args!(
+                            "#[{ATTR}({attr})] fields cannot be used in a transparent container"
+                        ),
+                    );
+                }
+            }
+
+            if let Some((span, _)) = f.skip_encoding_if {
+                cx.error_span(
+                    *span,
+                    format_args!(
+                        "#[{ATTR}(skip_encoding_if)] cannot be combined with #[{ATTR}({attr})]"
+                    ),
+                );
+            }
+
+            if let Some((span, _)) = f.default_attr {
+                cx.error_span(
+                    span,
+                    format_args!("#[{ATTR}(default)] cannot be combined with #[{ATTR}({attr})]"),
+                );
+            }
+        }
+
+        // Every container numbers its fields from zero, so fields named by
+        // their index would conflict with the fields of flattened containers.
+        let flatten = self
+            .unskipped_fields
+            .iter()
+            .filter(|f| f.unknown_fields.is_none())
+            .find_map(|f| f.flatten.map(|span| (span, "flatten")));
+
+        let flatten = flatten.or(self.flattenable.map(|span| (span, "flattenable")));
+
+        if let Some((span, attr)) = flatten {
+            if self
+                .unskipped_fields
+                .iter()
+                .any(|f| f.flatten.is_none() && f.index_name)
+            {
+                cx.error_span(
+                    span,
+                    format_args!(
+                        "#[{ATTR}({attr})] cannot be used with fields named by their index since they would conflict, consider using #[{ATTR}(name_all = \"name\")]"
+                    ),
+                );
+            }
+        }
     }
 
     pub(crate) fn name_format(&self, value: &syn::Expr) -> syn::Expr {
@@ -186,6 +360,22 @@ pub(crate) struct Variant<'a> {
     pub(crate) pattern: Option<&'a syn::Pat>,
     pub(crate) st: Body<'a>,
     pub(crate) patterns: Punctuated<syn::FieldPat, Token![,]>,
+    pub(crate) is_fallback: bool,
+}
+
+impl Variant<'_> {
+    /// Get the fields capturing the unknown tag and content, if this is a
+    /// fallback variant with fields.
+    pub(crate) fn fallback_capture(&self) -> Option<(&Field<'_>, &Field<'_>)> {
+        if !self.is_fallback {
+            return None;
+        }
+
+        match &self.st.all_fields[..] {
+            [tag, content] => Some((tag, content)),
+            _ => None,
+        }
+    }
 }
 
 pub(crate) struct Field<'a> {
@@ -194,11 +384,19 @@ pub(crate) struct Field<'a> {
     pub(crate) encode_path: (Span, syn::Path),
     pub(crate) decode_path: (Span, syn::Path),
     pub(crate) name: syn::Expr,
+    /// The field is named by its index, since it hasn't been given a name.
+    pub(crate) index_name: bool,
     pub(crate) pattern: Option<&'a syn::Pat>,
     /// Skip field entirely and always initialize with the specified expresion,
     /// or default value through `default_attr`.
     pub(crate) skip: Option<Span>,
     pub(crate) skip_encoding_if: Option<&'a (Span, syn::Path)>,
+    /// Function used to validate the field after it has been decoded.
+    pub(crate) validate: Option<&'a (Span, syn::Path)>,
+    /// Flatten the entries of the field into the enclosing container.
+    pub(crate) flatten: Option<Span>,
+    /// Collect unknown entries into the field. Implies `flatten`.
+    pub(crate) unknown_fields: Option<Span>,
     /// Fill with default value, if missing.
     pub(crate) default_attr: Option<(Span, Option<&'a syn::Path>)>,
     pub(crate) self_access: syn::Expr,
@@ -206,6 +404,27 @@ pub(crate) struct Field<'a> {
     pub(crate) packing: Packing,
     pub(crate) var: syn::Ident,
     pub(crate) ty: &'a syn::Type,
+    /// Adapters used to encode and decode the elements of the field.
+    pub(crate) adapters: Vec<FieldAdapter<'a>>,
+}
+
+/// The part of a container which is encoded and decoded through an adapter.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AdapterKind {
+    /// Each element, as specified by `#[musli(with_elements = <path>)]`.
+    Elements,
+    /// Each key, as specified by `#[musli(with_keys = <path>)]`.
+    Keys,
+    /// Each value, as specified by `#[musli(with_values = <path>)]`.
+    Values,
+}
+
+/// A generated type which adapts the functions of a `with_elements`,
+/// `with_keys` or `with_values` module to the element of a container.
+pub(crate) struct FieldAdapter<'a> {
+    pub(crate) kind: AdapterKind,
+    pub(crate) ident: syn::Ident,
+    pub(crate) with: &'a WithPaths,
 }
 
 /// Setup a build.
@@ -227,6 +446,16 @@ pub(crate) fn setup<'a>(
         }
     };
 
+    let from = e.type_attr.proxy_from(mode);
+    let try_from = e.type_attr.proxy_try_from(mode);
+
+    if let (Some(_), Some(&(span, _))) = (from, try_from) {
+        e.cx.error_span(
+            span,
+            format_args!("#[{ATTR}(try_from)] cannot be combined with #[{ATTR}(from)]"),
+        );
+    }
+
     if e.cx.has_errors() {
         return Err(());
     }
@@ -242,6 +471,13 @@ pub(crate) fn setup<'a>(
         decode_t_decode: mode.decode_t_decode(FieldEncoding::Default),
         encode_t_encode: mode.encode_t_encode(FieldEncoding::Default),
         enum_tagging_span: e.type_attr.enum_tagging_span(mode),
+        name_type_span: e.type_attr.name_type(mode).map(|&(span, _)| span),
+        flattenable: e.type_attr.flattenable(mode).map(|&(span, ())| span),
+        from,
+        try_from,
+        into: e.type_attr.proxy_into(mode),
+        validate_path: e.type_attr.validate(mode),
+        remote: e.type_attr.remote(mode),
     })
 }
 
@@ -261,7 +497,7 @@ fn setup_struct<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a StructData<'a>) -
             (name_all, name_all.ty(), NameMethod::Value)
         }
         _ => split_name(
-            mode.kind,
+            mode,
             e.type_attr.name_type(mode),
             e.type_attr.name_all(mode),
             e.type_attr.name_method(mode),
@@ -291,6 +527,8 @@ fn setup_struct<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a StructData<'a>) -
         packing,
         kind: data.kind,
         path,
+        deny_unknown_fields: e.type_attr.deny_unknown_fields(mode).map(|&(s, ())| s),
+        flattenable: e.type_attr.flattenable(mode).map(|&(s, ())| s),
     };
 
     body.validate(&e.cx);
@@ -318,14 +556,31 @@ fn setup_enum<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a EnumData<'a>) -> En
         }
     };
 
+    if let EnumTagging::Untagged = enum_tagging {
+        if let Some(&(span, _)) = e.type_attr.tag(mode).or(e.type_attr.content(mode)) {
+            e.cx.error_span(span, format_args!("#[{ATTR}(untagged)] cannot be combined with #[{ATTR}(tag)] or #[{ATTR}(content)]"));
+        }
+    }
+
     if !matches!(enum_tagging, EnumTagging::Default | EnumTagging::Empty) {
+        let tagging = match enum_tagging {
+            EnumTagging::Untagged => format!("#[{ATTR}(untagged)]"),
+            _ => format!("#[{ATTR}(tag)] or #[{ATTR}(content)]"),
+        };
+
         match packing_span {
             Some((_, Packing::Tagged)) => (),
             Some(&(span, Packing::Packed)) => {
-                e.cx.error_span(span, format_args!("#[{ATTR}(packed)] cannot be combined with #[{ATTR}(tag)] or #[{ATTR}(content)]"));
+                e.cx.error_span(
+                    span,
+                    format_args!("#[{ATTR}(packed)] cannot be combined with {tagging}"),
+                );
             }
             Some(&(span, Packing::Transparent)) => {
-                e.cx.error_span(span, format_args!("#[{ATTR}(transparent)] cannot be combined with #[{ATTR}(tag)] or #[{ATTR}(content)]"));
+                e.cx.error_span(
+                    span,
+                    format_args!("#[{ATTR}(transparent)] cannot be combined with {tagging}"),
+                );
             }
             _ => (),
         }
@@ -338,7 +593,7 @@ fn setup_enum<'a>(e: &'a Expander, mode: Mode<'_>, data: &'a EnumData<'a>) -> En
         .unwrap_or_default();
 
     let (_, name_type, name_method) = split_name(
-        mode.kind,
+        mode,
         e.type_attr.name_type(mode),
         e.type_attr.name_all(mode),
         e.type_attr.name_method(mode),
@@ -384,7 +639,7 @@ fn setup_variant<'a>(
             (name_all, name_all.ty(), NameMethod::Value)
         }
         _ => split_name(
-            mode.kind,
+            mode,
             data.attr.name_type(mode),
             data.attr.name_all(mode),
             data.attr.name_method(mode),
@@ -392,7 +647,7 @@ fn setup_variant<'a>(
     };
 
     let (type_name_all, _, _) = split_name(
-        mode.kind,
+        mode,
         e.type_attr.name_type(mode),
         e.type_attr.name_all(mode),
         e.type_attr.name_method(mode),
@@ -405,11 +660,18 @@ fn setup_variant<'a>(
     let mut path = syn::Path::from(syn::Ident::new("Self", data.span));
     path.segments.push(data.ident.clone().into());
 
+    let is_fallback = data.attr.default_variant(mode).is_some();
+
     if let Some((span, _)) = data.attr.default_variant(mode) {
-        if !data.fields.is_empty() {
+        if !matches!(data.fields.len(), 0 | 2) {
             e.cx.error_span(
                 *span,
-                format_args!("#[{ATTR}(default)] variant must be empty"),
+                format_args!("#[{ATTR}(default)] variant must either be empty or have two fields capturing the tag and content"),
+            );
+        } else if !data.fields.is_empty() && e.type_attr.enum_tagging(mode).is_some() {
+            e.cx.error_span(
+                *span,
+                format_args!("#[{ATTR}(default)] variants with fields are only supported in externally tagged enums"),
             );
         } else if fallback.is_some() {
             e.cx.error_span(
@@ -451,6 +713,8 @@ fn setup_variant<'a>(
         name_method,
         name_format_with: data.attr.name_format_with(mode),
         path,
+        deny_unknown_fields: e.type_attr.deny_unknown_fields(mode).map(|&(s, ())| s),
+        flattenable: None,
     };
 
     st.validate(&e.cx);
@@ -462,6 +726,7 @@ fn setup_variant<'a>(
         pattern,
         patterns,
         st,
+        is_fallback,
     }
 }
 
@@ -473,14 +738,34 @@ fn setup_field<'a>(
     packing: Packing,
     patterns: Option<&mut Punctuated<syn::FieldPat, Token![,]>>,
 ) -> Field<'a> {
-    let encode_path = data.attr.encode_path_expanded(mode, data.span);
-    let decode_path = data.attr.decode_path_expanded(mode, data.span);
+    let mut encode_path = data.attr.encode_path_expanded(mode, data.span);
+    let mut decode_path = data.attr.decode_path_expanded(mode, data.span);
+    let adapters = setup_adapters(e, mode, data);
+
+    if let Some((encode, decode)) = adapter_paths(e, mode, &adapters) {
+        encode_path.1 = encode;
+        decode_path.1 = decode;
+    }
 
     let name = expander::expand_name(data, mode, name_all, data.ident);
+    let index_name = data.attr.name(mode).is_none()
+        && (data.ident.is_none() || matches!(name_all, NameAll::Index));
     let pattern = data.attr.pattern(mode).map(|(_, p)| p);
 
     let skip = data.attr.skip(mode).map(|&(s, ())| s);
     let skip_encoding_if = data.attr.skip_encoding_if(mode);
+    let validate = data.attr.validate(mode);
+    let unknown_fields = data.attr.unknown_fields(mode).map(|&(s, ())| s);
+    let flatten = data.attr.flatten(mode).map(|&(s, ())| s);
+
+    if let (Some(_), Some(span)) = (flatten, unknown_fields) {
+        e.cx.error_span(
+            span,
+            format_args!("#[{ATTR}(unknown_fields)] cannot be combined with #[{ATTR}(flatten)]"),
+        );
+    }
+
+    let flatten = flatten.or(unknown_fields);
     let default_attr = data
         .attr
         .is_default(mode)
@@ -568,25 +853,141 @@ fn setup_field<'a>(
         encode_path,
         decode_path,
         name,
+        index_name,
         pattern,
         skip,
         skip_encoding_if,
+        validate,
+        flatten,
+        unknown_fields,
         default_attr,
         self_access,
         member,
         packing,
         var,
         ty: data.ty,
+        adapters,
+    }
+}
+
+fn setup_adapters<'a>(
+    e: &'a Expander,
+    mode: Mode<'_>,
+    data: &'a FieldData<'a>,
+) -> Vec<FieldAdapter<'a>> {
+    let kinds = [
+        (AdapterKind::Elements, data.attr.with_elements(mode)),
+        (AdapterKind::Keys, data.attr.with_keys(mode)),
+        (AdapterKind::Values, data.attr.with_values(mode)),
+    ];
+
+    let mut adapters = Vec::new();
+
+    for (kind, with) in kinds {
+        let Some((span, with)) = with else {
+            continue;
+        };
+
+        let attr = match kind {
+            AdapterKind::Elements => "with_elements",
+            AdapterKind::Keys => "with_keys",
+            AdapterKind::Values => "with_values",
+        };
+
+        if data.attr.encode_path(mode).is_some() || data.attr.decode_path(mode).is_some() {
+            e.cx.error_span(
+                *span,
+                format_args!("#[{ATTR}({attr})] cannot be combined with #[{ATTR}(with)]"),
+            );
+        }
+
+        if let Some(&(_, encoding)) = data.attr.encoding(mode) {
+            if !matches!(encoding, FieldEncoding::Default) {
+                e.cx.error_span(
+                    *span,
+                    format_args!(
+                        "#[{ATTR}({attr})] cannot be combined with #[{ATTR}(packed)], #[{ATTR}(bytes)] or #[{ATTR}(trace)]"
+                    ),
+                );
+            }
+        }
+
+        if kind != AdapterKind::Elements
+            && adapters
+                .iter()
+                .any(|a: &FieldAdapter<'_>| a.kind == AdapterKind::Elements)
+        {
+            e.cx.error_span(
+                *span,
+                format_args!("#[{ATTR}({attr})] cannot be combined with #[{ATTR}(with_elements)]"),
+            );
+        }
+
+        adapters.push(FieldAdapter {
+            kind,
+            ident: e.cx.adapter_ident(*span),
+            with,
+        });
     }
+
+    adapters
+}
+
+/// Construct the paths used to encode and decode a field through its
+/// adapters.
+fn adapter_paths(
+    e: &Expander,
+    mode: Mode<'_>,
+    adapters: &[FieldAdapter<'_>],
+) -> Option<(syn::Path, syn::Path)> {
+    let Tokens {
+        decode_elements_t,
+        decode_entries_t,
+        default_with,
+        encode_elements_t,
+        encode_entries_t,
+        ..
+    } = &e.tokens;
+
+    let (_, type_generics, _) = e.input.generics.split_for_impl();
+    let mode_path = mode.mode_path.as_path();
+
+    let adapter = |kind: AdapterKind| -> syn::Type {
+        match adapters.iter().find(|a| a.kind == kind) {
+            Some(FieldAdapter { ident, .. }) => syn::parse_quote!(#ident #type_generics),
+            None => syn::parse_quote!(#default_with),
+        }
+    };
+
+    if adapters.iter().any(|a| a.kind == AdapterKind::Elements) {
+        let w = adapter(AdapterKind::Elements);
+
+        return Some((
+            syn::parse_quote!(#encode_elements_t::<#mode_path>::encode_elements::<#w, _>),
+            syn::parse_quote!(#decode_elements_t::<#mode_path>::decode_elements::<#w, _>),
+        ));
+    }
+
+    if adapters.is_empty() {
+        return None;
+    }
+
+    let k = adapter(AdapterKind::Keys);
+    let v = adapter(AdapterKind::Values);
+
+    Some((
+        syn::parse_quote!(#encode_entries_t::<#mode_path>::encode_entries::<#k, #v, _>),
+        syn::parse_quote!(#decode_entries_t::<#mode_path>::decode_entries::<#k, #v, _>),
+    ))
 }
 
 fn split_name(
-    kind: Option<&ModeKind>,
+    mode: Mode<'_>,
     name_type: Option<&(Span, syn::Type)>,
     name_all: Option<&(Span, NameAll)>,
     name_method: Option<&(Span, NameMethod)>,
 ) -> (NameAll, syn::Type, NameMethod) {
-    let kind_name_all = kind.and_then(ModeKind::default_name_all);
+    let kind_name_all = mode.default_name_all();
 
     let name_all = name_all.map(|&(_, v)| v);
     let name_method = name_method.map(|&(_, v)| v);
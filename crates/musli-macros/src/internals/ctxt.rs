@@ -12,11 +12,13 @@ use super::ATTR;
 struct Inner {
     b1: String,
     modes: HashMap<ModeKind, ModeIdent>,
+    inherits: HashMap<ModeKind, ModeKind>,
     errors: Vec<syn::Error>,
     #[cfg(not(feature = "verbose"))]
     names: HashMap<String, usize>,
     #[cfg(not(feature = "verbose"))]
     types: usize,
+    adapters: usize,
 }
 
 pub(crate) struct Ctxt {
@@ -30,11 +32,13 @@ impl Ctxt {
             inner: RefCell::new(Inner {
                 b1: String::new(),
                 modes: HashMap::new(),
+                inherits: HashMap::new(),
                 errors: Vec::new(),
                 #[cfg(not(feature = "verbose"))]
                 names: HashMap::new(),
                 #[cfg(not(feature = "verbose"))]
                 types: 0,
+                adapters: 0,
             }),
         }
     }
@@ -65,6 +69,54 @@ impl Ctxt {
             .insert(mode.kind.clone(), mode);
     }
 
+    /// Register that `mode` inherits attributes from `parent`.
+    pub(crate) fn register_inherit(&self, mode: &ModeIdent, parent: &ModeIdent) {
+        if self.inherits(&parent.kind).contains(&mode.kind) || mode.kind == parent.kind {
+            self.error_span(
+                parent.ident.span(),
+                format_args!(
+                    "#[{ATTR}(inherit = {})] Mode `{}` cannot inherit from itself",
+                    parent.ident, mode.ident
+                ),
+            );
+            return;
+        }
+
+        let mut inner = self.inner.borrow_mut();
+
+        if inner.inherits.contains_key(&mode.kind) {
+            drop(inner);
+
+            self.error_span(
+                parent.ident.span(),
+                format_args!(
+                    "#[{ATTR}(inherit = {})] Mode `{}` already inherits from another mode",
+                    parent.ident, mode.ident
+                ),
+            );
+            return;
+        }
+
+        inner
+            .inherits
+            .insert(mode.kind.clone(), parent.kind.clone());
+    }
+
+    /// Get the chain of modes that the given mode inherits from, nearest parent
+    /// first.
+    pub(crate) fn inherits(&self, kind: &ModeKind) -> Vec<ModeKind> {
+        let inner = self.inner.borrow();
+        let mut out = Vec::new();
+        let mut current = kind;
+
+        while let Some(parent) = inner.inherits.get(current) {
+            out.push(parent.clone());
+            current = parent;
+        }
+
+        out
+    }
+
     /// Test if context contains errors.
     pub(crate) fn has_errors(&self) -> bool {
         !self.inner.borrow().errors.is_empty()
@@ -93,7 +145,21 @@ impl Ctxt {
 
     /// Get all extra modes specified.
     pub(crate) fn modes(&self) -> Vec<ModeIdent> {
-        self.inner.borrow().modes.values().cloned().collect()
+        let modes = self
+            .inner
+            .borrow()
+            .modes
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+
+        modes
+            .into_iter()
+            .map(|mode| ModeIdent {
+                inherits: self.inherits(&mode.kind),
+                ..mode
+            })
+            .collect()
     }
 
     pub(crate) fn reset(&self) {
@@ -151,6 +217,20 @@ impl Ctxt {
         ident
     }
 
+    /// Build the identifier of a field adapter type.
+    ///
+    /// These are unique for the whole derive and are not affected by
+    /// [`Ctxt::reset`].
+    pub(crate) fn adapter_ident(&self, span: Span) -> syn::Ident {
+        let mut inner = self.inner.borrow_mut();
+        let index = inner.adapters;
+        inner.adapters += 1;
+        _ = write!(inner.b1, "_With{index}");
+        let ident = syn::Ident::new(&inner.b1, span);
+        inner.b1.clear();
+        ident
+    }
+
     /// Build a type identifier with a span.
     pub(crate) fn type_with_span<N>(
         &self,
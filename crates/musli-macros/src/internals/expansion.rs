@@ -13,6 +13,7 @@ impl<'a> Expansion<'a> {
     pub(crate) fn as_mode(&'a self, tokens: &'a Tokens, only: Only) -> Mode<'a> {
         Mode {
             kind: Some(&self.mode_ident.kind),
+            inherits: &self.mode_ident.inherits,
             mode_path: self.mode_path(tokens),
             tokens,
             only,
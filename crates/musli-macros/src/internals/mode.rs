@@ -6,6 +6,7 @@ use syn::spanned::Spanned;
 use syn::Token;
 
 use super::attr::{FieldEncoding, ModeKind};
+use super::name::NameAll;
 use super::tokens::Tokens;
 use super::Only;
 
@@ -35,12 +36,26 @@ impl ModePath<'_> {
 #[derive(Clone, Copy)]
 pub(crate) struct Mode<'a> {
     pub(crate) kind: Option<&'a ModeKind>,
+    /// Modes that attributes are inherited from, nearest parent first.
+    pub(crate) inherits: &'a [ModeKind],
     pub(crate) mode_path: ModePath<'a>,
     pub(crate) tokens: &'a Tokens,
     pub(crate) only: Only,
 }
 
 impl<'a> Mode<'a> {
+    /// Iterate over the current mode followed by the modes it inherits from,
+    /// in the order in which attributes should be looked up.
+    pub(crate) fn kinds(&self) -> impl Iterator<Item = &'a ModeKind> {
+        self.kind.into_iter().chain(self.inherits)
+    }
+
+    /// The default `name_all` for this mode, which is taken from the nearest
+    /// mode which has one.
+    pub(crate) fn default_name_all(&self) -> Option<NameAll> {
+        self.kinds().find_map(ModeKind::default_name_all)
+    }
+
     /// Construct a typed encode call.
     pub(crate) fn encode_t_encode(&self, encoding: FieldEncoding) -> syn::Path {
         let (mut encode_t, name) = match encoding {
@@ -2,17 +2,30 @@ use proc_macro2::Span;
 
 pub(crate) struct Tokens {
     pub(crate) as_decoder_t: syn::Path,
+    pub(crate) attempt: syn::Path,
+    pub(crate) clone_t: syn::Path,
     pub(crate) context_t: syn::Path,
     pub(crate) decode_bytes_t: syn::Path,
+    pub(crate) decode_elements_t: syn::Path,
+    pub(crate) decode_entries_t: syn::Path,
+    pub(crate) decode_flatten_t: syn::Path,
     pub(crate) decode_packed_t: syn::Path,
     pub(crate) decode_t: syn::Path,
+    pub(crate) decode_with_t: syn::Path,
     pub(crate) decoder_t: syn::Path,
     pub(crate) default_function: syn::Path,
+    pub(crate) default_with: syn::Path,
     pub(crate) encode_bytes_t: syn::Path,
+    pub(crate) encode_elements_t: syn::Path,
+    pub(crate) encode_entries_t: syn::Path,
+    pub(crate) encode_flatten_t: syn::Path,
     pub(crate) encode_packed_t: syn::Path,
     pub(crate) encode_t: syn::Path,
+    pub(crate) encode_with_t: syn::Path,
     pub(crate) encoder_t: syn::Path,
     pub(crate) fmt: syn::Path,
+    pub(crate) from_t: syn::Path,
+    pub(crate) into_t: syn::Path,
     pub(crate) map_decoder_t: syn::Path,
     pub(crate) map_encoder_t: syn::Path,
     pub(crate) map_entry_encoder_t: syn::Path,
@@ -21,6 +34,7 @@ pub(crate) struct Tokens {
     pub(crate) option_some: syn::Path,
     pub(crate) option: syn::Path,
     pub(crate) pack_decoder_t: syn::Path,
+    pub(crate) phantom_data: syn::Path,
     pub(crate) result_err: syn::Path,
     pub(crate) result_ok: syn::Path,
     pub(crate) result: syn::Path,
@@ -28,8 +42,10 @@ pub(crate) struct Tokens {
     pub(crate) skip_field: syn::Path,
     pub(crate) skip: syn::Path,
     pub(crate) struct_field_decoder_t: syn::Path,
+    pub(crate) to_owned: syn::Path,
     pub(crate) trace_decode_t: syn::Path,
     pub(crate) trace_encode_t: syn::Path,
+    pub(crate) try_from_t: syn::Path,
     pub(crate) variant_decoder_t: syn::Path,
     pub(crate) variant_encoder_t: syn::Path,
     pub(crate) prefix: syn::Path,
@@ -39,17 +55,30 @@ impl Tokens {
     pub(crate) fn new(span: Span, prefix: syn::Path) -> Self {
         Self {
             as_decoder_t: path(span, &prefix, ["de", "AsDecoder"]),
+            attempt: path(span, &prefix, ["__priv", "attempt"]),
+            clone_t: path(span, &prefix, ["__priv", "Clone"]),
             context_t: path(span, &prefix, ["Context"]),
             decode_bytes_t: path(span, &prefix, ["de", "DecodeBytes"]),
+            decode_elements_t: path(span, &prefix, ["de", "DecodeElements"]),
+            decode_entries_t: path(span, &prefix, ["de", "DecodeEntries"]),
+            decode_flatten_t: path(span, &prefix, ["de", "DecodeFlatten"]),
             decode_packed_t: path(span, &prefix, ["de", "DecodePacked"]),
             decode_t: path(span, &prefix, ["de", "Decode"]),
+            decode_with_t: path(span, &prefix, ["de", "DecodeWith"]),
             decoder_t: path(span, &prefix, ["de", "Decoder"]),
             default_function: path(span, &prefix, ["__priv", "default"]),
+            default_with: path(span, &prefix, ["__priv", "DefaultWith"]),
             encode_bytes_t: path(span, &prefix, ["en", "EncodeBytes"]),
+            encode_elements_t: path(span, &prefix, ["en", "EncodeElements"]),
+            encode_entries_t: path(span, &prefix, ["en", "EncodeEntries"]),
+            encode_flatten_t: path(span, &prefix, ["en", "EncodeFlatten"]),
             encode_packed_t: path(span, &prefix, ["en", "EncodePacked"]),
             encode_t: path(span, &prefix, ["en", "Encode"]),
+            encode_with_t: path(span, &prefix, ["en", "EncodeWith"]),
             encoder_t: path(span, &prefix, ["en", "Encoder"]),
             fmt: path(span, &prefix, ["__priv", "fmt"]),
+            from_t: path(span, &prefix, ["__priv", "From"]),
+            into_t: path(span, &prefix, ["__priv", "Into"]),
             map_decoder_t: path(span, &prefix, ["de", "MapDecoder"]),
             map_encoder_t: path(span, &prefix, ["en", "MapEncoder"]),
             map_entry_encoder_t: path(span, &prefix, ["en", "EntryEncoder"]),
@@ -58,6 +87,7 @@ impl Tokens {
             option_some: path(span, &prefix, ["__priv", "Some"]),
             option: path(span, &prefix, ["__priv", "Option"]),
             pack_decoder_t: path(span, &prefix, ["de", "SequenceDecoder"]),
+            phantom_data: path(span, &prefix, ["__priv", "PhantomData"]),
             result_err: path(span, &prefix, ["__priv", "Err"]),
             result_ok: path(span, &prefix, ["__priv", "Ok"]),
             result: path(span, &prefix, ["__priv", "Result"]),
@@ -65,8 +95,10 @@ impl Tokens {
             skip_field: path(span, &prefix, ["__priv", "skip_field"]),
             skip: path(span, &prefix, ["__priv", "skip"]),
             struct_field_decoder_t: path(span, &prefix, ["de", "EntryDecoder"]),
+            to_owned: path(span, &prefix, ["__priv", "ToOwned"]),
             trace_decode_t: path(span, &prefix, ["de", "DecodeTrace"]),
             trace_encode_t: path(span, &prefix, ["en", "EncodeTrace"]),
+            try_from_t: path(span, &prefix, ["__priv", "TryFrom"]),
             variant_decoder_t: path(span, &prefix, ["de", "VariantDecoder"]),
             variant_encoder_t: path(span, &prefix, ["en", "VariantEncoder"]),
             prefix,
@@ -15,6 +15,7 @@ use proc_macro::TokenStream;
 
 #[cfg(feature = "sneaky-fields")]
 mod sneaky_fields;
+mod to_archived;
 mod visit;
 mod zero_copy;
 
@@ -40,6 +41,17 @@ pub fn visit(input: TokenStream) -> TokenStream {
     }
 }
 
+#[proc_macro_derive(ToArchived, attributes(to_archived))]
+pub fn to_archived(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+    let expander = to_archived::Expander::new(&input);
+
+    match expander.expand() {
+        Ok(stream) => stream.into(),
+        Err(errors) => to_compile_errors(errors).into(),
+    }
+}
+
 // NB: Only used in UI tests.
 #[proc_macro_attribute]
 #[doc(hidden)]
@@ -0,0 +1,184 @@
+use std::cell::RefCell;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::{DeriveInput, Token};
+
+#[derive(Default)]
+struct Ctxt {
+    errors: RefCell<Vec<syn::Error>>,
+}
+
+impl Ctxt {
+    fn error(&self, error: syn::Error) {
+        self.errors.borrow_mut().push(error);
+    }
+}
+
+pub struct Expander<'a> {
+    input: &'a DeriveInput,
+}
+
+impl<'a> Expander<'a> {
+    pub fn new(input: &'a DeriveInput) -> Self {
+        Self { input }
+    }
+}
+
+impl<'a> Expander<'a> {
+    pub fn expand(&self) -> Result<TokenStream, Vec<syn::Error>> {
+        let cx = Ctxt::default();
+
+        let Ok(output) = expand(&cx, self.input) else {
+            return Err(cx.errors.into_inner());
+        };
+
+        let errors = cx.errors.into_inner();
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(output)
+    }
+}
+
+fn expand(cx: &Ctxt, input: &DeriveInput) -> Result<TokenStream, ()> {
+    let mut krate: syn::Path = syn::parse_quote!(musli_zerocopy);
+    let mut archived = None;
+
+    for attr in &input.attrs {
+        if attr.path().is_ident("to_archived") {
+            let result = attr.parse_nested_meta(|meta: ParseNestedMeta| {
+                if meta.path.is_ident("crate") {
+                    if meta.input.parse::<Option<Token![=]>>()?.is_some() {
+                        krate = meta.input.parse()?;
+                    } else {
+                        krate = syn::parse_quote!(crate);
+                    }
+
+                    return Ok(());
+                }
+
+                if meta.path.is_ident("name") {
+                    meta.input.parse::<Token![=]>()?;
+                    archived = Some(meta.input.parse::<syn::Ident>()?);
+                    return Ok(());
+                }
+
+                Err(syn::Error::new(
+                    meta.input.span(),
+                    "ToArchived: Unsupported attribute",
+                ))
+            });
+
+            if let Err(error) = result {
+                cx.error(error);
+            }
+        }
+    }
+
+    let syn::Data::Struct(st) = &input.data else {
+        cx.error(syn::Error::new(
+            Span::call_site(),
+            "ToArchived: only structs are supported",
+        ));
+        return Err(());
+    };
+
+    if !input.generics.params.is_empty() || input.generics.where_clause.is_some() {
+        cx.error(syn::Error::new(
+            input.generics.span(),
+            "ToArchived: generic structs are not supported",
+        ));
+        return Err(());
+    }
+
+    process_fields(cx, &st.fields);
+
+    let to_archived: syn::Path = syn::parse_quote!(#krate::__private::ToArchived);
+    let default_size: syn::Path = syn::parse_quote!(#krate::__private::DefaultSize);
+    let error: syn::Path = syn::parse_quote!(#krate::Error);
+    let native: syn::Path = syn::parse_quote!(#krate::__private::Native);
+    let result: syn::Path = syn::parse_quote!(#krate::__private::result::Result);
+    let store_buf: syn::Path = syn::parse_quote!(#krate::__private::StoreBuf);
+    let zero_copy: syn::Path = syn::parse_quote!(#krate::ZeroCopy);
+
+    let vis = &input.vis;
+    let name = &input.ident;
+    let archived = archived.unwrap_or_else(|| format_ident!("Archived{}", name));
+    let doc = format!(" The archived counterpart of [`{name}`].");
+
+    let mut fields = Vec::new();
+    let mut values = Vec::new();
+
+    for (index, field) in st.fields.iter().enumerate() {
+        let vis = &field.vis;
+        let ty = &field.ty;
+
+        let member = match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(syn::Index::from(index)),
+        };
+
+        let field_ty = quote!(<#ty as #to_archived>::Archived);
+
+        fields.push(match &field.ident {
+            Some(ident) => quote!(#vis #ident: #field_ty),
+            None => quote!(#vis #field_ty),
+        });
+
+        values.push(quote!(#member: <#ty as #to_archived>::to_archived(&self.#member, buf)?));
+    }
+
+    let body = match &st.fields {
+        syn::Fields::Named(..) => quote!({ #(#fields,)* }),
+        syn::Fields::Unnamed(..) => quote!((#(#fields,)*);),
+        syn::Fields::Unit => quote!(;),
+    };
+
+    Ok(quote! {
+        #[doc = #doc]
+        #[derive(#zero_copy)]
+        #[zero_copy(crate = #krate)]
+        #[repr(C)]
+        #vis struct #archived #body
+
+        #[automatically_derived]
+        impl #to_archived for #name {
+            type Archived = #archived;
+
+            #[inline]
+            fn to_archived<__S>(&self, buf: &mut __S) -> #result<Self::Archived, #error>
+            where
+                __S: ?Sized + #store_buf<ByteOrder = #native, Size = #default_size>,
+            {
+                #result::Ok(#archived { #(#values,)* })
+            }
+        }
+    })
+}
+
+fn process_fields<'a, I>(cx: &Ctxt, fields: I)
+where
+    I: IntoIterator<Item = &'a syn::Field>,
+{
+    for field in fields {
+        for attr in &field.attrs {
+            if attr.path().is_ident("to_archived") {
+                let result = attr.parse_nested_meta(|meta: ParseNestedMeta| {
+                    Err(syn::Error::new(
+                        meta.input.span(),
+                        "ToArchived: Unsupported attribute",
+                    ))
+                });
+
+                if let Err(error) = result {
+                    cx.error(error);
+                }
+            }
+        }
+    }
+}
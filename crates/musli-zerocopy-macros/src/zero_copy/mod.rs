@@ -57,6 +57,7 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
     let mut krate: syn::Path = syn::parse_quote!(musli_zerocopy);
     let mut swap_bytes_self = false;
     let mut swap_bytes = None;
+    let mut visit_refs = None::<syn::Path>;
 
     for attr in &attrs {
         if attr.path().is_ident("repr") {
@@ -97,6 +98,12 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                     return Ok(());
                 }
 
+                if meta.path.is_ident("visit_refs") {
+                    meta.input.parse::<Token![=]>()?;
+                    visit_refs = Some(meta.input.parse()?);
+                    return Ok(());
+                }
+
                 Err(syn::Error::new(
                     meta.input.span(),
                     "ZeroCopy: Unsupported attribute",
@@ -120,6 +127,8 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
     let error: syn::Path = syn::parse_quote!(#krate::Error);
     let mem: syn::Path = syn::parse_quote!(#krate::__private::mem);
     let padder: syn::Path = syn::parse_quote!(#krate::buf::Padder);
+    let ptr: syn::Path = syn::parse_quote!(#krate::__private::ptr);
+    let ref_visitor: syn::Path = syn::parse_quote!(#krate::buf::RefVisitor);
     let result: syn::Path = syn::parse_quote!(#krate::__private::result::Result);
     let unknown_discriminant: syn::Path =
         syn::parse_quote!(#krate::__private::unknown_discriminant);
@@ -132,6 +141,7 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
 
     let pad;
     let validate;
+    let visit;
     let impl_zero_sized;
     let any_bits;
     let padded;
@@ -162,12 +172,15 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
 
             match (repr, &output.first_field) {
                 (Repr::Transparent, Some((ty, member))) => {
+                    let label = &output.labels[0];
+
                     pad = quote! {
                         <#ty as #zero_copy>::pad(#padder::transparent::<#ty>(padder));
                     };
 
                     validate = quote! {
-                        <#ty as #zero_copy>::validate(#validator::transparent::<#ty>(validator))?;
+                        <#ty as #zero_copy>::validate(#validator::transparent::<#ty>(validator))
+                            .map_err(|error| #error::__field::<Self>(error, #label))?;
                     };
 
                     let ignored_members = &output.ignored_members;
@@ -180,7 +193,7 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                     };
                 }
                 _ => {
-                    let types = &output.types;
+                    let Fields { types, labels, .. } = &output;
 
                     match r.repr_packed {
                         Some((_, align)) => {
@@ -192,7 +205,8 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                                 // SAFETY: We've systematically ensured that we're
                                 // only validating over fields within the size of
                                 // this type.
-                                #(#validator::validate_with::<#types>(validator, #align)?;)*
+                                #(#validator::validate_with::<#types>(validator, #align)
+                                    .map_err(|error| #error::__field::<Self>(error, #labels))?;)*
                             };
                         }
                         _ => {
@@ -204,7 +218,8 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                                 // SAFETY: We've systematically ensured that we're
                                 // only validating over fields within the size of
                                 // this type.
-                                #(#validator::validate::<#types>(validator)?;)*
+                                #(#validator::validate::<#types>(validator)
+                                    .map_err(|error| #error::__field::<Self>(error, #labels))?;)*
                             };
                         }
                     }
@@ -224,6 +239,37 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                 }
             }
 
+            visit = (!output.types.is_empty()).then(|| {
+                let Fields {
+                    types,
+                    members,
+                    labels,
+                    ..
+                } = &output;
+
+                if r.repr_packed.is_some() {
+                    // NB: Fields of packed structs can't be referenced, so
+                    // they are copied out before they are visited.
+                    quote! {
+                        #(#ref_visitor::field::<Self, _>(visitor, #labels, |visitor| {
+                            // SAFETY: The field is read from a valid reference
+                            // and is never dropped.
+                            let value = #mem::ManuallyDrop::new(unsafe {
+                                #ptr::read_unaligned(#ptr::addr_of!(self.#members))
+                            });
+
+                            <#types as #zero_copy>::visit_refs(&value, visitor);
+                        });)*
+                    }
+                } else {
+                    quote! {
+                        #(#ref_visitor::field::<Self, _>(visitor, #labels, |visitor| {
+                            <#types as #zero_copy>::visit_refs(&self.#members, visitor);
+                        });)*
+                    }
+                }
+            });
+
             let mut field_sizes = Vec::new();
             let mut field_padded = Vec::new();
             let mut field_byte_ordered = Vec::new();
@@ -312,6 +358,8 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
             let mut padded_variants = Vec::new();
             let mut byte_ordered_variants = Vec::new();
             let mut variant_fields = Vec::new();
+            let mut visit_variants = Vec::new();
+            let mut any_fields = false;
 
             let mut enumerator = Enumerator::new(num, ty.span());
 
@@ -370,7 +418,27 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                     }
                 }
 
-                let types = &output.types;
+                let Fields {
+                    types,
+                    members,
+                    variables,
+                    labels,
+                    ..
+                } = &output;
+
+                let labels = labels
+                    .iter()
+                    .map(|label| format!("{}.{label}", variant.ident))
+                    .collect::<Vec<_>>();
+
+                let bindings = members
+                    .iter()
+                    .zip(variables)
+                    .map(|(member, variable)| match member {
+                        syn::Member::Named(ident) => quote!(#ident),
+                        syn::Member::Unnamed(index) => quote!(#index: #variable),
+                    })
+                    .collect::<Vec<_>>();
 
                 let discriminant_const =
                     syn::Ident::new(&format!("DISCRIMINANT{}", index), variant.ident.span());
@@ -383,12 +451,23 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
 
                 validate_variants.push(quote! {
                     #discriminant_const => {
-                        #(#validator::validate::<#types>(validator)?;)*
+                        #(#validator::validate::<#types>(validator)
+                            .map_err(|error| #error::__field::<Self>(error, #labels))?;)*
                     }
                 });
 
                 let ident = &variant.ident;
 
+                any_fields |= !types.is_empty();
+
+                visit_variants.push(quote! {
+                    Self::#ident { #(#bindings,)* .. } => {
+                        #(#ref_visitor::field::<Self, _>(visitor, #labels, |visitor| {
+                            <#types as #zero_copy>::visit_refs(#variables, visitor);
+                        });)*
+                    }
+                });
+
                 let Fields { types, .. } = &output;
 
                 pad_variants.push(quote! {
@@ -564,6 +643,14 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
                 }
             };
 
+            visit = any_fields.then(|| {
+                quote! {
+                    match self {
+                        #(#visit_variants,)*
+                    }
+                }
+            });
+
             impl_zero_sized = None;
             any_bits = quote!(false);
             padded = quote!(false #(|| #padded_variants)*);
@@ -615,6 +702,20 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
 
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    let visit = match visit_refs {
+        Some(path) => Some(quote!(#path(self, visitor);)),
+        None => visit,
+    };
+
+    let visit_refs = visit.map(|visit| {
+        quote! {
+            #[inline]
+            fn visit_refs(&self, visitor: &mut #ref_visitor<'_>) {
+                #visit
+            }
+        }
+    });
+
     let (swap_bytes_block, can_swap_bytes) = if swap_bytes_self {
         (quote!(this), quote!(true))
     } else {
@@ -651,6 +752,8 @@ fn expand(cx: &Ctxt, input: syn::DeriveInput) -> Result<TokenStream, ()> {
             fn swap_bytes<#endianness: #byte_order>(self) -> Self {
                 <#endianness as #byte_order>::try_map(self, |this| #swap_bytes_block)
             }
+
+            #visit_refs
         }
     })
 }
@@ -726,6 +829,7 @@ struct Fields<'a> {
     assigns: Vec<syn::FieldValue>,
     members: Vec<syn::Member>,
     variables: Vec<syn::Ident>,
+    labels: Vec<String>,
     first_field: Option<(&'a syn::Type, syn::Member)>,
     ignored_members: Vec<syn::Member>,
     ignored_variables: Vec<syn::Ident>,
@@ -793,6 +897,11 @@ fn process_fields<'a>(cx: &Ctxt, fields: &'a syn::Fields) -> Fields<'a> {
             output.first_field = Some((ty, member.clone()));
         }
 
+        output.labels.push(match &member {
+            syn::Member::Named(ident) => ident.to_string(),
+            syn::Member::Unnamed(index) => index.index.to_string(),
+        });
+
         output.types.push(ty);
         output.members.push(member);
         output.variables.push(variable);
@@ -0,0 +1,446 @@
+//! A self-describing envelope for zero-copy archives.
+//!
+//! A buffer on its own carries no information about how it was constructed,
+//! so nothing prevents a buffer built with an [`OwnedBuf<Big, u64>`] from being
+//! loaded as if it used native endian and `u32` offsets. An archive prefixes
+//! the buffer with a [`Header`] which records:
+//! * Magic bytes identifying the format, see [`MAGIC`].
+//! * A user-defined schema version.
+//! * The [`ByteOrder`] and [`Size`] the archive was built with.
+//! * The alignment the buffer must be loaded with.
+//! * The offset of the root value.
+//! * An optional checksum of the body following the header.
+//!
+//! All of these are validated by [`Archive::load`] before a reference to the
+//! root is handed out. The header itself is always stored in little endian, so
+//! it can be inspected on any platform.
+//!
+//! [`OwnedBuf<Big, u64>`]: crate::buf::OwnedBuf
+//!
+//! # Examples
+//!
+//! ```
+//! use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+//! use musli_zerocopy::archive::Archive;
+//!
+//! #[derive(ZeroCopy)]
+//! #[repr(C)]
+//! struct Person {
+//!     name: Ref<str>,
+//!     age: u32,
+//! }
+//!
+//! let archive = Archive::new().with_version(2).with_checksum();
+//!
+//! let mut buf = OwnedBuf::new();
+//!
+//! archive.store(&mut buf, |buf| {
+//!     let name = buf.store_unsized("John-John");
+//!     Ok(buf.store(&Person { name, age: 35 }))
+//! })?;
+//!
+//! let person = archive.load::<Person>(&buf)?;
+//! assert_eq!(buf.load(person.name)?, "John-John");
+//! assert_eq!(person.age, 35);
+//!
+//! // Loading with a different schema version fails.
+//! assert!(Archive::new().with_version(3).load::<Person>(&buf).is_err());
+//! // Loading with a different size fails.
+//! assert!(archive.with_size::<u64>().load::<Person>(&buf).is_err());
+//! # Ok::<_, musli_zerocopy::Error>(())
+//! ```
+
+use core::hash::Hasher;
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of};
+
+use crate::buf::{Buf, StoreBuf};
+use crate::endian::{ByteOrder, Endian, Little, Native};
+use crate::error::{Error, ErrorKind};
+use crate::pointer::{DefaultSize, Ref, Size};
+use crate::sip::SipHasher13;
+use crate::ZeroCopy;
+
+/// The default magic bytes identifying an archive.
+pub const MAGIC: [u8; 8] = *b"MUSLIZC\0";
+
+/// Fixed keys used when calculating the checksum of an archive body.
+const CHECKSUM_KEYS: (u64, u64) = (0x6d75736c69, 0x7a65726f636f7079);
+
+/// Flag set in the header if it contains a checksum.
+const FLAG_CHECKSUM: u8 = 0b1;
+
+/// The header stored at the start of every archive.
+///
+/// All fields are stored in little endian byte order regardless of the byte
+/// order of the archive body.
+#[derive(Debug, Clone, Copy, ZeroCopy)]
+#[zero_copy(crate)]
+#[repr(C)]
+pub struct Header {
+    magic: [u8; 8],
+    version: Endian<u32, Little>,
+    align: Endian<u32, Little>,
+    root: Endian<u64, Little>,
+    len: Endian<u64, Little>,
+    checksum: Endian<u64, Little>,
+    byte_order: u8,
+    size: u8,
+    flags: u8,
+    _reserved: [u8; 5],
+}
+
+impl Header {
+    /// The magic bytes of the archive.
+    #[inline]
+    pub fn magic(&self) -> [u8; 8] {
+        self.magic
+    }
+
+    /// The user-defined schema version of the archive.
+    #[inline]
+    pub fn version(&self) -> u32 {
+        self.version.to_ne()
+    }
+
+    /// The alignment the archive must be loaded with.
+    #[inline]
+    pub fn align(&self) -> usize {
+        self.align.to_ne() as usize
+    }
+
+    /// The offset of the root value in the archive.
+    #[inline]
+    pub fn root(&self) -> u64 {
+        self.root.to_ne()
+    }
+
+    /// The total length of the archive, including the header.
+    #[inline]
+    #[allow(clippy::len_without_is_empty)]
+    pub fn len(&self) -> u64 {
+        self.len.to_ne()
+    }
+
+    /// The width of the [`Size`] the archive was built with, in bytes.
+    #[inline]
+    pub fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    /// Test if the archive is using the byte order `E`.
+    #[inline]
+    pub fn is_byte_order<E>(&self) -> bool
+    where
+        E: ByteOrder,
+    {
+        self.byte_order == E::TAG
+    }
+
+    /// The checksum of the archive body, if one is present.
+    #[inline]
+    pub fn checksum(&self) -> Option<u64> {
+        if self.flags & FLAG_CHECKSUM != 0 {
+            Some(self.checksum.to_ne())
+        } else {
+            None
+        }
+    }
+}
+
+/// Configuration for storing and loading an archive.
+///
+/// The byte order `E` and size `O` must match those of the buffer the archive
+/// is stored in, and are validated when the archive is loaded.
+///
+/// See the [module level documentation] for more information.
+///
+/// [module level documentation]: crate::archive
+pub struct Archive<E = Native, O = DefaultSize>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    magic: [u8; 8],
+    version: u32,
+    checksum: bool,
+    _marker: PhantomData<(E, O)>,
+}
+
+impl Archive {
+    /// Construct a new archive configuration using [`MAGIC`], version `0` and
+    /// no checksum.
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            magic: MAGIC,
+            version: 0,
+            checksum: false,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<E, O> Clone for Archive<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E, O> Copy for Archive<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+}
+
+impl Default for Archive {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E, O> Archive<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    /// Use custom magic bytes to identify the archive.
+    #[inline]
+    pub const fn with_magic(self, magic: [u8; 8]) -> Self {
+        Self { magic, ..self }
+    }
+
+    /// Set the schema version of the archive.
+    ///
+    /// Loading fails unless the version matches exactly.
+    #[inline]
+    pub const fn with_version(self, version: u32) -> Self {
+        Self { version, ..self }
+    }
+
+    /// Store a checksum of the archive body.
+    ///
+    /// When loading this requires the archive to contain a checksum. A
+    /// checksum which is present is always validated.
+    #[inline]
+    pub const fn with_checksum(self) -> Self {
+        Self {
+            checksum: true,
+            ..self
+        }
+    }
+
+    /// Modify the byte order of the archive.
+    #[inline]
+    pub const fn with_byte_order<U>(self) -> Archive<U, O>
+    where
+        U: ByteOrder,
+    {
+        Archive {
+            magic: self.magic,
+            version: self.version,
+            checksum: self.checksum,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Modify the size of the archive.
+    #[inline]
+    pub const fn with_size<U>(self) -> Archive<E, U>
+    where
+        U: Size,
+    {
+        Archive {
+            magic: self.magic,
+            version: self.version,
+            checksum: self.checksum,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Store an archive into an empty buffer.
+    ///
+    /// The header is reserved at the start of the buffer, after which `build`
+    /// is called to store the body and return a reference to the root value.
+    ///
+    /// The archive can be stored in any buffer implementing [`StoreBuf`], such
+    /// as an [`OwnedBuf`] or a [`SliceMut`].
+    ///
+    /// [`OwnedBuf`]: crate::buf::OwnedBuf
+    /// [`SliceMut`]: crate::buf::SliceMut
+    ///
+    /// # Errors
+    ///
+    /// Errors if the buffer is not empty, or if `build` errors.
+    pub fn store<T, F, S>(&self, buf: &mut S, build: F) -> Result<Ref<T, E, O>, Error>
+    where
+        T: ZeroCopy,
+        F: FnOnce(&mut S) -> Result<Ref<T, E, O>, Error>,
+        S: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
+    {
+        if buf.len() != 0 {
+            return Err(Error::new(ErrorKind::ArchiveNotEmpty { len: buf.len() }));
+        }
+
+        let header = buf.store(&self.header(0, 0, 0, None));
+        let root = build(buf)?;
+
+        let checksum = if self.checksum {
+            let body = buf.get(size_of::<Header>()..).unwrap_or_default();
+            Some(checksum(body))
+        } else {
+            None
+        };
+
+        let header_value = self.header(buf.requested(), root.offset(), buf.len(), checksum);
+        *buf.as_mut_buf().load_mut(header)? = header_value;
+        Ok(root)
+    }
+
+    /// Construct the header describing an archive stored with this
+    /// configuration.
+    fn header(&self, align: usize, root: usize, len: usize, checksum: Option<u64>) -> Header {
+        Header {
+            magic: self.magic,
+            version: Endian::new(self.version),
+            align: Endian::new(align as u32),
+            root: Endian::new(root as u64),
+            len: Endian::new(len as u64),
+            checksum: Endian::new(checksum.unwrap_or_default()),
+            byte_order: E::TAG,
+            size: size_of::<O>() as u8,
+            flags: if checksum.is_some() { FLAG_CHECKSUM } else { 0 },
+            _reserved: [0; 5],
+        }
+    }
+
+    /// Load and validate the [`Header`] of an archive.
+    ///
+    /// This only validates the magic bytes and that the header is in bounds.
+    pub fn load_header<'buf>(&self, buf: &'buf Buf) -> Result<&'buf Header, Error> {
+        let header = buf.load(Ref::<Header, E, O>::new(0))?;
+
+        if header.magic != self.magic {
+            return Err(Error::new(ErrorKind::ArchiveMagicMismatch {
+                expected: self.magic,
+                actual: header.magic,
+            }));
+        }
+
+        Ok(header)
+    }
+
+    /// Load the root value of an archive after validating its header.
+    ///
+    /// The root is loaded from the part of the buffer covered by the archive,
+    /// so any trailing data in `buf` is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Errors if any part of the header doesn't match this configuration, if
+    /// the buffer isn't aligned as required by the archive, or if the checksum
+    /// doesn't match the body.
+    pub fn load<'buf, T>(&self, buf: &'buf Buf) -> Result<&'buf T, Error>
+    where
+        T: ZeroCopy,
+    {
+        let header = self.load_header(buf)?;
+
+        if header.version() != self.version {
+            return Err(Error::new(ErrorKind::ArchiveVersionMismatch {
+                expected: self.version,
+                actual: header.version(),
+            }));
+        }
+
+        if !header.is_byte_order::<E>() {
+            return Err(Error::new(ErrorKind::ArchiveByteOrderMismatch {
+                expected: E::TAG,
+                actual: header.byte_order,
+            }));
+        }
+
+        if header.size() != size_of::<O>() {
+            return Err(Error::new(ErrorKind::ArchiveSizeMismatch {
+                expected: size_of::<O>(),
+                actual: header.size(),
+            }));
+        }
+
+        let align = header.align().max(align_of::<Header>());
+
+        if !align.is_power_of_two() || !buf.is_aligned_with(align) {
+            return Err(Error::new(ErrorKind::ArchiveAlignmentMismatch { align }));
+        }
+
+        let len = usize::try_from(header.len()).unwrap_or(usize::MAX);
+
+        let Some(bytes) = buf
+            .get(..len)
+            .filter(|bytes| bytes.len() >= size_of::<Header>())
+        else {
+            return Err(Error::new(ErrorKind::OutOfRangeBounds {
+                range: 0..len,
+                len: buf.len(),
+            }));
+        };
+
+        match header.checksum() {
+            Some(expected) => {
+                let actual = checksum(&bytes[size_of::<Header>()..]);
+
+                if actual != expected {
+                    return Err(Error::new(ErrorKind::ArchiveChecksumMismatch {
+                        expected,
+                        actual,
+                    }));
+                }
+            }
+            None if self.checksum => {
+                return Err(Error::new(ErrorKind::ArchiveMissingChecksum));
+            }
+            None => {}
+        }
+
+        let root = usize::try_from(header.root()).unwrap_or(usize::MAX);
+        Buf::new(bytes).load(Ref::<T, E, O>::try_with_metadata(root, ())?)
+    }
+}
+
+/// Load the root value `T` of an archive using the default [`Archive`]
+/// configuration.
+///
+/// See [`Archive::load`].
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::archive::{self, Archive};
+///
+/// let mut buf = OwnedBuf::new();
+/// Archive::new().store(&mut buf, |buf| Ok(buf.store(&42u32)))?;
+///
+/// assert_eq!(archive::load_archive::<u32>(&buf)?, &42);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[inline]
+pub fn load_archive<T>(buf: &Buf) -> Result<&T, Error>
+where
+    T: ZeroCopy,
+{
+    Archive::new().load(buf)
+}
+
+fn checksum(bytes: &[u8]) -> u64 {
+    let mut hasher = SipHasher13::new_with_keys(CHECKSUM_KEYS.0, CHECKSUM_KEYS.1);
+    hasher.write(bytes);
+    hasher.finish()
+}
@@ -0,0 +1,397 @@
+use core::hash::Hash;
+
+use alloc::boxed::Box;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::buf::{StoreBuf, Visit};
+use crate::endian::Native;
+use crate::pointer::{DefaultSize, Ref};
+#[cfg(feature = "std")]
+use crate::swiss;
+use crate::{sorted, Error, ZeroCopy};
+
+/// Trait for ordinary Rust types which can be stored in a buffer as a
+/// [`ZeroCopy`] counterpart.
+///
+/// Archiving a value stores everything it refers to in the buffer, such as the
+/// contents of strings, vectors and maps, and returns the
+/// [`ToArchived::Archived`] value which refers to them. The returned value can
+/// then either be stored itself through [`ToArchived::store`], or be embedded
+/// in another archived value.
+///
+/// Archived types use the [`Native`] byte order and the [`DefaultSize`] of
+/// references.
+///
+/// This is usually implemented through the [`ToArchived`][derive] derive, which
+/// also generates the archived counterpart of the type it is used on. The
+/// following types are archived as:
+///
+/// | Type                         | Archived                                  |
+/// |------------------------------|-------------------------------------------|
+/// | Primitives such as `u32`     | Themselves                                |
+/// | `String` and `str`           | [`Ref<str>`]                              |
+/// | `Vec<T>` and `[T]`           | [`Ref<[T::Archived]>`][Ref]               |
+/// | `Box<T>`                     | [`Ref<T::Archived>`][Ref]                 |
+/// | `Option<T>`                  | [`ArchivedOption<T::Archived>`]           |
+/// | `HashMap<K, V>`              | [`swiss::MapRef<K::Archived, V::Archived>`] |
+/// | `HashSet<T>`                 | [`swiss::SetRef<T::Archived>`]            |
+/// | `BTreeMap<K, V>`             | [`sorted::MapRef<K::Archived, V::Archived>`] |
+/// | `BTreeSet<T>`                | [`sorted::SetRef<T::Archived>`]           |
+///
+/// [derive]: derive@crate::ToArchived
+/// [`swiss::MapRef<K::Archived, V::Archived>`]: crate::swiss::MapRef
+/// [`swiss::SetRef<T::Archived>`]: crate::swiss::SetRef
+/// [`sorted::MapRef<K::Archived, V::Archived>`]: crate::sorted::MapRef
+/// [`sorted::SetRef<T::Archived>`]: crate::sorted::SetRef
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use musli_zerocopy::{OwnedBuf, ToArchived};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let mut map = HashMap::new();
+/// map.insert(String::from("first"), vec![1u32, 2]);
+/// map.insert(String::from("second"), vec![3u32]);
+///
+/// let map = map.store(&mut buf)?;
+/// let map = buf.load(map)?;
+///
+/// let values = map.get(&buf, "first")?.expect("missing first");
+/// assert_eq!(buf.load(*values)?, &[1, 2]);
+/// assert!(map.get(&buf, "third")?.is_none());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub trait ToArchived {
+    /// The [`ZeroCopy`] counterpart of this type.
+    type Archived: ZeroCopy;
+
+    /// Store everything this value refers to in `buf`, and return its archived
+    /// counterpart.
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>;
+
+    /// Store this value in `buf`, and return a reference to its archived
+    /// counterpart.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{OwnedBuf, ToArchived};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let values = vec![String::from("Hello"), String::from("World")];
+    /// let values = values.store(&mut buf)?;
+    ///
+    /// let values = buf.load(values)?;
+    /// let values = buf.load(*values)?;
+    /// assert_eq!(values.len(), 2);
+    /// assert_eq!(buf.load(values[0])?, "Hello");
+    /// assert_eq!(buf.load(values[1])?, "World");
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    fn store<S>(&self, buf: &mut S) -> Result<Ref<Self::Archived>, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        let archived = self.to_archived(buf)?;
+        Ok(buf.store(&archived))
+    }
+}
+
+/// The archived counterpart of an [`Option<T>`].
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{ArchivedOption, OwnedBuf, ToArchived};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let some = Some(String::from("Hello")).store(&mut buf)?;
+/// let none = None::<String>.store(&mut buf)?;
+///
+/// let ArchivedOption::Some(string) = buf.load(some)? else {
+///     panic!("expected some");
+/// };
+///
+/// assert_eq!(buf.load(*string)?, "Hello");
+/// assert!(buf.load(none)?.is_none());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, ZeroCopy)]
+#[repr(u8)]
+#[zero_copy(crate, bounds = {T: ZeroCopy})]
+pub enum ArchivedOption<T> {
+    /// No value.
+    None,
+    /// Some value of type `T`.
+    Some(T),
+}
+
+impl<T> ArchivedOption<T> {
+    /// Test if the option is [`ArchivedOption::Some`].
+    #[inline]
+    pub fn is_some(&self) -> bool {
+        matches!(self, Self::Some(..))
+    }
+
+    /// Test if the option is [`ArchivedOption::None`].
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        matches!(self, Self::None)
+    }
+
+    /// Convert into an [`Option`] referencing the contained value.
+    #[inline]
+    pub fn as_ref(&self) -> Option<&T> {
+        match self {
+            Self::None => None,
+            Self::Some(value) => Some(value),
+        }
+    }
+}
+
+impl<T> From<ArchivedOption<T>> for Option<T> {
+    #[inline]
+    fn from(value: ArchivedOption<T>) -> Self {
+        match value {
+            ArchivedOption::None => None,
+            ArchivedOption::Some(value) => Some(value),
+        }
+    }
+}
+
+macro_rules! impl_primitive {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ToArchived for $ty {
+                type Archived = $ty;
+
+                #[inline]
+                fn to_archived<S>(&self, _: &mut S) -> Result<Self::Archived, Error>
+                where
+                    S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+                {
+                    Ok(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive! {
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64, bool, char, (),
+}
+
+impl<T> ToArchived for &T
+where
+    T: ?Sized + ToArchived,
+{
+    type Archived = T::Archived;
+
+    #[inline]
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        (**self).to_archived(buf)
+    }
+}
+
+impl ToArchived for str {
+    type Archived = Ref<str>;
+
+    #[inline]
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        Ok(buf.store_unsized(self))
+    }
+}
+
+impl ToArchived for String {
+    type Archived = Ref<str>;
+
+    #[inline]
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        self.as_str().to_archived(buf)
+    }
+}
+
+impl<T> ToArchived for [T]
+where
+    T: ToArchived,
+{
+    type Archived = Ref<[T::Archived]>;
+
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        let mut values = Vec::with_capacity(self.len());
+
+        for value in self {
+            values.push(value.to_archived(buf)?);
+        }
+
+        Ok(buf.store_unsized(&values[..]))
+    }
+}
+
+impl<T> ToArchived for Vec<T>
+where
+    T: ToArchived,
+{
+    type Archived = Ref<[T::Archived]>;
+
+    #[inline]
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        self.as_slice().to_archived(buf)
+    }
+}
+
+impl<T> ToArchived for Box<T>
+where
+    T: ToArchived,
+{
+    type Archived = Ref<T::Archived>;
+
+    #[inline]
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        (**self).store(buf)
+    }
+}
+
+impl<T> ToArchived for Option<T>
+where
+    T: ToArchived,
+{
+    type Archived = ArchivedOption<T::Archived>;
+
+    #[inline]
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        Ok(match self {
+            Some(value) => ArchivedOption::Some(value.to_archived(buf)?),
+            None => ArchivedOption::None,
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V, H> ToArchived for HashMap<K, V, H>
+where
+    K: ToArchived,
+    K::Archived: Visit,
+    <K::Archived as Visit>::Target: Hash,
+    V: ToArchived,
+{
+    type Archived = swiss::MapRef<K::Archived, V::Archived>;
+
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        let mut entries = Vec::with_capacity(self.len());
+
+        for (key, value) in self {
+            entries.push((key.to_archived(buf)?, value.to_archived(buf)?));
+        }
+
+        swiss::store_map(buf, entries)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T, H> ToArchived for HashSet<T, H>
+where
+    T: ToArchived,
+    T::Archived: Visit,
+    <T::Archived as Visit>::Target: Hash,
+{
+    type Archived = swiss::SetRef<T::Archived>;
+
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        let mut values = Vec::with_capacity(self.len());
+
+        for value in self {
+            values.push(value.to_archived(buf)?);
+        }
+
+        swiss::store_set(buf, values)
+    }
+}
+
+impl<K, V> ToArchived for BTreeMap<K, V>
+where
+    K: ToArchived,
+    K::Archived: Visit,
+    <K::Archived as Visit>::Target: Ord,
+    V: ToArchived,
+{
+    type Archived = sorted::MapRef<K::Archived, V::Archived>;
+
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        let mut entries = Vec::with_capacity(self.len());
+
+        for (key, value) in self {
+            entries.push((key.to_archived(buf)?, value.to_archived(buf)?));
+        }
+
+        sorted::store_map(buf, entries)
+    }
+}
+
+impl<T> ToArchived for BTreeSet<T>
+where
+    T: ToArchived,
+    T::Archived: Visit,
+    <T::Archived as Visit>::Target: Ord,
+{
+    type Archived = sorted::SetRef<T::Archived>;
+
+    fn to_archived<S>(&self, buf: &mut S) -> Result<Self::Archived, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = Native, Size = DefaultSize>,
+    {
+        let mut values = Vec::with_capacity(self.len());
+
+        for value in self {
+            values.push(value.to_archived(buf)?);
+        }
+
+        sorted::store_set(buf, values)
+    }
+}
@@ -3,11 +3,19 @@ use crate::error::Error;
 
 mod sealed {
     use crate::endian::ByteOrder;
-    use crate::pointer::Size;
+    use crate::pointer::{Pointee, Size};
     use crate::traits::ZeroCopy;
 
     pub trait Sealed {}
 
+    impl<T, E, O> Sealed for crate::pointer::OptionRef<T, E, O>
+    where
+        T: ?Sized + Pointee,
+        E: ByteOrder,
+        O: Size,
+    {
+    }
+
     impl<K, V, E, O> Sealed for crate::phf::map::MapRef<K, V, E, O>
     where
         K: ZeroCopy,
@@ -17,6 +25,15 @@ mod sealed {
     {
     }
 
+    impl<K, V, E, O> Sealed for crate::sorted::map::MapRef<K, V, E, O>
+    where
+        K: ZeroCopy,
+        V: ZeroCopy,
+        E: ByteOrder,
+        O: Size,
+    {
+    }
+
     impl<K, V, E, O> Sealed for crate::swiss::map::MapRef<K, V, E, O>
     where
         K: ZeroCopy,
@@ -34,6 +51,14 @@ mod sealed {
     {
     }
 
+    impl<T, E, O> Sealed for crate::sorted::set::SetRef<T, E, O>
+    where
+        T: ZeroCopy,
+        E: ByteOrder,
+        O: Size,
+    {
+    }
+
     impl<T, E, O> Sealed for crate::swiss::set::SetRef<T, E, O>
     where
         T: ZeroCopy,
@@ -7,12 +7,16 @@ use core::slice::SliceIndex;
 
 #[cfg(feature = "alloc")]
 use alloc::borrow::{Cow, ToOwned};
-
 #[cfg(feature = "alloc")]
-use crate::buf::OwnedBuf;
+use alloc::vec::Vec;
+
 use crate::buf::{self, Bindable, Load, LoadMut, Validator};
+#[cfg(feature = "alloc")]
+use crate::buf::{OwnedBuf, RefVisitor};
 use crate::endian::ByteOrder;
 use crate::error::{Error, ErrorKind};
+#[cfg(feature = "alloc")]
+use crate::pointer::Pointee;
 use crate::pointer::{Ref, Size};
 use crate::traits::{UnsizedZeroCopy, ZeroCopy};
 
@@ -596,31 +600,94 @@ impl Buf {
     where
         T: ZeroCopy,
     {
-        self.ensure_compatible_with::<T>()?;
+        self.ensure_compatible_with::<T>()
+            .map_err(|error| error.with_load::<T>(0))?;
         Ok(Validator::from_slice(&self.data))
     }
 
+    /// Validate the value pointed to by `reference`, and every value which is
+    /// reachable from it through other references.
+    ///
+    /// Unlike [`Buf::load`], which only validates the value being loaded, this
+    /// walks through every reference reported by [`ZeroCopy::visit_refs`] and
+    /// collects every problem found instead of stopping at the first one. The
+    /// [`path`] of each error describes how it was reached from `reference`.
+    ///
+    /// [`path`]: Error::path
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+    ///
+    /// #[derive(ZeroCopy)]
+    /// #[repr(C)]
+    /// struct Person {
+    ///     name: Ref<str>,
+    ///     age: u8,
+    /// }
+    ///
+    /// #[derive(ZeroCopy)]
+    /// #[repr(C)]
+    /// struct Team {
+    ///     members: Ref<[Person]>,
+    /// }
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let name = buf.store_unsized("Aristotle");
+    /// let members = buf.store_unsized(&[
+    ///     Person { name, age: 62 },
+    ///     Person { name: Ref::with_metadata(name.offset(), 1024usize), age: 61 },
+    /// ][..]);
+    /// let team = buf.store(&Team { members });
+    ///
+    /// // Loading the team only validates the reference to its members.
+    /// assert!(buf.load(team).is_ok());
+    ///
+    /// let errors = buf.validate_deep(team).unwrap_err();
+    /// assert_eq!(errors.len(), 1);
+    /// assert!(errors[0].to_string().contains(".members -> "));
+    /// assert!(errors[0].to_string().contains("[1].name -> str@0"));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn validate_deep<T, E, O>(&self, reference: Ref<T, E, O>) -> Result<(), Vec<Error>>
+    where
+        T: ?Sized + Pointee,
+        E: ByteOrder,
+        O: Size,
+    {
+        let errors = RefVisitor::visit_all(self, reference);
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(())
+    }
+
     pub(crate) unsafe fn get_range_from(
         &self,
         start: usize,
         align: usize,
     ) -> Result<(NonNull<u8>, usize), Error> {
-        // if self.data.len() < start {
-        //     return Err(Error::new(ErrorKind::OutOfRangeFromBounds {
-        //         range: start..,
-        //         len: self.data.len(),
-        //     }));
-        // };
+        if self.data.len() < start {
+            return Err(Error::new(ErrorKind::OutOfRangeFromBounds {
+                range: start..,
+                len: self.data.len(),
+            }));
+        };
 
         let ptr = NonNull::new_unchecked(self.data.as_ptr().add(start) as *mut _);
         let remaining = self.data.len() - start;
 
-        // if !buf::is_aligned_with(ptr.as_ptr(), align) {
-        //     return Err(Error::new(ErrorKind::AlignmentRangeFromMismatch {
-        //         range: start..,
-        //         align,
-        //     }));
-        // }
+        if !buf::is_aligned_with(ptr.as_ptr(), align) {
+            return Err(Error::new(ErrorKind::AlignmentRangeFromMismatch {
+                range: start..,
+                align,
+            }));
+        }
 
         Ok((ptr, remaining))
     }
@@ -747,8 +814,11 @@ impl Buf {
         // SAFETY: Alignment and size is checked just above when getting the
         // buffer slice.
         unsafe {
-            let (buf, remaining) = self.get_range_from(start, T::ALIGN)?;
-            let metadata = T::validate_unsized::<E, O>(buf, remaining, metadata)?;
+            let (buf, remaining) = self
+                .get_range_from(start, T::ALIGN)
+                .map_err(|error| error.with_load::<T>(start))?;
+            let metadata = T::validate_unsized::<E, O>(buf, remaining, metadata)
+                .map_err(|error| error.with_load::<T>(start))?;
             Ok(&*T::with_metadata(buf, metadata))
         }
     }
@@ -793,8 +863,11 @@ impl Buf {
         // SAFETY: Alignment and size is checked just above when getting the
         // buffer slice.
         unsafe {
-            let (buf, remaining) = self.get_mut_range_from(start, T::ALIGN)?;
-            let metadata = T::validate_unsized::<E, O>(buf, remaining, metadata)?;
+            let (buf, remaining) = self
+                .get_mut_range_from(start, T::ALIGN)
+                .map_err(|error| error.with_load::<T>(start))?;
+            let metadata = T::validate_unsized::<E, O>(buf, remaining, metadata)
+                .map_err(|error| error.with_load::<T>(start))?;
             Ok(&mut *T::with_metadata_mut(buf, metadata))
         }
     }
@@ -809,13 +882,16 @@ impl Buf {
             let end = offset + size_of::<T>();
 
             // SAFETY: align_of::<T>() is always a power of two.
-            let buf = self.inner_get(offset, end, align_of::<T>())?;
+            let buf = self
+                .inner_get(offset, end, align_of::<T>())
+                .map_err(|error| error.with_load::<T>(offset))?;
 
             if !T::ANY_BITS {
                 // SAFETY: We've checked the size and alignment of the buffer above.
                 // The remaining safety requirements depend on the implementation of
                 // validate.
-                T::validate(&mut Validator::from_slice(buf))?;
+                T::validate(&mut Validator::from_slice(buf))
+                    .map_err(|error| error.with_load::<T>(offset))?;
             }
 
             // SAFETY: Implementing ANY_BITS is unsafe, and requires that the
@@ -934,13 +1010,16 @@ impl Buf {
 
         unsafe {
             // SAFETY: align_of::<T>() is always a power of two.
-            let buf = self.inner_get_mut(offset, end, align_of::<T>())?;
+            let buf = self
+                .inner_get_mut(offset, end, align_of::<T>())
+                .map_err(|error| error.with_load::<T>(offset))?;
 
             if !T::ANY_BITS {
                 // SAFETY: We've checked the size and alignment of the buffer above.
                 // The remaining safety requirements depend on the implementation of
                 // validate.
-                T::validate(&mut Validator::from_slice(buf))?;
+                T::validate(&mut Validator::from_slice(buf))
+                    .map_err(|error| error.with_load::<T>(offset))?;
             }
 
             // SAFETY: Implementing ANY_BITS is unsafe, and requires that the
@@ -959,13 +1038,16 @@ impl Buf {
 
         unsafe {
             // SAFETY: align_of::<T>() is always a power of two.
-            let buf = self.inner_get_unaligned(start, end)?;
+            let buf = self
+                .inner_get_unaligned(start, end)
+                .map_err(|error| error.with_load::<T>(start))?;
 
             if !T::ANY_BITS {
                 // SAFETY: We've checked the size and alignment of the buffer above.
                 // The remaining safety requirements depend on the implementation of
                 // validate.
-                T::validate(&mut Validator::from_slice(buf))?;
+                T::validate(&mut Validator::from_slice(buf))
+                    .map_err(|error| error.with_load::<T>(start))?;
             }
 
             // SAFETY: Implementing ANY_BITS is unsafe, and requires that the
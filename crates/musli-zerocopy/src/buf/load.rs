@@ -1,7 +1,9 @@
+use core::any::type_name;
+
 use crate::buf::Buf;
 use crate::endian::ByteOrder;
-use crate::error::Error;
-use crate::pointer::{Ref, Size};
+use crate::error::{Error, ErrorKind};
+use crate::pointer::{OptionRef, Pointee, Ref, Size};
 use crate::traits::ZeroCopy;
 
 /// Trait used for loading any kind of reference through [`Buf::load`].
@@ -126,3 +128,51 @@ where
         buf.load_unsized_mut(*self)
     }
 }
+
+impl<T, E, O> Load for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    Ref<T, E, O>: Load,
+    E: ByteOrder,
+    O: Size,
+{
+    type Target = <Ref<T, E, O> as Load>::Target;
+
+    #[inline]
+    fn load<'buf>(&self, buf: &'buf Buf) -> Result<&'buf Self::Target, Error> {
+        match self.get() {
+            Some(reference) => reference.load(buf),
+            None => Err(Error::new(ErrorKind::NoneRef {
+                name: type_name::<T>(),
+            })),
+        }
+    }
+
+    #[inline]
+    unsafe fn load_unchecked<'buf>(&self, buf: &'buf Buf) -> Result<&'buf Self::Target, Error> {
+        match self.get() {
+            Some(reference) => reference.load_unchecked(buf),
+            None => Err(Error::new(ErrorKind::NoneRef {
+                name: type_name::<T>(),
+            })),
+        }
+    }
+}
+
+impl<T, E, O> LoadMut for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    Ref<T, E, O>: LoadMut,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn load_mut<'buf>(&self, buf: &'buf mut Buf) -> Result<&'buf mut Self::Target, Error> {
+        match self.get() {
+            Some(reference) => reference.load_mut(buf),
+            None => Err(Error::new(ErrorKind::NoneRef {
+                name: type_name::<T>(),
+            })),
+        }
+    }
+}
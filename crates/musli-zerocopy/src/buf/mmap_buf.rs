@@ -0,0 +1,137 @@
+use core::borrow::Borrow;
+use core::ops::Deref;
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+use crate::buf::{self, Buf};
+
+/// The alignment which is guaranteed for the start of a memory mapped buffer.
+///
+/// Mappings always start at the beginning of a page, and every supported
+/// platform uses pages of at least this size.
+pub const MMAP_ALIGNMENT: usize = 4096;
+
+/// A read-only buffer backed by a memory mapped file.
+///
+/// The mapping is guaranteed to be aligned by [`MMAP_ALIGNMENT`], so any type
+/// with an alignment up to that is compatible with the buffer as checked by
+/// [`Buf::is_compatible_with`]. This means that archives can be used directly
+/// without first being copied into an aligned allocation.
+///
+/// This is only available with the `mmap` feature.
+///
+/// # Examples
+///
+/// ```no_run
+/// use musli_zerocopy::{Ref, ZeroCopy};
+/// use musli_zerocopy::buf::MmapBuf;
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// struct Person {
+///     name: Ref<str>,
+///     age: u32,
+/// }
+///
+/// // SAFETY: The file is not modified while it is mapped.
+/// let buf = unsafe { MmapBuf::open("person.bin")? };
+/// assert!(buf.is_compatible_with::<Person>());
+///
+/// let person = buf.load(Ref::<Person>::zero())?;
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct MmapBuf {
+    map: Mmap,
+}
+
+impl MmapBuf {
+    /// Open and map the file at the given path.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the underlying file is not modified or
+    /// truncated for as long as the mapping is alive, since this would change
+    /// the contents of a buffer which is assumed to be immutable.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the file could not be opened or mapped, or if the mapping
+    /// does not have the alignment promised by [`MMAP_ALIGNMENT`].
+    pub unsafe fn open<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::open(path)?;
+        Self::from_file(&file)
+    }
+
+    /// Map the given file.
+    ///
+    /// The mapping is kept alive independently of `file`, so the file handle
+    /// can be dropped once this returns.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the underlying file is not modified or
+    /// truncated for as long as the mapping is alive, since this would change
+    /// the contents of a buffer which is assumed to be immutable.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the file could not be mapped, or if the mapping does not have
+    /// the alignment promised by [`MMAP_ALIGNMENT`].
+    pub unsafe fn from_file(file: &File) -> io::Result<Self> {
+        let map = Mmap::map(file)?;
+
+        if !buf::is_aligned_with(map.as_ptr(), MMAP_ALIGNMENT) {
+            return Err(io::Error::other("Memory mapping is not page aligned"));
+        }
+
+        Ok(Self { map })
+    }
+
+    /// Get the length of the mapped file.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Test if the mapped file is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Get the mapped file as a [`Buf`].
+    #[inline]
+    pub fn as_buf(&self) -> &Buf {
+        Buf::new(&self.map)
+    }
+}
+
+impl Deref for MmapBuf {
+    type Target = Buf;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.as_buf()
+    }
+}
+
+impl AsRef<Buf> for MmapBuf {
+    #[inline]
+    fn as_ref(&self) -> &Buf {
+        self
+    }
+}
+
+impl Borrow<Buf> for MmapBuf {
+    #[inline]
+    fn borrow(&self) -> &Buf {
+        self
+    }
+}
@@ -0,0 +1,491 @@
+use core::borrow::Borrow;
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of, size_of_val};
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+use core::slice::SliceIndex;
+
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+use memmap2::MmapMut;
+
+use crate::buf::{self, Buf, DefaultAlignment, MmapBuf, Padder, StoreBuf, MMAP_ALIGNMENT};
+use crate::endian::{ByteOrder, Native};
+use crate::error::Error;
+use crate::pointer::{DefaultSize, Ref, Size};
+use crate::traits::{UnsizedZeroCopy, ZeroCopy};
+
+/// A growable buffer which writes directly into a memory mapped file.
+///
+/// The backing file is grown as needed, so archives which are larger than
+/// available memory can be constructed without first being built in an
+/// [`OwnedBuf`]. Once construction is done, [`MmapWriter::finish`] truncates
+/// the file to the written length and maps it read-only as an [`MmapBuf`].
+///
+/// Since the [`StoreBuf`] methods can't report errors, failing to grow the
+/// backing file while storing a value will panic. Use
+/// [`MmapWriter::try_reserve`] up front to handle this gracefully.
+///
+/// This is only available with the `mmap` feature.
+///
+/// [`OwnedBuf`]: crate::buf::OwnedBuf
+///
+/// # Examples
+///
+/// ```no_run
+/// use musli_zerocopy::buf::MmapWriter;
+/// use musli_zerocopy::swiss;
+///
+/// // SAFETY: Nothing else accesses the file while it is mapped.
+/// let mut buf = unsafe { MmapWriter::create("map.bin")? };
+/// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+///
+/// let buf = buf.finish()?;
+/// let map = buf.bind(map)?;
+/// assert_eq!(map.get(&1)?, Some(&2));
+/// # Ok::<_, anyhow::Error>(())
+/// ```
+pub struct MmapWriter<E = Native, O = DefaultSize>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    /// The backing file.
+    file: File,
+    /// The current mapping of the file, whose length is the capacity of the
+    /// buffer.
+    map: MmapMut,
+    /// The initialized length of the buffer.
+    len: usize,
+    /// The requested alignment.
+    requested: usize,
+    /// Sticky endianness and pointer size.
+    _marker: PhantomData<(E, O)>,
+}
+
+impl MmapWriter {
+    /// Create or truncate the file at the given path and construct a writer
+    /// into it.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the underlying file is not accessed or
+    /// modified by anything else for as long as it is mapped.
+    pub unsafe fn create<P>(path: P) -> io::Result<Self>
+    where
+        P: AsRef<Path>,
+    {
+        let file = File::options()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+
+        Self::from_file(file)
+    }
+
+    /// Construct a writer into the given file, which must be opened for both
+    /// reading and writing.
+    ///
+    /// Any existing contents of the file are discarded.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the underlying file is not accessed or
+    /// modified by anything else for as long as it is mapped.
+    pub unsafe fn from_file(file: File) -> io::Result<Self> {
+        file.set_len(0)?;
+        let map = MmapMut::map_mut(&file)?;
+
+        Ok(Self {
+            file,
+            map,
+            len: 0,
+            requested: align_of::<DefaultAlignment>(),
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<E, O> MmapWriter<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    /// Modify the size of the writer. See [`OwnedBuf::with_size`].
+    ///
+    /// [`OwnedBuf::with_size`]: crate::buf::OwnedBuf::with_size
+    #[inline]
+    pub fn with_size<U: Size>(self) -> MmapWriter<E, U> {
+        MmapWriter {
+            file: self.file,
+            map: self.map,
+            len: self.len,
+            requested: self.requested,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Modify the byte order of the writer. See [`OwnedBuf::with_byte_order`].
+    ///
+    /// [`OwnedBuf::with_byte_order`]: crate::buf::OwnedBuf::with_byte_order
+    #[inline]
+    pub fn with_byte_order<U>(self) -> MmapWriter<U, O>
+    where
+        U: ByteOrder,
+    {
+        MmapWriter {
+            file: self.file,
+            map: self.map,
+            len: self.len,
+            requested: self.requested,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Get the current length of the buffer.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Test if the buffer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the current capacity of the buffer, which is the size of the
+    /// backing file while it is being written.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Return the requested alignment of the buffer.
+    #[inline]
+    pub fn requested(&self) -> usize {
+        self.requested
+    }
+
+    /// Reserve capacity for at least `capacity` more bytes in this buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the backing file could not be grown. See
+    /// [`MmapWriter::try_reserve`] for a fallible version.
+    #[inline]
+    pub fn reserve(&mut self, capacity: usize) {
+        if let Err(error) = self.try_reserve(capacity) {
+            panic!("Failed to grow memory mapped file: {error}");
+        }
+    }
+
+    /// Try to reserve capacity for at least `capacity` more bytes in this
+    /// buffer.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the backing file could not be grown or re-mapped.
+    pub fn try_reserve(&mut self, capacity: usize) -> io::Result<()> {
+        let new_capacity = self.len + capacity;
+
+        if self.map.len() >= new_capacity {
+            return Ok(());
+        }
+
+        let new_capacity = new_capacity
+            .max(self.map.len().saturating_mul(2))
+            .max(MMAP_ALIGNMENT);
+
+        self.file.set_len(new_capacity as u64)?;
+        // SAFETY: The caller of the constructor is responsible for ensuring
+        // that nothing else modifies the file. The previous mapping is shared,
+        // so anything written to it is visible through the new one.
+        self.map = unsafe { MmapMut::map_mut(&self.file)? };
+        Ok(())
+    }
+
+    /// Flush outstanding modifications of the mapping to the backing file.
+    pub fn flush(&self) -> io::Result<()> {
+        self.map.flush()
+    }
+
+    /// Finish writing, truncating the backing file to the written length and
+    /// mapping it read-only.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the mapping could not be flushed, or if the file could not be
+    /// truncated or mapped again.
+    pub fn finish(self) -> io::Result<MmapBuf> {
+        self.map.flush()?;
+        drop(self.map);
+        self.file.set_len(self.len as u64)?;
+        // SAFETY: The caller of the constructor is responsible for ensuring
+        // that nothing else modifies the file.
+        unsafe { MmapBuf::from_file(&self.file) }
+    }
+
+    /// Get the written part of the buffer as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.map[..self.len]
+    }
+
+    /// Get the written part of the buffer as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.map[..self.len]
+    }
+
+    /// Store a [`ZeroCopy`] value in the buffer. See [`OwnedBuf::store`].
+    ///
+    /// [`OwnedBuf::store`]: crate::buf::OwnedBuf::store
+    #[inline]
+    pub fn store<T>(&mut self, value: &T) -> Ref<T, E, O>
+    where
+        T: ZeroCopy,
+    {
+        self.next_offset_with_and_reserve(align_of::<T>(), size_of::<T>());
+
+        // SAFETY: We're ensuring to both align the internal buffer and store
+        // the value.
+        unsafe {
+            let offset = self.len;
+            let ptr = NonNull::new_unchecked(self.map.as_mut_ptr().add(offset));
+            buf::store_unaligned(ptr, value);
+            self.len += size_of::<T>();
+            Ref::new(offset)
+        }
+    }
+
+    /// Store an unsized [`ZeroCopy`] value in the buffer. See
+    /// [`OwnedBuf::store_unsized`].
+    ///
+    /// [`OwnedBuf::store_unsized`]: crate::buf::OwnedBuf::store_unsized
+    #[inline]
+    pub fn store_unsized<T>(&mut self, value: &T) -> Ref<T, E, O>
+    where
+        T: ?Sized + UnsizedZeroCopy,
+    {
+        unsafe {
+            let size = size_of_val(value);
+            self.next_offset_with_and_reserve(T::ALIGN, size);
+            let offset = self.len;
+            let ptr = NonNull::new_unchecked(self.map.as_mut_ptr().add(offset));
+            ptr.as_ptr().copy_from_nonoverlapping(value.as_ptr(), size);
+
+            if T::PADDED {
+                let mut padder = Padder::new(ptr);
+                value.pad(&mut padder);
+                padder.remaining_unsized(value);
+            }
+
+            self.len += size;
+            Ref::with_metadata(offset, value.metadata())
+        }
+    }
+
+    /// Store a slice of [`ZeroCopy`] values in the buffer.
+    #[inline(always)]
+    pub fn store_slice<T>(&mut self, values: &[T]) -> Ref<[T], E, O>
+    where
+        T: ZeroCopy,
+    {
+        self.store_unsized(values)
+    }
+
+    /// Extend the buffer from a slice of bytes.
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        self.map[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+        self.len += bytes.len();
+    }
+
+    /// Align the buffer for `T` and return the offset at which it would be
+    /// written.
+    #[inline]
+    pub fn next_offset<T>(&mut self) -> usize {
+        self.next_offset_with_and_reserve(align_of::<T>(), size_of::<T>());
+        self.len
+    }
+
+    fn fill(&mut self, byte: u8, len: usize) {
+        self.reserve(len);
+        self.map[self.len..self.len + len].fill(byte);
+        self.len += len;
+    }
+
+    #[inline]
+    fn next_offset_with_and_reserve(&mut self, align: usize, reserve: usize) {
+        self.requested = self.requested.max(align);
+        let extra = buf::padding_to(self.len, align);
+        self.fill(0, extra);
+        self.reserve(reserve);
+    }
+}
+
+impl<E, O> Deref for MmapWriter<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    type Target = Buf;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        Buf::new(self.as_slice())
+    }
+}
+
+impl<E, O> DerefMut for MmapWriter<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        Buf::new_mut(self.as_mut_slice())
+    }
+}
+
+impl<E, O> AsRef<Buf> for MmapWriter<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn as_ref(&self) -> &Buf {
+        self
+    }
+}
+
+impl<E, O> AsMut<Buf> for MmapWriter<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn as_mut(&mut self) -> &mut Buf {
+        self
+    }
+}
+
+impl<E, O> Borrow<Buf> for MmapWriter<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn borrow(&self) -> &Buf {
+        self.as_ref()
+    }
+}
+
+impl<E, O> StoreBuf for MmapWriter<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    type ByteOrder = E;
+    type Size = O;
+
+    #[inline]
+    fn len(&self) -> usize {
+        MmapWriter::len(self)
+    }
+
+    #[inline]
+    fn requested(&self) -> usize {
+        MmapWriter::requested(self)
+    }
+
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        if self.len > len {
+            self.len = len;
+        }
+    }
+
+    #[inline]
+    fn store_unsized<T>(&mut self, value: &T) -> Ref<T, Self::ByteOrder, Self::Size>
+    where
+        T: ?Sized + UnsizedZeroCopy,
+    {
+        MmapWriter::store_unsized(self, value)
+    }
+
+    #[inline]
+    fn store<T>(&mut self, value: &T) -> Ref<T, Self::ByteOrder, Self::Size>
+    where
+        T: ZeroCopy,
+    {
+        MmapWriter::store(self, value)
+    }
+
+    #[inline]
+    fn swap<T>(
+        &mut self,
+        a: Ref<T, Self::ByteOrder, Self::Size>,
+        b: Ref<T, Self::ByteOrder, Self::Size>,
+    ) -> Result<(), Error>
+    where
+        T: ZeroCopy,
+    {
+        Buf::swap(self, a, b)
+    }
+
+    #[inline]
+    fn align_in_place(&mut self) {
+        // Mappings are always aligned to the start of a page, so this only
+        // fails if a type requests an alignment larger than that.
+        if !buf::is_aligned_with(self.map.as_ptr(), self.requested) {
+            panic!("Memory mapping is not aligned by {}", self.requested);
+        }
+    }
+
+    #[inline]
+    fn next_offset<T>(&mut self) -> usize {
+        MmapWriter::next_offset::<T>(self)
+    }
+
+    #[inline]
+    fn next_offset_with_and_reserve(&mut self, align: usize, reserve: usize) {
+        MmapWriter::next_offset_with_and_reserve(self, align, reserve)
+    }
+
+    #[inline]
+    fn fill(&mut self, byte: u8, len: usize) {
+        MmapWriter::fill(self, byte, len);
+    }
+
+    #[inline]
+    fn get<I>(&self, index: I) -> Option<&I::Output>
+    where
+        I: SliceIndex<[u8]>,
+    {
+        Buf::get(self, index)
+    }
+
+    #[inline]
+    fn get_mut<I>(&mut self, index: I) -> Option<&mut I::Output>
+    where
+        I: SliceIndex<[u8]>,
+    {
+        Buf::get_mut(self, index)
+    }
+
+    #[inline]
+    fn as_buf(&self) -> &Buf {
+        self
+    }
+
+    #[inline]
+    fn as_mut_buf(&mut self) -> &mut Buf {
+        self
+    }
+}
@@ -105,6 +105,9 @@ pub(crate) mod visit;
 pub use self::validator::Validator;
 mod validator;
 
+pub use self::ref_visitor::RefVisitor;
+pub(crate) mod ref_visitor;
+
 pub use self::padder::Padder;
 mod padder;
 
@@ -119,6 +122,16 @@ mod owned_buf;
 pub use self::slice_mut::SliceMut;
 mod slice_mut;
 
+#[cfg(feature = "mmap")]
+pub use self::mmap_buf::{MmapBuf, MMAP_ALIGNMENT};
+#[cfg(feature = "mmap")]
+mod mmap_buf;
+
+#[cfg(feature = "mmap")]
+pub use self::mmap_writer::MmapWriter;
+#[cfg(feature = "mmap")]
+mod mmap_writer;
+
 use core::mem::size_of;
 use core::ptr::NonNull;
 
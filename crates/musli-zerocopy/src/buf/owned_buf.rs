@@ -1317,6 +1317,11 @@ where
         OwnedBuf::len(self)
     }
 
+    #[inline]
+    fn requested(&self) -> usize {
+        OwnedBuf::requested(self)
+    }
+
     #[inline]
     fn truncate(&mut self, len: usize) {
         if self.len > len {
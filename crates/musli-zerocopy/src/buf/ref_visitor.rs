@@ -0,0 +1,329 @@
+use core::any::type_name;
+
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::buf::Buf;
+use crate::endian::{ByteOrder, Native};
+use crate::error::{Error, PathSegment};
+use crate::pointer::{Pointee, Ref, Size};
+use crate::traits::ZeroCopy;
+
+/// The function used to visit a queued reference.
+type VisitFn = for<'a> fn(&mut RefVisitor<'a>, usize, usize);
+
+/// Visitor over every reference reachable from a value, constructed through
+/// [`Buf::validate_deep`].
+///
+/// References are reported to the visitor through
+/// [`ZeroCopy::visit_refs`], which is implemented by the
+/// [`ZeroCopy`][derive@crate::ZeroCopy] derive for every field in a type.
+///
+/// Every reference is only visited once, so cyclical references are
+/// supported. Visiting is not recursive, so arbitrarily long chains of
+/// references can be visited.
+pub struct RefVisitor<'a> {
+    buf: &'a Buf,
+    #[cfg(feature = "alloc")]
+    state: State,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Default)]
+struct State {
+    // Path segments which queued references were found in.
+    nodes: Vec<Node>,
+    // The node of the reference currently being visited.
+    root: Option<usize>,
+    // Path segments inside of the reference currently being visited.
+    stack: Vec<PathSegment>,
+    // References which have been queued, by offset, metadata and type.
+    visited: BTreeSet<(usize, usize, &'static str)>,
+    pending: Vec<Pending>,
+    errors: Vec<Error>,
+}
+
+#[cfg(feature = "alloc")]
+struct Node {
+    parent: Option<usize>,
+    segment: PathSegment,
+}
+
+#[cfg(feature = "alloc")]
+struct Pending {
+    node: Option<usize>,
+    offset: usize,
+    metadata: usize,
+    visit: VisitFn,
+}
+
+impl<'a> RefVisitor<'a> {
+    /// Visit `reference` and every reference reachable from it, collecting
+    /// every error encountered along the way.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn visit_all<T, E, O>(buf: &'a Buf, reference: Ref<T, E, O>) -> Vec<Error>
+    where
+        T: ?Sized + Pointee,
+        E: ByteOrder,
+        O: Size,
+    {
+        let mut this = Self {
+            buf,
+            state: State::default(),
+        };
+
+        this.visit_ref(reference);
+
+        while let Some(pending) = this.state.pending.pop() {
+            this.state.root = pending.node;
+            this.state.stack.clear();
+
+            let len = this.state.pending.len();
+            (pending.visit)(&mut this, pending.offset, pending.metadata);
+            // Visit newly queued references in the order they were found.
+            this.state.pending[len..].reverse();
+        }
+
+        this.state.errors
+    }
+
+    /// The buffer being visited.
+    #[inline]
+    pub fn buf(&self) -> &'a Buf {
+        self.buf
+    }
+
+    /// Visit a reference, validating what it points to and visiting every
+    /// reference reachable from it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{ByteOrder, Error, OwnedBuf, Ref, ZeroCopy};
+    /// use musli_zerocopy::buf::{Padder, RefVisitor, Validator};
+    ///
+    /// /// A reference to a string stored as a plain offset and length.
+    /// #[derive(Clone, Copy)]
+    /// #[repr(C)]
+    /// struct Name {
+    ///     offset: u32,
+    ///     len: u32,
+    /// }
+    ///
+    /// unsafe impl ZeroCopy for Name {
+    ///     const ANY_BITS: bool = true;
+    ///     const PADDED: bool = false;
+    ///     const CAN_SWAP_BYTES: bool = true;
+    ///
+    ///     unsafe fn pad(padder: &mut Padder<'_, Self>) {
+    ///         padder.pad::<u32>();
+    ///         padder.pad::<u32>();
+    ///     }
+    ///
+    ///     unsafe fn validate(validator: &mut Validator<'_, Self>) -> Result<(), Error> {
+    ///         validator.validate::<u32>()?;
+    ///         validator.validate::<u32>()
+    ///     }
+    ///
+    ///     fn swap_bytes<E: ByteOrder>(self) -> Self {
+    ///         Self {
+    ///             offset: <u32 as ZeroCopy>::swap_bytes::<E>(self.offset),
+    ///             len: <u32 as ZeroCopy>::swap_bytes::<E>(self.len),
+    ///         }
+    ///     }
+    ///
+    ///     fn visit_refs(&self, visitor: &mut RefVisitor<'_>) {
+    ///         visitor.visit_ref(Ref::<str>::with_metadata(self.offset, self.len as usize));
+    ///     }
+    /// }
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// let string = buf.store_unsized("Hello");
+    /// let name = buf.store(&Name { offset: string.offset() as u32, len: 5 });
+    /// let broken = buf.store(&Name { offset: string.offset() as u32, len: 64 });
+    ///
+    /// assert!(buf.validate_deep(name).is_ok());
+    /// assert_eq!(buf.validate_deep(broken).unwrap_err().len(), 1);
+    /// ```
+    #[inline]
+    pub fn visit_ref<T, E, O>(&mut self, reference: Ref<T, E, O>)
+    where
+        T: ?Sized + Pointee,
+        E: ByteOrder,
+        O: Size,
+    {
+        T::visit_ref(reference, self);
+    }
+
+    /// Visit the references inside of the field `field` of the type `T`.
+    ///
+    /// Any errors found while visiting the references will include the field
+    /// in their [`path`].
+    ///
+    /// [`path`]: Error::path
+    #[inline]
+    pub fn field<T, F>(&mut self, field: &'static str, visit: F)
+    where
+        T: ?Sized,
+        F: FnOnce(&mut Self),
+    {
+        self.enter(
+            PathSegment::Field {
+                name: type_name::<T>(),
+                field,
+            },
+            visit,
+        );
+    }
+
+    /// Visit the references inside of the element at `index`.
+    ///
+    /// Any errors found while visiting the references will include the index
+    /// in their [`path`].
+    ///
+    /// [`path`]: Error::path
+    #[inline]
+    pub fn index<F>(&mut self, index: usize, visit: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        self.enter(PathSegment::Index { index }, visit);
+    }
+
+    /// Queue the reference at `offset` with `metadata` to be visited by
+    /// `visit`, unless it has already been visited.
+    #[inline]
+    #[cfg_attr(
+        not(feature = "alloc"),
+        allow(unused_variables, clippy::extra_unused_type_parameters)
+    )]
+    pub(crate) fn queue<T>(&mut self, offset: usize, metadata: usize, visit: VisitFn)
+    where
+        T: ?Sized,
+    {
+        #[cfg(feature = "alloc")]
+        {
+            let state = &mut self.state;
+
+            if !state.visited.insert((offset, metadata, type_name::<T>())) {
+                return;
+            }
+
+            let mut node = state.root;
+
+            for &segment in &state.stack {
+                state.nodes.push(Node {
+                    parent: node,
+                    segment,
+                });
+
+                node = Some(state.nodes.len() - 1);
+            }
+
+            state.pending.push(Pending {
+                node,
+                offset,
+                metadata,
+                visit,
+            });
+        }
+    }
+
+    /// Report an error found at the current path.
+    #[inline]
+    fn report(&mut self, error: Error) {
+        #[cfg(feature = "alloc")]
+        {
+            let state = &mut self.state;
+
+            let mut path = Vec::new();
+            let mut node = state.root;
+
+            while let Some(index) = node {
+                let Node { parent, segment } = state.nodes[index];
+                path.push(segment);
+                node = parent;
+            }
+
+            path.reverse();
+            path.extend_from_slice(&state.stack);
+            state.errors.push(error.with_prefix(path));
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = error;
+        }
+    }
+
+    #[inline]
+    fn enter<F>(&mut self, segment: PathSegment, visit: F)
+    where
+        F: FnOnce(&mut Self),
+    {
+        #[cfg(feature = "alloc")]
+        self.state.stack.push(segment);
+        #[cfg(not(feature = "alloc"))]
+        let _ = segment;
+
+        visit(self);
+
+        #[cfg(feature = "alloc")]
+        self.state.stack.pop();
+    }
+}
+
+/// Visit a queued reference to a sized `T`.
+pub(crate) fn visit_sized<T>(visitor: &mut RefVisitor<'_>, offset: usize, _: usize)
+where
+    T: ZeroCopy,
+{
+    match visitor.buf.load_sized::<T>(offset) {
+        Ok(value) => {
+            let segment = PathSegment::Load {
+                name: type_name::<T>(),
+                offset,
+            };
+
+            visitor.enter(segment, |visitor| value.visit_refs(visitor));
+        }
+        Err(error) => visitor.report(error),
+    }
+}
+
+/// Visit a queued reference to a slice `[T]`.
+pub(crate) fn visit_slice<T>(visitor: &mut RefVisitor<'_>, offset: usize, len: usize)
+where
+    T: ZeroCopy,
+{
+    match visitor
+        .buf
+        .load(Ref::<[T], Native, usize>::with_metadata(offset, len))
+    {
+        Ok(values) => {
+            let segment = PathSegment::Load {
+                name: type_name::<[T]>(),
+                offset,
+            };
+
+            visitor.enter(segment, |visitor| {
+                for (index, value) in values.iter().enumerate() {
+                    visitor.index(index, |visitor| value.visit_refs(visitor));
+                }
+            });
+        }
+        Err(error) => visitor.report(error),
+    }
+}
+
+/// Visit a queued reference to a `str`.
+pub(crate) fn visit_str(visitor: &mut RefVisitor<'_>, offset: usize, len: usize) {
+    if let Err(error) = visitor
+        .buf
+        .load(Ref::<str, Native, usize>::with_metadata(offset, len))
+    {
+        visitor.report(error);
+    }
+}
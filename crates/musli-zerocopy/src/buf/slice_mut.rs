@@ -1007,6 +1007,11 @@ where
         SliceMut::len(self)
     }
 
+    #[inline]
+    fn requested(&self) -> usize {
+        SliceMut::requested(self)
+    }
+
     #[inline]
     fn truncate(&mut self, len: usize) {
         if self.len > len {
@@ -4,6 +4,8 @@ use crate::traits::{UnsizedZeroCopy, ZeroCopy};
 use crate::{Buf, ByteOrder, Error, Ref, Size};
 
 mod sealed {
+    #[cfg(feature = "mmap")]
+    use crate::buf::MmapWriter;
     #[cfg(feature = "alloc")]
     use crate::buf::OwnedBuf;
     use crate::buf::SliceMut;
@@ -25,6 +27,14 @@ mod sealed {
         O: Size,
     {
     }
+
+    #[cfg(feature = "mmap")]
+    impl<E, O> Sealed for MmapWriter<E, O>
+    where
+        E: ByteOrder,
+        O: Size,
+    {
+    }
 }
 
 /// A buffer that we can store things into.
@@ -40,6 +50,10 @@ pub trait StoreBuf: self::sealed::Sealed {
     #[doc(hidden)]
     fn len(&self) -> usize;
 
+    /// The alignment requested for the buffer.
+    #[doc(hidden)]
+    fn requested(&self) -> usize;
+
     /// Truncate the buffer to the given length.
     #[doc(hidden)]
     fn truncate(&mut self, len: usize);
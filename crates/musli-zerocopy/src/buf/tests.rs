@@ -188,3 +188,56 @@ fn test_packing() {
 
     const _: () = assert!(!Packed1::PADDED);
 }
+
+#[test]
+#[cfg(feature = "mmap")]
+fn mmap_round_trip() -> Result<()> {
+    use std::fs;
+
+    use crate::buf::{MmapWriter, MMAP_ALIGNMENT};
+    use crate::{swiss, trie};
+
+    let path = std::env::temp_dir().join(std::format!(
+        "musli-zerocopy-mmap-{}.bin",
+        std::process::id()
+    ));
+
+    // SAFETY: The file is private to this test.
+    let mut buf = unsafe { MmapWriter::create(&path)? };
+    let first = buf.store(&Inner {
+        first: 1,
+        second: 2,
+    });
+    let map = swiss::store_map(&mut buf, (0..1000u32).map(|n| (n, u64::from(n) * 2)))?;
+    let values = [
+        (buf.store_unsized("work"), 1u32),
+        (buf.store_unsized("working"), 2u32),
+    ];
+    let trie = trie::store(&mut buf, values)?;
+    let len = buf.len();
+
+    let buf = buf.finish()?;
+    assert_eq!(buf.len(), len);
+    assert_eq!(fs::metadata(&path)?.len(), len as u64);
+    assert!(buf.is_aligned_with(MMAP_ALIGNMENT));
+    assert!(buf.is_compatible_with::<Inner>());
+
+    assert_eq!(
+        buf.load(first)?,
+        &Inner {
+            first: 1,
+            second: 2
+        }
+    );
+
+    let map = buf.bind(map)?;
+    assert_eq!(map.len(), 1000);
+    assert_eq!(map.get(&999u32)?, Some(&1998));
+
+    assert_eq!(trie.get(&buf, "work")?, Some(&[1][..]));
+    assert_eq!(trie.get(&buf, "working")?, Some(&[2][..]));
+
+    drop(buf);
+    fs::remove_file(&path)?;
+    Ok(())
+}
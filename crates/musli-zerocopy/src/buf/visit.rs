@@ -1,7 +1,7 @@
 use crate::buf::{Buf, Load};
 use crate::endian::ByteOrder;
 use crate::error::Error;
-use crate::pointer::{Pointee, Ref, Size};
+use crate::pointer::{OptionRef, Pointee, Ref, Size};
 
 /// Trait used for accessing the value behind a reference when interacting with
 /// higher level containers such as [`phf`] or [`swiss`].
@@ -55,6 +55,25 @@ where
     }
 }
 
+impl<T, E, O> Visit for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    Self: Load,
+    E: ByteOrder,
+    O: Size,
+{
+    type Target = <OptionRef<T, E, O> as Load>::Target;
+
+    #[inline]
+    fn visit<V, U>(&self, buf: &Buf, visitor: V) -> Result<U, Error>
+    where
+        V: FnOnce(&Self::Target) -> U,
+    {
+        let value = buf.load(*self)?;
+        Ok(visitor(value))
+    }
+}
+
 impl Visit for str {
     type Target = str;
 
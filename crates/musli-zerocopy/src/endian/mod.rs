@@ -300,6 +300,11 @@ mod sealed {
 /// [`Little`], and its internals are intentionally hidden. Do not attempt
 /// to use them yourself.
 pub trait ByteOrder: 'static + Sized + self::sealed::Sealed {
+    /// A tag uniquely identifying the byte order, as stored in archive
+    /// headers.
+    #[doc(hidden)]
+    const TAG: u8;
+
     /// Maps the `value` through `map`, unless the current byte order is
     /// [`Native`].
     #[doc(hidden)]
@@ -357,6 +362,8 @@ pub trait ByteOrder: 'static + Sized + self::sealed::Sealed {
 }
 
 impl ByteOrder for Little {
+    const TAG: u8 = 0;
+
     #[cfg(target_endian = "little")]
     #[inline(always)]
     fn try_map<T, F>(value: T, _: F) -> T
@@ -437,6 +444,8 @@ impl ByteOrder for Little {
 }
 
 impl ByteOrder for Big {
+    const TAG: u8 = 1;
+
     #[cfg(target_endian = "big")]
     #[inline(always)]
     fn try_map<T, F>(value: T, _: F) -> T
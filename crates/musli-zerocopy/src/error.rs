@@ -4,6 +4,9 @@ use core::fmt;
 use core::ops::{Range, RangeFrom};
 use core::str::Utf8Error;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 mod sealed {
     pub trait Sealed {}
     impl Sealed for () {}
@@ -101,17 +104,173 @@ impl fmt::Display for Repr {
     }
 }
 
+/// A segment in the path to where an [`Error`] occurred.
+///
+/// See [`Error::path`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PathSegment {
+    /// A value of the type `name` loaded from `offset` in the buffer, such as
+    /// the target of a [`Ref`].
+    ///
+    /// [`Ref`]: crate::pointer::Ref
+    Load {
+        /// The name of the loaded type.
+        name: &'static str,
+        /// The offset the value was loaded from.
+        offset: usize,
+    },
+    /// The field `field` in the type `name`.
+    ///
+    /// Fields in enums are prefixed with the name of their variant, like
+    /// `Variant.field`.
+    Field {
+        /// The name of the type containing the field.
+        name: &'static str,
+        /// The name of the field.
+        field: &'static str,
+    },
+    /// The element at `index` in a slice or an array.
+    Index {
+        /// The index of the element.
+        index: usize,
+    },
+}
+
 /// Müsli's zero copy error type.
+///
+/// With the `alloc` feature enabled, errors carry the [`path`] into the buffer
+/// where they occurred, which is included when they are displayed.
+///
+/// [`path`]: Error::path
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+///
+/// #[derive(Debug, ZeroCopy)]
+/// #[repr(C)]
+/// struct Flags {
+///     enabled: bool,
+/// }
+///
+/// let mut buf = OwnedBuf::new();
+/// let flags = buf.store(&2u8);
+///
+/// let error = buf.load(Ref::<Flags>::new(flags.offset())).unwrap_err();
+///
+/// assert!(error.to_string().starts_with("Illegal bool representation 2 at "));
+/// assert!(error.to_string().ends_with("Flags@0.enabled"));
+/// ```
 #[derive(Debug)]
 #[cfg_attr(test, derive(PartialEq))]
 pub struct Error {
     kind: ErrorKind,
+    #[cfg(feature = "alloc")]
+    path: Vec<PathSegment>,
 }
 
 impl Error {
     #[inline]
     pub(crate) const fn new(kind: ErrorKind) -> Self {
-        Self { kind }
+        Self {
+            kind,
+            #[cfg(feature = "alloc")]
+            path: Vec::new(),
+        }
+    }
+
+    /// The path into the buffer where the error occurred, starting with the
+    /// outermost segment.
+    ///
+    /// The path is only recorded if the `alloc` feature is enabled, otherwise
+    /// it is always empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{OwnedBuf, PathSegment, Ref, ZeroCopy};
+    ///
+    /// #[derive(Debug, ZeroCopy)]
+    /// #[repr(C)]
+    /// struct Flags {
+    ///     enabled: bool,
+    /// }
+    ///
+    /// let mut buf = OwnedBuf::new();
+    /// buf.store(&0u32);
+    /// buf.store(&2u8);
+    ///
+    /// let error = buf.load(Ref::<Flags>::new(4)).unwrap_err();
+    ///
+    /// assert!(matches!(
+    ///     error.path(),
+    ///     [
+    ///         PathSegment::Load { offset: 4, .. },
+    ///         PathSegment::Field { field: "enabled", .. },
+    ///     ]
+    /// ));
+    /// ```
+    #[inline]
+    pub fn path(&self) -> &[PathSegment] {
+        #[cfg(feature = "alloc")]
+        {
+            &self.path
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            &[]
+        }
+    }
+
+    /// Prefix the path of the error with the given segments.
+    #[cold]
+    pub(crate) fn with_prefix<I>(self, prefix: I) -> Self
+    where
+        I: IntoIterator<Item = PathSegment>,
+    {
+        #[cfg(feature = "alloc")]
+        {
+            let mut this = self;
+            this.path.splice(0..0, prefix);
+            this
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = prefix;
+            self
+        }
+    }
+
+    /// Prefix the path of the error with a value of type `T` loaded from
+    /// `offset`.
+    #[cold]
+    pub(crate) fn with_load<T>(self, offset: usize) -> Self
+    where
+        T: ?Sized,
+    {
+        self.with_prefix([PathSegment::Load {
+            name: type_name::<T>(),
+            offset,
+        }])
+    }
+
+    /// Prefix the path of the error with the element at `index`.
+    #[cold]
+    pub(crate) fn with_index(self, index: usize) -> Self {
+        self.with_prefix([PathSegment::Index { index }])
+    }
+
+    #[cold]
+    #[doc(hidden)]
+    pub fn __field<T>(self, field: &'static str) -> Self {
+        self.with_prefix([PathSegment::Field {
+            name: type_name::<T>(),
+            field,
+        }])
     }
 
     #[inline(always)]
@@ -127,7 +286,39 @@ impl Error {
 impl fmt::Display for Error {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.kind.fmt(f)
+        self.kind.fmt(f)?;
+
+        let path = self.path();
+
+        if path.is_empty() {
+            return Ok(());
+        }
+
+        write!(f, " at ")?;
+
+        for (n, segment) in path.iter().enumerate() {
+            match *segment {
+                PathSegment::Load { name, offset } => {
+                    if n > 0 {
+                        write!(f, " -> ")?;
+                    }
+
+                    write!(f, "{name}@{offset}")?;
+                }
+                PathSegment::Field { name, field } => {
+                    if n == 0 {
+                        write!(f, "{name}")?;
+                    }
+
+                    write!(f, ".{field}")?;
+                }
+                PathSegment::Index { index } => {
+                    write!(f, "[{index}]")?;
+                }
+            }
+        }
+
+        Ok(())
     }
 }
 
@@ -217,6 +408,50 @@ pub(crate) enum ErrorKind {
     StackOverflow {
         capacity: usize,
     },
+    NoneRef {
+        name: &'static str,
+    },
+    #[cfg(feature = "alloc")]
+    FstTransitionCycle {
+        from: usize,
+        to: usize,
+    },
+    ArchiveMagicMismatch {
+        expected: [u8; 8],
+        actual: [u8; 8],
+    },
+    ArchiveVersionMismatch {
+        expected: u32,
+        actual: u32,
+    },
+    ArchiveByteOrderMismatch {
+        expected: u8,
+        actual: u8,
+    },
+    ArchiveSizeMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    ArchiveAlignmentMismatch {
+        align: usize,
+    },
+    ArchiveMissingChecksum,
+    ArchiveChecksumMismatch {
+        expected: u64,
+        actual: u64,
+    },
+    ArchiveNotEmpty {
+        len: usize,
+    },
+    #[cfg(feature = "alloc")]
+    FstKeyOutOfOrder {
+        index: usize,
+    },
+    #[cfg(feature = "alloc")]
+    BucketMaskMismatch {
+        bucket_mask: usize,
+        buckets: usize,
+    },
     #[cfg(feature = "alloc")]
     CapacityError,
     #[cfg(feature = "alloc")]
@@ -292,7 +527,78 @@ impl fmt::Display for ErrorKind {
             ErrorKind::StackOverflow { capacity } => {
                 write!(f, "Stack with capacity {capacity} overflowed")
             }
+            ErrorKind::NoneRef { name } => {
+                write!(f, "Tried to load an absent reference to {name}")
+            }
+            #[cfg(feature = "alloc")]
+            ErrorKind::FstTransitionCycle { from, to } => {
+                write!(
+                    f,
+                    "Transition from state at {from} to state at {to} does not lead to an earlier state"
+                )
+            }
             ErrorKind::Utf8Error { error } => error.fmt(f),
+            ErrorKind::ArchiveMagicMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Archive magic {actual:?} does not match expected {expected:?}"
+                )
+            }
+            ErrorKind::ArchiveVersionMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Archive version {actual} does not match expected version {expected}"
+                )
+            }
+            ErrorKind::ArchiveByteOrderMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Archive byte order {} does not match expected byte order {}",
+                    byte_order_name(*actual),
+                    byte_order_name(*expected)
+                )
+            }
+            ErrorKind::ArchiveSizeMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Archive size width of {actual} bytes does not match expected width of {expected} bytes"
+                )
+            }
+            ErrorKind::ArchiveAlignmentMismatch { align } => {
+                write!(f, "Buffer is not aligned by {align} as required by archive")
+            }
+            ErrorKind::ArchiveMissingChecksum => {
+                write!(f, "Archive is missing a checksum")
+            }
+            ErrorKind::ArchiveChecksumMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Archive checksum {actual:016x} does not match expected {expected:016x}"
+                )
+            }
+            ErrorKind::ArchiveNotEmpty { len } => {
+                write!(
+                    f,
+                    "Archive must be stored in an empty buffer, but it has length {len}"
+                )
+            }
+            #[cfg(feature = "alloc")]
+            ErrorKind::FstKeyOutOfOrder { index } => {
+                write!(
+                    f,
+                    "Key at index {index} is not strictly greater than the previous key"
+                )
+            }
+            #[cfg(feature = "alloc")]
+            ErrorKind::BucketMaskMismatch {
+                bucket_mask,
+                buckets,
+            } => {
+                write!(
+                    f,
+                    "Bucket mask {bucket_mask} does not match a table with {buckets} buckets"
+                )
+            }
             #[cfg(feature = "alloc")]
             ErrorKind::CapacityError => {
                 write!(f, "Out of capacity")
@@ -304,3 +610,11 @@ impl fmt::Display for ErrorKind {
         }
     }
 }
+
+fn byte_order_name(tag: u8) -> &'static str {
+    match tag {
+        0 => "little",
+        1 => "big",
+        _ => "unknown",
+    }
+}
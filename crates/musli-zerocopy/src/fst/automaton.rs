@@ -0,0 +1,250 @@
+//! Automata which can drive a search over a finite state transducer.
+//!
+//! See [`FstRef::search`].
+//!
+//! [`FstRef::search`]: crate::fst::FstRef::search
+
+/// A deterministic automaton over bytes, which is used to select the keys
+/// produced by [`FstRef::search`].
+///
+/// The automaton is run in lockstep with the transducer, so any regular
+/// language can be searched for efficiently. A regular expression can for
+/// example drive a search by implementing this trait over its compiled DFA.
+///
+/// [`FstRef::search`]: crate::fst::FstRef::search
+///
+/// # Examples
+///
+/// An automaton which matches keys of an exact length:
+///
+/// ```
+/// use musli_zerocopy::{fst, OwnedBuf};
+/// use musli_zerocopy::fst::automaton::Automaton;
+///
+/// struct Length(usize);
+///
+/// impl Automaton for Length {
+///     type State = usize;
+///
+///     fn start(&self) -> usize {
+///         0
+///     }
+///
+///     fn is_match(&self, state: &usize) -> bool {
+///         *state == self.0
+///     }
+///
+///     fn can_match(&self, state: &usize) -> bool {
+///         *state <= self.0
+///     }
+///
+///     fn accept(&self, state: &usize, _: u8) -> usize {
+///         *state + 1
+///     }
+/// }
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let fst = fst::store(&mut buf, [("a", 1), ("ab", 2), ("b", 3), ("bcd", 4)])?;
+///
+/// let mut stream = fst.search(&buf, Length(1));
+/// let mut values = Vec::new();
+///
+/// while let Some(result) = stream.next() {
+///     values.push(result?.1);
+/// }
+///
+/// assert_eq!(values, [1, 3]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub trait Automaton {
+    /// The state of the automaton.
+    type State;
+
+    /// The start state of the automaton.
+    fn start(&self) -> Self::State;
+
+    /// Test if the given state is a match.
+    fn is_match(&self, state: &Self::State) -> bool;
+
+    /// Test if the given state, or any state reachable from it, can be a
+    /// match.
+    ///
+    /// Returning `false` allows a search to skip everything reachable from
+    /// the current position in the transducer.
+    #[inline]
+    fn can_match(&self, state: &Self::State) -> bool {
+        let _ = state;
+        true
+    }
+
+    /// Transition from `state` over the given byte.
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State;
+}
+
+impl<A> Automaton for &A
+where
+    A: ?Sized + Automaton,
+{
+    type State = A::State;
+
+    #[inline]
+    fn start(&self) -> Self::State {
+        (**self).start()
+    }
+
+    #[inline]
+    fn is_match(&self, state: &Self::State) -> bool {
+        (**self).is_match(state)
+    }
+
+    #[inline]
+    fn can_match(&self, state: &Self::State) -> bool {
+        (**self).can_match(state)
+    }
+
+    #[inline]
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        (**self).accept(state, byte)
+    }
+}
+
+/// An automaton which matches every key.
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct AlwaysMatch;
+
+impl Automaton for AlwaysMatch {
+    type State = ();
+
+    #[inline]
+    fn start(&self) {}
+
+    #[inline]
+    fn is_match(&self, _: &()) -> bool {
+        true
+    }
+
+    #[inline]
+    fn accept(&self, _: &(), _: u8) {}
+}
+
+/// An automaton which matches keys starting with a prefix.
+///
+/// See [`FstRef::prefix`].
+///
+/// [`FstRef::prefix`]: crate::fst::FstRef::prefix
+#[derive(Debug, Clone, Copy)]
+pub struct Prefix<'a> {
+    prefix: &'a [u8],
+}
+
+impl<'a> Prefix<'a> {
+    /// Construct an automaton matching keys which start with `prefix`.
+    #[inline]
+    pub fn new<P>(prefix: &'a P) -> Self
+    where
+        P: ?Sized + AsRef<[u8]>,
+    {
+        Self {
+            prefix: prefix.as_ref(),
+        }
+    }
+}
+
+impl Automaton for Prefix<'_> {
+    // The number of bytes of the prefix matched so far, or `None` if the key
+    // diverged from it.
+    type State = Option<usize>;
+
+    #[inline]
+    fn start(&self) -> Self::State {
+        Some(0)
+    }
+
+    #[inline]
+    fn is_match(&self, state: &Self::State) -> bool {
+        *state == Some(self.prefix.len())
+    }
+
+    #[inline]
+    fn can_match(&self, state: &Self::State) -> bool {
+        state.is_some()
+    }
+
+    #[inline]
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        let n = (*state)?;
+
+        match self.prefix.get(n) {
+            Some(&b) if b == byte => Some(n + 1),
+            Some(..) => None,
+            None => Some(n),
+        }
+    }
+}
+
+/// An automaton which matches keys containing the bytes of a string in order,
+/// but not necessarily contiguously.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{fst, OwnedBuf};
+/// use musli_zerocopy::fst::automaton::Subsequence;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let fst = fst::store(&mut buf, [("crate", 1), ("create", 2), ("react", 3)])?;
+///
+/// let mut stream = fst.search(&buf, Subsequence::new("crt"));
+/// let mut values = Vec::new();
+///
+/// while let Some(result) = stream.next() {
+///     values.push(result?.1);
+/// }
+///
+/// assert_eq!(values, [1, 2]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Subsequence<'a> {
+    string: &'a [u8],
+}
+
+impl<'a> Subsequence<'a> {
+    /// Construct an automaton matching keys which contain the bytes of
+    /// `string` in order.
+    #[inline]
+    pub fn new<S>(string: &'a S) -> Self
+    where
+        S: ?Sized + AsRef<[u8]>,
+    {
+        Self {
+            string: string.as_ref(),
+        }
+    }
+}
+
+impl Automaton for Subsequence<'_> {
+    // The number of bytes of the string matched so far.
+    type State = usize;
+
+    #[inline]
+    fn start(&self) -> Self::State {
+        0
+    }
+
+    #[inline]
+    fn is_match(&self, state: &Self::State) -> bool {
+        *state == self.string.len()
+    }
+
+    #[inline]
+    fn accept(&self, state: &Self::State, byte: u8) -> Self::State {
+        match self.string.get(*state) {
+            Some(&b) if b == byte => *state + 1,
+            _ => *state,
+        }
+    }
+}
@@ -0,0 +1,317 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use crate::buf::StoreBuf;
+use crate::error::ErrorKind;
+use crate::{Endian, Error, Ref};
+
+use super::{FstRef, NodeRef, TransitionRef};
+
+/// Store the given sorted entries in a finite state transducer.
+///
+/// Keys must be provided in strictly increasing byte order, which means that
+/// each key may only occur once.
+///
+/// See [`Builder`] for details.
+///
+/// # Errors
+///
+/// Errors if the keys are not provided in strictly increasing order.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{fst, OwnedBuf};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let fst = fst::store(&mut buf, [("bar", 2), ("baz", 3), ("foo", 1)])?;
+///
+/// assert_eq!(fst.len(), 3);
+/// assert_eq!(fst.get(&buf, "baz")?, Some(3));
+///
+/// assert!(fst::store(&mut buf, [("foo", 1), ("bar", 2)]).is_err());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store<S, I, K>(buf: &mut S, entries: I) -> Result<FstRef<S::ByteOrder, S::Size>, Error>
+where
+    S: ?Sized + StoreBuf,
+    I: IntoIterator<Item = (K, u64)>,
+    K: AsRef<[u8]>,
+{
+    let mut builder = Builder::new();
+
+    for (key, value) in entries {
+        builder.insert(buf, key.as_ref(), value)?;
+    }
+
+    builder.build(buf)
+}
+
+/// An incremental builder of a finite state transducer.
+///
+/// Keys must be inserted in strictly increasing byte order. States are written
+/// to the buffer as soon as no more keys can pass through them, and identical
+/// states are only stored once. So the memory used while building is
+/// proportional to the longest key, plus the registry of stored states which is
+/// used to share common suffixes.
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::{fst, OwnedBuf};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let mut builder = fst::Builder::new();
+///
+/// for (n, key) in ["jumping", "running", "walking"].iter().enumerate() {
+///     builder.insert(&mut buf, key, n as u64)?;
+/// }
+///
+/// let fst = builder.build(&mut buf)?;
+///
+/// assert_eq!(fst.get(&buf, "running")?, Some(1));
+/// assert_eq!(fst.get(&buf, "run")?, None);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub struct Builder {
+    // The states along the path of the last inserted key, which can still be
+    // modified. The first one is the root.
+    unfinished: Vec<Unfinished>,
+    // Every state which has been stored, mapped to its offset.
+    registry: BTreeMap<Node, usize>,
+    // The last inserted key.
+    last: Option<Vec<u8>>,
+    // The number of inserted keys.
+    len: usize,
+}
+
+impl Builder {
+    /// Construct a new empty builder.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            unfinished: Vec::from([Unfinished::default()]),
+            registry: BTreeMap::new(),
+            last: None,
+            len: 0,
+        }
+    }
+
+    /// Insert a key and its associated value.
+    ///
+    /// # Errors
+    ///
+    /// Errors if `key` is not strictly greater than the previously inserted
+    /// key.
+    pub fn insert<S, K>(&mut self, buf: &mut S, key: &K, value: u64) -> Result<(), Error>
+    where
+        S: ?Sized + StoreBuf,
+        K: ?Sized + AsRef<[u8]>,
+    {
+        let key = key.as_ref();
+
+        let prefix_len = match &self.last {
+            Some(last) => {
+                if key <= last.as_slice() {
+                    return Err(Error::new(ErrorKind::FstKeyOutOfOrder { index: self.len }));
+                }
+
+                prefix(last, key)
+            }
+            None => 0,
+        };
+
+        self.compile_from(buf, prefix_len);
+
+        // Move as much of the output as possible towards the root along the
+        // shared prefix, pushing any excess down into the next state.
+        let mut output = value;
+
+        for i in 0..prefix_len {
+            let Some((_, last)) = &mut self.unfinished[i].last else {
+                continue;
+            };
+
+            let common = output.min(*last);
+            let excess = *last - common;
+            *last = common;
+            output -= common;
+
+            if excess > 0 {
+                self.unfinished[i + 1].add_output(excess);
+            }
+        }
+
+        if let Some(&b) = key.get(prefix_len) {
+            self.unfinished[prefix_len].last = Some((b, output));
+
+            for &b in &key[prefix_len + 1..] {
+                self.unfinished.push(Unfinished {
+                    node: Node::default(),
+                    last: Some((b, 0)),
+                });
+            }
+
+            self.unfinished.push(Unfinished {
+                node: Node {
+                    is_final: true,
+                    ..Node::default()
+                },
+                last: None,
+            });
+        } else {
+            // Only the empty key can end on an existing state, since it has to
+            // be the first key inserted.
+            let node = &mut self.unfinished[prefix_len].node;
+            node.is_final = true;
+            node.final_output = output;
+        }
+
+        let last = self.last.get_or_insert_with(Vec::new);
+        last.clear();
+        last.extend_from_slice(key);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Store the remaining states of the transducer and return a reference to
+    /// it.
+    pub fn build<S>(mut self, buf: &mut S) -> Result<FstRef<S::ByteOrder, S::Size>, Error>
+    where
+        S: ?Sized + StoreBuf,
+    {
+        self.compile_from(buf, 0);
+
+        let root = self.unfinished.pop().unwrap_or_default();
+        let root = self.compile(buf, root.node);
+
+        Ok(FstRef {
+            root: Ref::new(root),
+            len: Endian::new(self.len as u64),
+        })
+    }
+
+    /// Store every unfinished state beyond `depth`, since no further keys can
+    /// pass through them.
+    fn compile_from<S>(&mut self, buf: &mut S, depth: usize)
+    where
+        S: ?Sized + StoreBuf,
+    {
+        let mut target = None;
+
+        while self.unfinished.len() > depth + 1 {
+            let Some(mut unfinished) = self.unfinished.pop() else {
+                break;
+            };
+
+            if let Some(target) = target {
+                unfinished.finish(target);
+            }
+
+            target = Some(self.compile(buf, unfinished.node));
+        }
+
+        if let Some(target) = target {
+            self.unfinished[depth].finish(target);
+        }
+    }
+
+    /// Store a state unless an identical one has already been stored,
+    /// returning its offset.
+    fn compile<S>(&mut self, buf: &mut S, node: Node) -> usize
+    where
+        S: ?Sized + StoreBuf,
+    {
+        if let Some(&offset) = self.registry.get(&node) {
+            return offset;
+        }
+
+        let transitions = node
+            .transitions
+            .iter()
+            .map(|t| TransitionRef {
+                output: Endian::new(t.output),
+                target: Ref::new(t.target),
+                byte: t.byte,
+            })
+            .collect::<Vec<_>>();
+
+        let transitions = buf.store_unsized(&transitions[..]);
+
+        let offset = buf
+            .store(&NodeRef {
+                transitions,
+                final_output: Endian::new(node.final_output),
+                is_final: node.is_final,
+            })
+            .offset();
+
+        self.registry.insert(node, offset);
+        offset
+    }
+}
+
+impl Default for Builder {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A state which is still being built.
+#[derive(Default)]
+struct Unfinished {
+    node: Node,
+    // The transition to the next unfinished state, whose target is not yet
+    // known.
+    last: Option<(u8, u64)>,
+}
+
+impl Unfinished {
+    /// Add output to every way of leaving this state.
+    fn add_output(&mut self, output: u64) {
+        if self.node.is_final {
+            self.node.final_output += output;
+        }
+
+        for t in &mut self.node.transitions {
+            t.output += output;
+        }
+
+        if let Some((_, last)) = &mut self.last {
+            *last += output;
+        }
+    }
+
+    /// Finish the last transition with the offset of its target.
+    fn finish(&mut self, target: usize) {
+        if let Some((byte, output)) = self.last.take() {
+            self.node.transitions.push(Transition {
+                byte,
+                output,
+                target,
+            });
+        }
+    }
+}
+
+#[derive(Default, PartialEq, Eq, PartialOrd, Ord)]
+struct Node {
+    is_final: bool,
+    final_output: u64,
+    transitions: Vec<Transition>,
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct Transition {
+    byte: u8,
+    output: u64,
+    target: usize,
+}
+
+/// Calculate the common prefix between two strings.
+fn prefix(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b.iter()).take_while(|(a, b)| a == b).count()
+}
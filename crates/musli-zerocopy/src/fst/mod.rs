@@ -0,0 +1,651 @@
+//! A serialized finite state transducer, mapping byte strings to `u64` values.
+//!
+//! Compared to a [`trie`], a finite state transducer shares both the prefixes
+//! *and* the suffixes of its keys, and stores the values associated with keys
+//! as outputs on the transitions between states. This makes it a much more
+//! compact alternative for large dictionaries, where many keys tend to share
+//! common endings.
+//!
+//! A transducer is constructed from keys in sorted order using a [`Builder`] or
+//! [`store`], and can be queried with [`FstRef::get`]. Entries can be streamed
+//! in sorted order, either completely, within a range, by prefix, or driven by
+//! an [`Automaton`] such as a regular expression.
+//!
+//! [`trie`]: crate::trie
+//! [`Automaton`]: crate::fst::automaton::Automaton
+//!
+//! # Examples
+//!
+//! ```
+//! use musli_zerocopy::{fst, OwnedBuf};
+//!
+//! let mut buf = OwnedBuf::new();
+//!
+//! let fst = fst::store(&mut buf, [("jump", 1), ("jumped", 2), ("jumping", 3), ("run", 4)])?;
+//!
+//! assert_eq!(fst.get(&buf, "jumped")?, Some(2));
+//! assert_eq!(fst.get(&buf, "jumps")?, None);
+//!
+//! let mut stream = fst.prefix(&buf, "jump");
+//! let mut keys = Vec::new();
+//!
+//! while let Some(result) = stream.next() {
+//!     let (key, value) = result?;
+//!     keys.push((String::from_utf8(key.to_vec())?, value));
+//! }
+//!
+//! assert_eq!(keys, [("jump".to_owned(), 1), ("jumped".to_owned(), 2), ("jumping".to_owned(), 3)]);
+//! # Ok::<_, anyhow::Error>(())
+//! ```
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "alloc")]
+pub use self::factory::{store, Builder};
+#[cfg(feature = "alloc")]
+mod factory;
+
+pub mod automaton;
+
+#[cfg(feature = "alloc")]
+use core::mem;
+#[cfg(feature = "alloc")]
+use core::ops::{Bound, RangeBounds};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::endian::Native;
+#[cfg(feature = "alloc")]
+use crate::error::ErrorKind;
+use crate::{Buf, ByteOrder, DefaultSize, Endian, Error, Ref, Size, ZeroCopy};
+
+#[cfg(feature = "alloc")]
+use self::automaton::{AlwaysMatch, Automaton, Prefix};
+
+/// A stored reference to a finite state transducer.
+///
+/// Constructed through [`store`] or [`Builder`].
+#[derive(ZeroCopy)]
+#[zero_copy(crate)]
+#[repr(C)]
+pub struct FstRef<E = Native, O = DefaultSize>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    root: Ref<NodeRef<E, O>, E, O>,
+    len: Endian<u64, E>,
+}
+
+impl<E, O> FstRef<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    /// Get the value associated with the given key.
+    ///
+    /// This only visits the states along the path of `key`, and does not
+    /// allocate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{fst, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let fst = fst::store(&mut buf, [("bar", 2), ("baz", 3), ("foo", 1)])?;
+    ///
+    /// assert_eq!(fst.get(&buf, "bar")?, Some(2));
+    /// assert_eq!(fst.get(&buf, "ba")?, None);
+    /// assert_eq!(fst.get(&buf, "qux")?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get<K>(&self, buf: &Buf, key: &K) -> Result<Option<u64>, Error>
+    where
+        K: ?Sized + AsRef<[u8]>,
+    {
+        let mut node = buf.load(self.root)?;
+        let mut output = 0u64;
+
+        for &b in key.as_ref() {
+            let transitions = buf.load(node.transitions)?;
+
+            let Ok(n) = transitions.binary_search_by(|t| t.byte.cmp(&b)) else {
+                return Ok(None);
+            };
+
+            let t = &transitions[n];
+            output = output.wrapping_add(t.output.to_ne());
+            node = buf.load(t.target)?;
+        }
+
+        if !node.is_final {
+            return Ok(None);
+        }
+
+        Ok(Some(output.wrapping_add(node.final_output.to_ne())))
+    }
+
+    /// Test if the transducer contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{fst, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let fst = fst::store(&mut buf, [("bar", 2), ("foo", 1)])?;
+    ///
+    /// assert!(fst.contains_key(&buf, "bar")?);
+    /// assert!(!fst.contains_key(&buf, "baz")?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn contains_key<K>(&self, buf: &Buf, key: &K) -> Result<bool, Error>
+    where
+        K: ?Sized + AsRef<[u8]>,
+    {
+        Ok(self.get(buf, key)?.is_some())
+    }
+
+    /// Get the number of keys in the transducer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{fst, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let fst = fst::store(&mut buf, [("bar", 2), ("foo", 1)])?;
+    ///
+    /// assert_eq!(fst.len(), 2);
+    /// assert!(!fst.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.to_ne() as usize
+    }
+
+    /// Test if the transducer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len.to_ne() == 0
+    }
+
+    /// Construct a stream over all entries in the transducer, in sorted key
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// The stream errors in case the transducer being streamed is structurally
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{fst, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let fst = fst::store(&mut buf, [("bar", 2), ("baz", 3), ("foo", 1)])?;
+    ///
+    /// let mut stream = fst.stream(&buf);
+    /// let mut entries = Vec::new();
+    ///
+    /// while let Some(result) = stream.next() {
+    ///     let (key, value) = result?;
+    ///     entries.push((key.to_vec(), value));
+    /// }
+    ///
+    /// assert_eq!(entries, [(b"bar".to_vec(), 2), (b"baz".to_vec(), 3), (b"foo".to_vec(), 1)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn stream<'buf>(&self, buf: &'buf Buf) -> Stream<'buf, AlwaysMatch, E, O> {
+        Stream::new(
+            buf,
+            self.root,
+            AlwaysMatch,
+            Bound::Unbounded,
+            Bound::Unbounded,
+        )
+    }
+
+    /// Construct a stream over all entries in the transducer whose key is
+    /// within `range`, in sorted key order.
+    ///
+    /// Only the states which are needed to locate the start of the range are
+    /// visited before the first entry is produced.
+    ///
+    /// # Errors
+    ///
+    /// The stream errors in case the transducer being streamed is structurally
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{fst, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let fst = fst::store(&mut buf, [("a", 1), ("b", 2), ("ba", 3), ("c", 4), ("d", 5)])?;
+    ///
+    /// let mut stream = fst.range(&buf, "b".."c");
+    /// let mut values = Vec::new();
+    ///
+    /// while let Some(result) = stream.next() {
+    ///     values.push(result?.1);
+    /// }
+    ///
+    /// assert_eq!(values, [2, 3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn range<'buf, K, R>(&self, buf: &'buf Buf, range: R) -> Stream<'buf, AlwaysMatch, E, O>
+    where
+        K: ?Sized + AsRef<[u8]>,
+        R: RangeBounds<K>,
+    {
+        Stream::new(
+            buf,
+            self.root,
+            AlwaysMatch,
+            range.start_bound().map(|k| k.as_ref()),
+            range.end_bound().map(|k| k.as_ref().to_vec()),
+        )
+    }
+
+    /// Construct a stream over all entries in the transducer whose key starts
+    /// with `prefix`, in sorted key order.
+    ///
+    /// # Errors
+    ///
+    /// The stream errors in case the transducer being streamed is structurally
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{fst, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let fst = fst::store(&mut buf, [("run", 1), ("work", 2), ("worker", 3), ("working", 4)])?;
+    ///
+    /// let mut stream = fst.prefix(&buf, "worke");
+    /// let mut values = Vec::new();
+    ///
+    /// while let Some(result) = stream.next() {
+    ///     values.push(result?.1);
+    /// }
+    ///
+    /// assert_eq!(values, [3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn prefix<'a, 'buf, K>(
+        &self,
+        buf: &'buf Buf,
+        prefix: &'a K,
+    ) -> Stream<'buf, Prefix<'a>, E, O>
+    where
+        K: ?Sized + AsRef<[u8]>,
+    {
+        let prefix = prefix.as_ref();
+
+        Stream::new(
+            buf,
+            self.root,
+            Prefix::new(prefix),
+            Bound::Included(prefix),
+            Bound::Unbounded,
+        )
+    }
+
+    /// Construct a stream over all entries in the transducer whose key is
+    /// matched by the given [`Automaton`], in sorted key order.
+    ///
+    /// Only states for which [`Automaton::can_match`] holds are visited, so an
+    /// automaton which rejects early avoids walking large parts of the
+    /// transducer.
+    ///
+    /// # Errors
+    ///
+    /// The stream errors in case the transducer being streamed is structurally
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{fst, OwnedBuf};
+    /// use musli_zerocopy::fst::automaton::Subsequence;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let fst = fst::store(&mut buf, [("fast", 1), ("first", 2), ("foo", 3), ("frost", 4)])?;
+    ///
+    /// let mut stream = fst.search(&buf, Subsequence::new("fst"));
+    /// let mut values = Vec::new();
+    ///
+    /// while let Some(result) = stream.next() {
+    ///     values.push(result?.1);
+    /// }
+    ///
+    /// assert_eq!(values, [1, 2, 4]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn search<'buf, A>(&self, buf: &'buf Buf, automaton: A) -> Stream<'buf, A, E, O>
+    where
+        A: Automaton,
+    {
+        Stream::new(
+            buf,
+            self.root,
+            automaton,
+            Bound::Unbounded,
+            Bound::Unbounded,
+        )
+    }
+}
+
+impl<E, O> Clone for FstRef<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E, O> Copy for FstRef<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+}
+
+/// A stream over entries in a [`FstRef`], in sorted key order.
+///
+/// Since keys are reconstructed as the transducer is walked, the stream lends
+/// each key out until the next call to [`Stream::next`]. It can therefore not
+/// implement [`Iterator`].
+///
+/// See [`FstRef::stream()`], [`FstRef::range()`], [`FstRef::prefix()`] and
+/// [`FstRef::search()`].
+#[cfg(feature = "alloc")]
+pub struct Stream<'buf, A, E, O>
+where
+    A: Automaton,
+    E: ByteOrder,
+    O: Size,
+{
+    buf: &'buf Buf,
+    automaton: A,
+    // States being visited, where the last one is the deepest.
+    stack: Vec<Frame<'buf, A::State, E, O>>,
+    // The key corresponding to the deepest state.
+    key: Vec<u8>,
+    // Upper bound of keys to produce.
+    end: Bound<Vec<u8>>,
+    // An error raised while seeking, which is produced by the first call to
+    // `next`.
+    error: Option<Error>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'buf, A, E, O> Stream<'buf, A, E, O>
+where
+    A: Automaton,
+    E: ByteOrder,
+    O: Size,
+{
+    fn new(
+        buf: &'buf Buf,
+        root: Ref<NodeRef<E, O>, E, O>,
+        automaton: A,
+        start: Bound<&[u8]>,
+        end: Bound<Vec<u8>>,
+    ) -> Self {
+        let mut this = Self {
+            buf,
+            automaton,
+            stack: Vec::new(),
+            key: Vec::new(),
+            end,
+            error: None,
+        };
+
+        if let Err(error) = this.seek(root, start) {
+            this.stack.clear();
+            this.error = Some(error);
+        }
+
+        this
+    }
+
+    /// Advance the stream, returning the next key and its associated value.
+    ///
+    /// This returns `None` once the stream is exhausted. If an error is
+    /// encountered, it is returned once after which the stream is exhausted.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<Result<(&[u8], u64), Error>> {
+        match self.poll() {
+            Ok(true) => {
+                let frame = self.stack.last()?;
+                let output = frame.output.wrapping_add(frame.final_output);
+                Some(Ok((&self.key, output)))
+            }
+            Ok(false) => None,
+            Err(error) => {
+                self.stack.clear();
+                Some(Err(error))
+            }
+        }
+    }
+
+    /// Position the stream on the first key which is within `start`.
+    fn seek(&mut self, root: Ref<NodeRef<E, O>, E, O>, start: Bound<&[u8]>) -> Result<(), Error> {
+        let state = self.automaton.start();
+
+        if !self.automaton.can_match(&state) {
+            return Ok(());
+        }
+
+        self.stack.push(Frame::new(self.buf, root, 0, state)?);
+
+        let (start, inclusive) = match start {
+            Bound::Included(start) => (start, true),
+            Bound::Excluded(start) => (start, false),
+            Bound::Unbounded => return Ok(()),
+        };
+
+        for &b in start {
+            let Some(frame) = self.stack.last_mut() else {
+                return Ok(());
+            };
+
+            // Every key through this state is smaller than `start`, and every
+            // transition before `n` leads to smaller keys as well.
+            frame.pending = false;
+            let n = frame.transitions.partition_point(|t| t.byte < b);
+            frame.next = n;
+
+            match frame.transitions.get(n) {
+                Some(t) if t.byte == b => {
+                    frame.next = n + 1;
+
+                    if !self.push(t)? {
+                        return Ok(());
+                    }
+                }
+                _ => return Ok(()),
+            }
+        }
+
+        if let Some(frame) = self.stack.last_mut() {
+            frame.pending = inclusive;
+        }
+
+        Ok(())
+    }
+
+    /// Advance to the next matching key, returning `true` if one was found.
+    fn poll(&mut self) -> Result<bool, Error> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+
+        loop {
+            let Some(frame) = self.stack.last_mut() else {
+                return Ok(false);
+            };
+
+            if mem::take(&mut frame.pending)
+                && frame.is_final
+                && self.automaton.is_match(&frame.state)
+            {
+                return Ok(true);
+            }
+
+            let Some(t) = frame.transitions.get(frame.next) else {
+                self.stack.pop();
+                self.key.pop();
+                continue;
+            };
+
+            frame.next += 1;
+            self.push(t)?;
+        }
+    }
+
+    /// Follow the given transition from the deepest state, returning `false`
+    /// if it was not followed.
+    fn push(&mut self, t: &'buf TransitionRef<E, O>) -> Result<bool, Error> {
+        let Some(frame) = self.stack.last() else {
+            return Ok(false);
+        };
+
+        let state = self.automaton.accept(&frame.state, t.byte);
+
+        if !self.automaton.can_match(&state) {
+            return Ok(false);
+        }
+
+        let from = frame.offset;
+        let to = t.target.offset();
+
+        // Nodes are always stored after the nodes they have transitions to,
+        // which guarantees that walking the transducer terminates.
+        if to >= from {
+            return Err(Error::new(ErrorKind::FstTransitionCycle { from, to }));
+        }
+
+        let output = frame.output.wrapping_add(t.output.to_ne());
+        self.key.push(t.byte);
+
+        let beyond = match &self.end {
+            Bound::Included(end) => self.key.as_slice() > end.as_slice(),
+            Bound::Excluded(end) => self.key.as_slice() >= end.as_slice(),
+            Bound::Unbounded => false,
+        };
+
+        // Every remaining key is beyond the end of the range, since they are
+        // produced in order.
+        if beyond {
+            self.stack.clear();
+            self.key.clear();
+            return Ok(false);
+        }
+
+        let frame = Frame::new(self.buf, t.target, output, state)?;
+        self.stack.push(frame);
+        Ok(true)
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct Frame<'buf, S, E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    // Offset of the node.
+    offset: usize,
+    // Transitions out of the node.
+    transitions: &'buf [TransitionRef<E, O>],
+    // Whether the node is final.
+    is_final: bool,
+    // The final output of the node.
+    final_output: u64,
+    // The output accumulated to reach the node.
+    output: u64,
+    // Index of the next transition to follow.
+    next: usize,
+    // Whether the node itself has yet to be considered as a match.
+    pending: bool,
+    // State of the automaton after reaching the node.
+    state: S,
+}
+
+#[cfg(feature = "alloc")]
+impl<'buf, S, E, O> Frame<'buf, S, E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    fn new(
+        buf: &'buf Buf,
+        node: Ref<NodeRef<E, O>, E, O>,
+        output: u64,
+        state: S,
+    ) -> Result<Self, Error> {
+        let offset = node.offset();
+        let node = buf.load(node)?;
+
+        Ok(Self {
+            offset,
+            transitions: buf.load(node.transitions)?,
+            is_final: node.is_final,
+            final_output: node.final_output.to_ne(),
+            output,
+            next: 0,
+            pending: true,
+            state,
+        })
+    }
+}
+
+#[derive(ZeroCopy)]
+#[zero_copy(crate)]
+#[repr(C)]
+struct NodeRef<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    transitions: Ref<[TransitionRef<E, O>], E, O>,
+    final_output: Endian<u64, E>,
+    is_final: bool,
+}
+
+#[derive(ZeroCopy)]
+#[zero_copy(crate)]
+#[repr(C)]
+struct TransitionRef<E, O>
+where
+    E: ByteOrder,
+    O: Size,
+{
+    output: Endian<u64, E>,
+    target: Ref<NodeRef<E, O>, E, O>,
+    byte: u8,
+}
@@ -0,0 +1,189 @@
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+
+use anyhow::Result;
+
+use crate::endian::Native;
+use crate::{trie, DefaultSize, OwnedBuf};
+
+use super::automaton::{Automaton, Subsequence};
+use super::{store, Builder, Stream};
+
+fn collect<A>(mut stream: Stream<'_, A, Native, DefaultSize>) -> Result<Vec<(Vec<u8>, u64)>>
+where
+    A: Automaton,
+{
+    let mut out = Vec::new();
+
+    while let Some(result) = stream.next() {
+        let (key, value) = result?;
+        out.push((key.to_vec(), value));
+    }
+
+    Ok(out)
+}
+
+fn words() -> BTreeMap<Vec<u8>, u64> {
+    let stems = ["", "re", "un", "pre", "over"];
+    let roots = ["walk", "talk", "jump", "run", "play", "work", "read"];
+    let suffixes = ["", "s", "ed", "ing", "er", "ers"];
+
+    let mut words = BTreeMap::new();
+    let mut n = 0u64;
+
+    for stem in stems {
+        for root in roots {
+            for suffix in suffixes {
+                let word = [stem, root, suffix].concat().into_bytes();
+                // Values which are not monotonic in key order exercise pushing
+                // outputs around while building.
+                words.insert(word, (n * 7919) % 1000);
+                n += 1;
+            }
+        }
+    }
+
+    words
+}
+
+#[test]
+fn fst_matches_btree_map() -> Result<()> {
+    let expected = words();
+
+    let mut buf = OwnedBuf::new();
+    let fst = store(&mut buf, expected.iter().map(|(k, v)| (k, *v)))?;
+
+    assert_eq!(fst.len(), expected.len());
+
+    for (key, value) in &expected {
+        assert_eq!(fst.get(&buf, key)?, Some(*value));
+    }
+
+    for key in ["", "w", "walke", "runs ", "zzz", "overwalkingg"] {
+        assert_eq!(fst.get(&buf, key)?, expected.get(key.as_bytes()).copied());
+    }
+
+    let all = collect(fst.stream(&buf))?;
+    assert_eq!(all, expected.clone().into_iter().collect::<Vec<_>>());
+
+    let ranges: [(&str, &str); 4] = [("re", "run"), ("a", "b"), ("walk", "walked"), ("z", "zz")];
+
+    for (start, end) in ranges {
+        let (start_key, end_key) = (start.as_bytes().to_vec(), end.as_bytes().to_vec());
+
+        let actual = collect(fst.range(&buf, start..end))?;
+        let expected_range = expected
+            .range(start_key.clone()..end_key.clone())
+            .map(|(k, v)| (k.clone(), *v))
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected_range, "{start}..{end}");
+
+        let actual = collect(fst.range(&buf, start..=end))?;
+        let expected_range = expected
+            .range(start_key..=end_key)
+            .map(|(k, v)| (k.clone(), *v))
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected_range, "{start}..={end}");
+    }
+
+    for prefix in ["", "re", "rew", "overplay", "x"] {
+        let actual = collect(fst.prefix(&buf, prefix))?;
+        let expected = expected
+            .iter()
+            .filter(|(k, _)| k.starts_with(prefix.as_bytes()))
+            .map(|(k, v)| (k.clone(), *v))
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected, "prefix {prefix}");
+    }
+
+    let actual = collect(fst.search(&buf, Subsequence::new("wkd")))?;
+    let expected = expected
+        .iter()
+        .filter(|(k, _)| is_subsequence(b"wkd", k))
+        .map(|(k, v)| (k.clone(), *v))
+        .collect::<Vec<_>>();
+    assert!(!expected.is_empty());
+    assert_eq!(actual, expected);
+    Ok(())
+}
+
+#[test]
+fn fst_is_smaller_than_trie() -> Result<()> {
+    let words = words();
+
+    let mut fst_buf = OwnedBuf::new();
+    store(&mut fst_buf, words.iter().map(|(k, v)| (k, *v)))?;
+
+    let mut trie_buf = OwnedBuf::new();
+    let mut entries = Vec::new();
+
+    for (key, value) in &words {
+        entries.push((trie_buf.store_unsized(&key[..]), *value));
+    }
+
+    trie::store(&mut trie_buf, entries)?;
+
+    assert!(
+        fst_buf.len() < trie_buf.len(),
+        "fst: {}, trie: {}",
+        fst_buf.len(),
+        trie_buf.len()
+    );
+    Ok(())
+}
+
+#[test]
+fn fst_empty_key_and_empty() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+
+    let fst = store::<_, _, &str>(&mut buf, [])?;
+    assert!(fst.is_empty());
+    assert_eq!(fst.get(&buf, "")?, None);
+    assert!(collect(fst.stream(&buf))?.is_empty());
+
+    let fst = store(&mut buf, [("", 5), ("a", 3), ("ab", 7)])?;
+    assert_eq!(fst.get(&buf, "")?, Some(5));
+    assert_eq!(fst.get(&buf, "a")?, Some(3));
+    assert_eq!(fst.get(&buf, "ab")?, Some(7));
+
+    let all = collect(fst.stream(&buf))?;
+    assert_eq!(
+        all,
+        [(b"".to_vec(), 5), (b"a".to_vec(), 3), (b"ab".to_vec(), 7)]
+    );
+
+    let after = collect(fst.range::<&str, _>(
+        &buf,
+        (core::ops::Bound::Excluded(""), core::ops::Bound::Unbounded),
+    ))?;
+    assert_eq!(after, [(b"a".to_vec(), 3), (b"ab".to_vec(), 7)]);
+    Ok(())
+}
+
+#[test]
+fn fst_out_of_order() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+    let mut builder = Builder::new();
+
+    builder.insert(&mut buf, "b", 1)?;
+    assert!(builder.insert(&mut buf, "a", 2).is_err());
+    assert!(builder.insert(&mut buf, "b", 2).is_err());
+    builder.insert(&mut buf, "c", 3)?;
+
+    let fst = builder.build(&mut buf)?;
+    assert_eq!(fst.len(), 2);
+    assert_eq!(fst.get(&buf, "c")?, Some(3));
+    Ok(())
+}
+
+fn is_subsequence(needle: &[u8], haystack: &[u8]) -> bool {
+    let mut needle = needle.iter().peekable();
+
+    for b in haystack {
+        if needle.peek() == Some(&b) {
+            needle.next();
+        }
+    }
+
+    needle.peek().is_none()
+}
@@ -44,8 +44,12 @@
 //!   functions.
 //! * [`swiss`] is a port of the [`hashbrown` crate] which is a Google
 //!   SwissTable implementation.
+//! * [`sorted`] provides maps and sets stored in sorted order, which support
+//!   range queries and ordered lookups.
 //! * [`trie`] is an implementation of a prefix-trie, which supports efficient
 //!   multi-value byte-prefixed lookups.
+//! * [`fst`] is a finite state transducer, which compactly maps large sorted
+//!   dictionaries of strings to values.
 //!
 //! Finally if you're interested in the performance of `musli-zerocopy` you
 //! should go to [`benchmarks`]. I will be extending this suite with more
@@ -217,8 +221,10 @@
 //!   follow this alignment. Dynamically this can be achieved by loading the
 //!   buffer using [`aligned_buf(bytes, align)`]. Other tricks include embedding
 //!   a static buffer inside of an aligned newtype which we'll showcase below.
-//!   Networked applications might simply agree to use a particular alignment up
-//!   front. This alignment has to be compatible with the types being coerced.
+//!   Large archives on disk can be memory mapped using [`MmapBuf`] from the
+//!   `mmap` feature, which is always page aligned. Networked applications
+//!   might simply agree to use a particular alignment up front. This
+//!   alignment has to be compatible with the types being coerced.
 //! * The *endianness* of the machine which produced the buffer. Any numerical
 //!   elements will in native endian ordering, so they would have to be adjusted
 //!   on the read side if it differ.
@@ -229,6 +235,9 @@
 //! * The offset at where the [`ZeroCopy`] structure is read. To read a
 //!   structure we combine a pointer and a type into a [`Ref`] instance.
 //!
+//! All of this can be recorded alongside the data and validated before it's
+//! read by storing the buffer as an [`archive`].
+//!
 //! If the goal is to both produce and read the buffer on the same system
 //! certain assumptions can be made. And if those assumptions turn out to be
 //! wrong the worst outcome will only ever be an error as long as you're using
@@ -507,9 +516,12 @@
 //! <br>
 //!
 //! [`aligned_buf(bytes, align)`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/pointer/trait.Size.html
+//! [`archive`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/archive/index.html
 //! [`benchmarks`]: https://udoprog.github.io/musli/benchmarks/
 //! [`ByteOrder`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/trait.ByteOrder.html
+//! [`fst`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/fst/index.html
 //! [`hashbrown` crate]: https://docs.rs/phf
+//! [`MmapBuf`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/buf/struct.MmapBuf.html
 //! [`OwnedBuf::with_size`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/buf/struct.OwnedBuf.html#method.with_size
 //! [`OwnedBuf`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/buf/struct.OwnedBuf.html
 //! [`phf` crate]: https://docs.rs/phf
@@ -519,6 +531,7 @@
 //! [`Ref<T, E, O>`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/pointer/struct.Ref.html
 //! [`requested()`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/struct.OwnedBuf.html#method.requested
 //! [`Size`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/pointer/trait.Size.html
+//! [`sorted`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/sorted/index.html
 //! [`swiss`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/swiss/index.html
 //! [`trie`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/trie/index.html
 //! [`with_byte_order::<E>()`]: https://docs.rs/musli-zerocopy/latest/musli_zerocopy/buf/struct.OwnedBuf.html#method.with_byte_order
@@ -553,7 +566,7 @@ pub mod slice;
 pub mod trie;
 
 #[doc(inline)]
-pub use self::error::Error;
+pub use self::error::{Error, PathSegment};
 mod error;
 
 /// `Result` alias provided for convenience.
@@ -563,9 +576,19 @@ pub type Result<T, E = Error> = core::result::Result<T, E>;
 pub use self::traits::{UnsizedZeroCopy, ZeroCopy, ZeroSized};
 mod traits;
 
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use self::archived::{ArchivedOption, ToArchived};
+#[cfg(feature = "alloc")]
+mod archived;
+
 pub(crate) mod sip;
 
+pub mod archive;
+
+pub mod fst;
 pub mod phf;
+pub mod sorted;
 pub mod swiss;
 
 #[doc(inline)]
@@ -905,9 +928,124 @@ pub use musli_zerocopy_macros::Visit;
 /// assert_eq!(v2, Enum::C);
 /// assert_eq!(v3, Enum::D);
 /// ```
+///
+/// <br>
+///
+/// ### `#[zero_copy(visit_refs = <path>)]`
+///
+/// Specifies a function which visits the references contained in the type,
+/// instead of visiting the references in each field. This is used by
+/// [`Buf::validate_deep`] for types which store references in some other way
+/// than as a [`Ref`].
+///
+/// The function is called with a reference to the value and the
+/// [`RefVisitor`] being used.
+///
+/// [`Buf::validate_deep`]: crate::buf::Buf::validate_deep
+/// [`RefVisitor`]: crate::buf::RefVisitor
+///
+/// ```
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+/// use musli_zerocopy::buf::RefVisitor;
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// #[zero_copy(visit_refs = visit_child)]
+/// struct Node {
+///     child: u32,
+/// }
+///
+/// fn visit_child(node: &Node, visitor: &mut RefVisitor<'_>) {
+///     visitor.visit_ref(Ref::<Node>::new(node.child));
+/// }
+///
+/// let mut buf = OwnedBuf::new();
+/// let node = buf.store(&Node { child: 0 });
+/// assert!(buf.validate_deep(node).is_ok());
+///
+/// let broken = buf.store(&Node { child: 1 });
+/// assert_eq!(buf.validate_deep(broken).unwrap_err().len(), 1);
+/// ```
 #[doc(inline)]
 pub use musli_zerocopy_macros::ZeroCopy;
 
+/// Derive macro to implement [`ToArchived`] for an ordinary Rust struct.
+///
+/// This generates the [`ZeroCopy`] counterpart of the struct, which is named
+/// `Archived` followed by the name of the struct unless specified otherwise.
+/// Each field in the generated struct is the [`ToArchived::Archived`]
+/// counterpart of the original field, so fields such as `String`, `Vec<T>`,
+/// `Option<T>` and `HashMap<K, V>` are replaced with references into the
+/// buffer.
+///
+/// Storing a value with [`ToArchived::store`] writes the whole object graph
+/// into the buffer, and returns a reference to the archived root.
+///
+/// [`ToArchived`]: trait@crate::ToArchived
+/// [`ToArchived::Archived`]: crate::ToArchived::Archived
+/// [`ToArchived::store`]: crate::ToArchived::store
+/// [`ZeroCopy`]: crate::traits::ZeroCopy
+///
+/// <br>
+///
+/// # Attributes
+///
+/// * `#[to_archived(name = Ident)]` changes the name of the generated struct.
+/// * `#[to_archived(crate = path)]` changes the path to the `musli_zerocopy`
+///   crate.
+///
+/// <br>
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use musli_zerocopy::{OwnedBuf, ToArchived};
+///
+/// #[derive(ToArchived)]
+/// struct Person {
+///     name: String,
+///     age: u8,
+///     nickname: Option<String>,
+///     aliases: Vec<String>,
+///     scores: HashMap<String, u32>,
+/// }
+///
+/// #[derive(ToArchived)]
+/// #[to_archived(name = ArchivedTeam)]
+/// struct Team {
+///     members: Vec<Person>,
+/// }
+///
+/// let team = Team {
+///     members: vec![Person {
+///         name: String::from("John-John"),
+///         age: 35,
+///         nickname: None,
+///         aliases: vec![String::from("udoprog")],
+///         scores: HashMap::from([(String::from("chess"), 1200)]),
+///     }],
+/// };
+///
+/// let mut buf = OwnedBuf::new();
+/// let team = team.store(&mut buf)?;
+///
+/// let team: &ArchivedTeam = buf.load(team)?;
+/// let members: &[ArchivedPerson] = buf.load(team.members)?;
+///
+/// let person = &members[0];
+/// assert_eq!(buf.load(person.name)?, "John-John");
+/// assert_eq!(person.age, 35);
+/// assert!(person.nickname.is_none());
+/// assert_eq!(buf.load(buf.load(person.aliases)?[0])?, "udoprog");
+/// assert_eq!(person.scores.get(&buf, "chess")?, Some(&1200));
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use musli_zerocopy_macros::ToArchived;
+
 #[cfg(test)]
 mod tests;
 
@@ -920,11 +1058,18 @@ pub mod __private {
     }
 
     pub mod mem {
-        pub use ::core::mem::{align_of, size_of};
+        pub use ::core::mem::{align_of, size_of, ManuallyDrop};
+    }
+
+    pub mod ptr {
+        pub use ::core::ptr::{addr_of, read_unaligned};
     }
 
-    pub use crate::buf::{Buf, Visit};
-    pub use crate::endian::ByteOrder;
+    #[cfg(feature = "alloc")]
+    pub use crate::archived::ToArchived;
+    pub use crate::buf::{Buf, StoreBuf, Visit};
+    pub use crate::endian::{ByteOrder, Native};
+    pub use crate::pointer::DefaultSize;
     pub use crate::traits::{ZeroCopy, ZeroSized};
 
     #[inline(always)]
@@ -3,8 +3,9 @@ use core::mem::{size_of, ManuallyDrop};
 use core::ptr::NonNull;
 use core::slice;
 
-use crate::buf;
-use crate::pointer::{Pointee, Size};
+use crate::buf::{self, RefVisitor};
+use crate::endian::ByteOrder;
+use crate::pointer::{Pointee, Ref, Size};
 use crate::traits::ZeroCopy;
 
 /// A value which might or might not have been initialized.
@@ -125,4 +126,21 @@ where
     {
         T::try_from_metadata(metadata)
     }
+
+    #[inline]
+    fn stored_zero<O>() -> Self::Stored<O>
+    where
+        O: Size,
+    {
+        T::stored_zero()
+    }
+
+    #[inline]
+    fn visit_ref<E, O>(_: Ref<Self, E, O>, _: &mut RefVisitor<'_>)
+    where
+        E: ByteOrder,
+        O: Size,
+    {
+        // Uninitialized values can't be validated.
+    }
 }
@@ -14,6 +14,7 @@
 
 use core::borrow::Borrow;
 use core::hash::Hash;
+use core::slice;
 
 use crate::buf::{Bindable, Buf, Visit};
 use crate::endian::{ByteOrder, Native};
@@ -159,6 +160,120 @@ where
             Ok(None)
         }
     }
+
+    /// Get the number of entries in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the map is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, Vec::<(u32, u32)>::new())?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert!(map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the entries of the map in storage order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let mut entries = map.iter().collect::<Vec<_>>();
+    /// entries.sort();
+    /// assert_eq!(entries, [(&1, &2), (&2, &3)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, K, V> {
+        Iter {
+            iter: self.entries.iter(),
+        }
+    }
+
+    /// Iterate over the keys of the map in storage order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let mut keys = map.keys().collect::<Vec<_>>();
+    /// keys.sort();
+    /// assert_eq!(keys, [&1, &2]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> Keys<'a, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Iterate over the values of the map in storage order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let mut values = map.values().collect::<Vec<_>>();
+    /// values.sort();
+    /// assert_eq!(values, [&2, &3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn values(&self) -> Values<'a, K, V> {
+        Values { iter: self.iter() }
+    }
 }
 
 /// Bind a [`MapRef`] into a [`Map`].
@@ -367,6 +482,124 @@ where
             Ok(None)
         }
     }
+
+    /// Get the number of entries in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert!(!map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the map is empty.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, Vec::<(u32, u32)>::new())?;
+    ///
+    /// assert!(map.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the entries of the map in storage order.
+    ///
+    /// Each entry is validated as it is loaded from the buffer, so iteration
+    /// stops producing values after the first error.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// let mut entries = map.iter(&buf).collect::<Result<Vec<_>, _>>()?;
+    /// entries.sort();
+    /// assert_eq!(entries, [(&1, &2), (&2, &3)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter<'buf>(&self, buf: &'buf Buf) -> RefIter<'buf, K, V, E, O> {
+        RefIter {
+            entries: self.entries,
+            buf,
+            index: 0,
+        }
+    }
+
+    /// Iterate over the keys of the map in storage order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// let mut keys = map.keys(&buf).collect::<Result<Vec<_>, _>>()?;
+    /// keys.sort();
+    /// assert_eq!(keys, [&1, &2]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn keys<'buf>(&self, buf: &'buf Buf) -> RefKeys<'buf, K, V, E, O> {
+        RefKeys {
+            iter: self.iter(buf),
+        }
+    }
+
+    /// Iterate over the values of the map in storage order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = phf::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// let mut values = map.values(&buf).collect::<Result<Vec<_>, _>>()?;
+    /// values.sort();
+    /// assert_eq!(values, [&2, &3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn values<'buf>(&self, buf: &'buf Buf) -> RefValues<'buf, K, V, E, O> {
+        RefValues {
+            iter: self.iter(buf),
+        }
+    }
 }
 
 impl<K, V, E, O> Clone for MapRef<K, V, E, O>
@@ -389,3 +622,187 @@ where
     O: Size,
 {
 }
+
+/// An iterator over the entries of a [`Map`].
+///
+/// See [`Map::iter`].
+pub struct Iter<'a, K, V> {
+    iter: slice::Iter<'a, Entry<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let e = self.iter.next()?;
+        Some((&e.key, &e.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+/// An iterator over the keys of a [`Map`].
+///
+/// See [`Map::keys`].
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.0)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+/// An iterator over the values of a [`Map`].
+///
+/// See [`Map::values`].
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+/// An iterator over the entries of a [`MapRef`] which validates each entry as
+/// it is loaded.
+///
+/// See [`MapRef::iter`].
+pub struct RefIter<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    entries: Ref<[Entry<K, V>], E, O>,
+    buf: &'buf Buf,
+    index: usize,
+}
+
+impl<'buf, K, V, E, O> Iterator for RefIter<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<(&'buf K, &'buf V), Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.get(self.index)?;
+
+        match self.buf.load(entry) {
+            Ok(e) => {
+                self.index += 1;
+                Some(Ok((&e.key, &e.value)))
+            }
+            Err(error) => {
+                self.index = self.entries.len();
+                Some(Err(error))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.entries.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+/// An iterator over the keys of a [`MapRef`].
+///
+/// See [`MapRef::keys`].
+pub struct RefKeys<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    iter: RefIter<'buf, K, V, E, O>,
+}
+
+impl<'buf, K, V, E, O> Iterator for RefKeys<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<&'buf K, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.map(|(k, _)| k))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// An iterator over the values of a [`MapRef`].
+///
+/// See [`MapRef::values`].
+pub struct RefValues<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    iter: RefIter<'buf, K, V, E, O>,
+}
+
+impl<'buf, K, V, E, O> Iterator for RefValues<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<&'buf V, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.map(|(_, v)| v))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
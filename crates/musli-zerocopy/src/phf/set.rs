@@ -14,6 +14,7 @@
 
 use core::borrow::Borrow;
 use core::hash::Hash;
+use core::slice;
 
 use crate::buf::{Bindable, Buf, Visit};
 use crate::endian::{ByteOrder, Native};
@@ -91,6 +92,59 @@ where
 
         key.visit(self.buf, |b| e.visit(self.buf, |a| a.borrow() == b))?
     }
+
+    /// Get the number of values in the set.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = phf::store_set(&mut buf, [1, 3])?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// assert!(!set.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the values of the set in storage order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = phf::store_set(&mut buf, [1, 3])?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// let mut values = set.iter().collect::<Vec<_>>();
+    /// values.sort();
+    /// assert_eq!(values, [&1, &3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            iter: self.entries.iter(),
+        }
+    }
 }
 
 /// Bind a [`SetRef`] into a [`Set`].
@@ -231,4 +285,129 @@ where
         let e = buf.load(e)?;
         key.visit(buf, |b| e.visit(buf, |a| a.borrow() == b))?
     }
+
+    /// Get the number of values in the set.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = phf::store_set(&mut buf, [1, 3])?;
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// assert!(!set.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the values of the set in storage order.
+    ///
+    /// Each value is validated as it is loaded from the buffer, so iteration
+    /// stops producing values after the first error.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::phf;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = phf::store_set(&mut buf, [1, 3])?;
+    ///
+    /// let mut values = set.iter(&buf).collect::<Result<Vec<_>, _>>()?;
+    /// values.sort();
+    /// assert_eq!(values, [&1, &3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter<'buf>(&self, buf: &'buf Buf) -> RefIter<'buf, T, E, O> {
+        RefIter {
+            entries: self.entries,
+            buf,
+            index: 0,
+        }
+    }
+}
+
+/// An iterator over the values of a [`Set`].
+///
+/// See [`Set::iter`].
+pub struct Iter<'a, T> {
+    iter: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// An iterator over the values of a [`SetRef`] which validates each value as
+/// it is loaded.
+///
+/// See [`SetRef::iter`].
+pub struct RefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    entries: Ref<[T], E, O>,
+    buf: &'buf Buf,
+    index: usize,
+}
+
+impl<'buf, T, E, O> Iterator for RefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<&'buf T, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.entries.get(self.index)?;
+
+        match self.buf.load(entry) {
+            Ok(value) => {
+                self.index += 1;
+                Some(Ok(value))
+            }
+            Err(error) => {
+                self.index = self.entries.len();
+                Some(Err(error))
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.entries.len() - self.index;
+        (remaining, Some(remaining))
+    }
 }
@@ -12,6 +12,8 @@
 //!   pointer and a size to a typed reference where `T` implements
 //!   [`UnsizedZeroCopy`]. It loads into `&T` and is implemented by types such
 //!   as `str` and `[u8]`.`
+//! * [`OptionRef<T>`] is the optional counterpart to [`Ref<T>`] with the same
+//!   layout, which can represent an absent reference.
 //!
 //! [`ZeroCopy`]: crate::traits::ZeroCopy
 //! [`UnsizedZeroCopy`]: crate::traits::UnsizedZeroCopy
@@ -26,6 +28,10 @@ mod size;
 pub use self::r#ref::Ref;
 mod r#ref;
 
+#[doc(inline)]
+pub use self::option_ref::OptionRef;
+mod option_ref;
+
 #[doc(inline)]
 pub use self::pointee::Pointee;
 mod pointee;
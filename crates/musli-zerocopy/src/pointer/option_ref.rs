@@ -0,0 +1,350 @@
+use core::fmt;
+use core::hash::{Hash, Hasher};
+
+use crate::buf::{Bindable, Buf, Load, RefVisitor};
+use crate::endian::{ByteOrder, Native};
+use crate::error::Error;
+use crate::pointer::{DefaultSize, Pointee, Ref, Size};
+use crate::ZeroCopy;
+
+/// A stored reference to a type `T` which might be absent.
+///
+/// This is the optional counterpart to [`Ref<T>`] and has the same size and
+/// layout, so it can be used for optional children of [`ZeroCopy`] types
+/// without sentinel values or separate flags. An absent reference is stored
+/// with an offset where every bit is set, which means that a value located at
+/// the maximum offset representable by `O` can't be referenced.
+///
+/// Loading an absent reference through [`Buf::load`] results in an error,
+/// while [`Buf::bind`] produces an [`Option`].
+///
+/// # Examples
+///
+/// ```
+/// use std::mem::size_of;
+///
+/// use musli_zerocopy::{OwnedBuf, Ref, ZeroCopy};
+/// use musli_zerocopy::pointer::OptionRef;
+///
+/// #[derive(ZeroCopy)]
+/// #[repr(C)]
+/// struct Node {
+///     value: u32,
+///     next: OptionRef<Node>,
+/// }
+///
+/// assert_eq!(size_of::<OptionRef<Node>>(), size_of::<Ref<Node>>());
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let first = buf.store(&Node { value: 1, next: OptionRef::none() });
+/// let second = buf.store(&Node { value: 2, next: OptionRef::some(first) });
+///
+/// let second = buf.load(second)?;
+/// assert_eq!(second.value, 2);
+///
+/// let first = buf.bind(second.next)?.expect("missing next node");
+/// assert_eq!(first.value, 1);
+/// assert!(first.next.is_none());
+/// assert!(buf.bind(first.next)?.is_none());
+/// assert!(buf.load(first.next).is_err());
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(ZeroCopy)]
+#[repr(transparent)]
+#[zero_copy(crate, swap_bytes_self, visit_refs = visit_option_ref)]
+pub struct OptionRef<T, E = Native, O = DefaultSize>
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    inner: Ref<T, E, O>,
+}
+
+#[inline]
+fn visit_option_ref<T, E, O>(this: &OptionRef<T, E, O>, visitor: &mut RefVisitor<'_>)
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    if let Some(reference) = this.get() {
+        visitor.visit_ref(reference);
+    }
+}
+
+impl<T, E, O> OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    /// Construct an absent reference.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::pointer::OptionRef;
+    ///
+    /// let reference = OptionRef::<u32>::none();
+    /// assert!(reference.is_none());
+    /// assert_eq!(reference.get(), None);
+    /// ```
+    #[inline]
+    pub fn none() -> Self {
+        Self {
+            inner: Ref::from_stored(O::MAX, T::stored_zero()),
+        }
+    }
+
+    /// Construct a present reference.
+    ///
+    /// # Panics
+    ///
+    /// This panics if the offset of `reference` is the maximum offset
+    /// representable by `O`, since it is reserved to represent an absent
+    /// reference. See [`OptionRef::try_some`] for a fallible variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::Ref;
+    /// use musli_zerocopy::pointer::OptionRef;
+    ///
+    /// let reference = OptionRef::some(Ref::<u32>::new(42));
+    /// assert!(reference.is_some());
+    /// assert_eq!(reference.get(), Some(Ref::new(42)));
+    /// ```
+    #[inline]
+    pub fn some(reference: Ref<T, E, O>) -> Self {
+        let Some(this) = Self::try_some(reference) else {
+            panic!(
+                "Offset {} is reserved to represent an absent reference",
+                reference.offset()
+            );
+        };
+
+        this
+    }
+
+    /// Try to construct a present reference, returning `None` if the offset of
+    /// `reference` is the maximum offset representable by `O`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::Ref;
+    /// use musli_zerocopy::endian::Native;
+    /// use musli_zerocopy::pointer::OptionRef;
+    ///
+    /// assert!(OptionRef::try_some(Ref::<u32, Native, u8>::new(254u8)).is_some());
+    /// assert!(OptionRef::try_some(Ref::<u32, Native, u8>::new(255u8)).is_none());
+    /// ```
+    #[inline]
+    pub fn try_some(reference: Ref<T, E, O>) -> Option<Self> {
+        if is_none_offset::<T, E, O>(reference) {
+            return None;
+        }
+
+        Some(Self { inner: reference })
+    }
+
+    /// Test if the reference is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::Ref;
+    /// use musli_zerocopy::pointer::OptionRef;
+    ///
+    /// assert!(OptionRef::some(Ref::<u32>::zero()).is_some());
+    /// assert!(!OptionRef::<u32>::none().is_some());
+    /// ```
+    #[inline]
+    pub fn is_some(self) -> bool {
+        !self.is_none()
+    }
+
+    /// Test if the reference is absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::Ref;
+    /// use musli_zerocopy::pointer::OptionRef;
+    ///
+    /// assert!(OptionRef::<u32>::none().is_none());
+    /// assert!(!OptionRef::some(Ref::<u32>::zero()).is_none());
+    /// ```
+    #[inline]
+    pub fn is_none(self) -> bool {
+        is_none_offset::<T, E, O>(self.inner)
+    }
+
+    /// Get the underlying reference if it is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::pointer::OptionRef;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let string = OptionRef::some(buf.store_unsized("Hello World"));
+    ///
+    /// if let Some(string) = string.get() {
+    ///     assert_eq!(buf.load(string)?, "Hello World");
+    /// }
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn get(self) -> Option<Ref<T, E, O>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(self.inner)
+        }
+    }
+}
+
+#[inline]
+fn is_none_offset<T, E, O>(reference: Ref<T, E, O>) -> bool
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    // Every bit of the offset being set is the same in any byte order.
+    reference.offset() == O::MAX.as_usize::<Native>()
+}
+
+impl<T, E, O> From<Ref<T, E, O>> for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    /// Convert a present reference, see [`OptionRef::some`].
+    #[inline]
+    fn from(reference: Ref<T, E, O>) -> Self {
+        Self::some(reference)
+    }
+}
+
+impl<T, E, O> From<Option<Ref<T, E, O>>> for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn from(reference: Option<Ref<T, E, O>>) -> Self {
+        match reference {
+            Some(reference) => Self::some(reference),
+            None => Self::none(),
+        }
+    }
+}
+
+impl<T, E, O> From<OptionRef<T, E, O>> for Option<Ref<T, E, O>>
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn from(reference: OptionRef<T, E, O>) -> Self {
+        reference.get()
+    }
+}
+
+/// Bind an [`OptionRef`] into an [`Option`] of the loaded value.
+impl<T, E, O> Bindable for OptionRef<T, E, O>
+where
+    T: 'static + ?Sized + Pointee,
+    Ref<T, E, O>: Load,
+    E: ByteOrder,
+    O: Size,
+{
+    type Bound<'a>
+        = Option<&'a <Ref<T, E, O> as Load>::Target>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn bind(self, buf: &Buf) -> Result<Self::Bound<'_>, Error> {
+        match self.get() {
+            Some(reference) => Ok(Some(buf.load(reference)?)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl<T, E, O> fmt::Debug for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee<Stored<O>: fmt::Debug>,
+    E: ByteOrder,
+    O: Size + fmt::Debug,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(reference) => write!(f, "Some({reference:?})"),
+            None => write!(f, "None"),
+        }
+    }
+}
+
+impl<T, E, O> Clone for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E, O> Copy for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+}
+
+impl<T, E, O> PartialEq for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee<Stored<O>: PartialEq>,
+    E: ByteOrder,
+    O: PartialEq + Size,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.get() == other.get()
+    }
+}
+
+impl<T, E, O> Eq for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee<Stored<O>: Eq>,
+    E: ByteOrder,
+    O: Eq + Size,
+{
+}
+
+impl<T, E, O> Hash for OptionRef<T, E, O>
+where
+    T: ?Sized + Pointee<Stored<O>: Hash>,
+    E: ByteOrder,
+    O: Hash + Size,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.get().hash(state);
+    }
+}
@@ -1,7 +1,9 @@
 use core::fmt;
 
+use crate::buf::{ref_visitor, RefVisitor};
+use crate::endian::ByteOrder;
 use crate::error::IntoRepr;
-use crate::pointer::Size;
+use crate::pointer::{Ref, Size};
 use crate::traits::ZeroCopy;
 
 mod sealed {
@@ -47,6 +49,19 @@ pub trait Pointee: self::sealed::Sealed {
     fn try_from_metadata<O>(metadata: Self::Metadata) -> Option<Self::Stored<O>>
     where
         O: Size;
+
+    /// The stored metadata where every bit is zero.
+    #[doc(hidden)]
+    fn stored_zero<O>() -> Self::Stored<O>
+    where
+        O: Size;
+
+    /// Visit a reference to this pointee.
+    #[doc(hidden)]
+    fn visit_ref<E, O>(reference: Ref<Self, E, O>, visitor: &mut RefVisitor<'_>)
+    where
+        E: ByteOrder,
+        O: Size;
 }
 
 impl<T> Pointee for T
@@ -63,6 +78,22 @@ where
     {
         Some(())
     }
+
+    #[inline(always)]
+    fn stored_zero<O>() -> Self::Stored<O>
+    where
+        O: Size,
+    {
+    }
+
+    #[inline]
+    fn visit_ref<E, O>(reference: Ref<Self, E, O>, visitor: &mut RefVisitor<'_>)
+    where
+        E: ByteOrder,
+        O: Size,
+    {
+        visitor.queue::<T>(reference.offset(), 0, ref_visitor::visit_sized::<T>);
+    }
 }
 
 impl<T> Pointee for [T]
@@ -79,6 +110,27 @@ where
     {
         O::try_from_usize(metadata)
     }
+
+    #[inline(always)]
+    fn stored_zero<O>() -> O
+    where
+        O: Size,
+    {
+        O::ZERO
+    }
+
+    #[inline]
+    fn visit_ref<E, O>(reference: Ref<Self, E, O>, visitor: &mut RefVisitor<'_>)
+    where
+        E: ByteOrder,
+        O: Size,
+    {
+        visitor.queue::<[T]>(
+            reference.offset(),
+            reference.len(),
+            ref_visitor::visit_slice::<T>,
+        );
+    }
 }
 
 impl Pointee for str {
@@ -92,4 +144,21 @@ impl Pointee for str {
     {
         O::try_from_usize(metadata)
     }
+
+    #[inline(always)]
+    fn stored_zero<O>() -> O
+    where
+        O: Size,
+    {
+        O::ZERO
+    }
+
+    #[inline]
+    fn visit_ref<E, O>(reference: Ref<Self, E, O>, visitor: &mut RefVisitor<'_>)
+    where
+        E: ByteOrder,
+        O: Size,
+    {
+        visitor.queue::<str>(reference.offset(), reference.len(), ref_visitor::visit_str);
+    }
 }
@@ -4,6 +4,7 @@ use core::hash::Hash;
 use core::marker::PhantomData;
 use core::mem::size_of;
 
+use crate::buf::RefVisitor;
 use crate::endian::{Big, ByteOrder, Little, Native};
 use crate::error::{Error, ErrorKind, IntoRepr};
 use crate::mem::MaybeUninit;
@@ -42,7 +43,7 @@ use crate::ZeroCopy;
 /// ```
 #[derive(ZeroCopy)]
 #[repr(C)]
-#[zero_copy(crate, swap_bytes_self)]
+#[zero_copy(crate, swap_bytes_self, visit_refs = visit_ref)]
 pub struct Ref<T, E = Native, O = DefaultSize>
 where
     T: ?Sized + Pointee,
@@ -55,6 +56,16 @@ where
     _marker: PhantomData<(E, T)>,
 }
 
+#[inline]
+fn visit_ref<T, E, O>(this: &Ref<T, E, O>, visitor: &mut RefVisitor<'_>)
+where
+    T: ?Sized + Pointee,
+    E: ByteOrder,
+    O: Size,
+{
+    visitor.visit_ref(*this);
+}
+
 impl<T, E, O> Ref<T, E, O>
 where
     T: ?Sized + Pointee,
@@ -770,6 +781,17 @@ where
         })
     }
 
+    /// Construct a reference from an offset and metadata which are already
+    /// stored in the byte order `E`.
+    #[inline]
+    pub(crate) const fn from_stored(offset: O, metadata: T::Stored<O>) -> Self {
+        Self {
+            offset,
+            metadata,
+            _marker: PhantomData,
+        }
+    }
+
     #[cfg(test)]
     pub(crate) fn cast<U>(self) -> Ref<U, E, O>
     where
@@ -0,0 +1,158 @@
+use core::cmp::Ordering;
+
+use alloc::vec::Vec;
+
+use crate::buf::{Buf, StoreBuf, Visit};
+use crate::error::Error;
+use crate::sorted::{Entry, MapRef, SetRef};
+use crate::ZeroCopy;
+
+/// Store a sorted map into a buffer.
+///
+/// Entries are sorted by key as they are stored, so they can be provided in
+/// any order.
+///
+/// This returns a [`MapRef`] which can be bound into a [`Map`] through the
+/// [`bind()`] method for convenience.
+///
+/// See the [module level documentation] for more information.
+///
+/// [`bind()`]: crate::buf::Buf::bind
+/// [`Map`]: crate::sorted::Map
+/// [module level documentation]: crate::sorted
+///
+/// # Duplicates
+///
+/// If the same key is provided multiple times, the last entry provided is the
+/// one that is stored.
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = sorted::store_map(&mut buf, [(1, 1), (2, 2), (1, 3)])?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.len(), 2);
+/// assert_eq!(map.get(&1)?, Some(&3));
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let first = buf.store_unsized("first");
+/// let second = buf.store_unsized("second");
+/// let third = buf.store_unsized("third");
+///
+/// let map = sorted::store_map(&mut buf, [(third, 3), (first, 1), (second, 2)])?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get("first")?, Some(&1));
+/// assert_eq!(map.get(&second)?, Some(&2));
+/// assert_eq!(map.get("fourth")?, None);
+///
+/// let values = map.range("f".."t")?.map(|(_, v)| *v).collect::<Vec<_>>();
+/// assert_eq!(values, [1, 2]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_map<K, V, I, S>(
+    buf: &mut S,
+    entries: I,
+) -> Result<MapRef<K, V, S::ByteOrder, S::Size>, Error>
+where
+    K: Visit + ZeroCopy,
+    K::Target: Ord,
+    V: ZeroCopy,
+    I: IntoIterator<Item = (K, V)>,
+    S: ?Sized + StoreBuf,
+{
+    let mut entries = entries
+        .into_iter()
+        .map(|(key, value)| Entry::new(key, value))
+        .collect::<Vec<_>>();
+
+    sort_dedup(buf.as_buf(), &mut entries, |e| &e.key)?;
+    Ok(MapRef::new(buf.store_unsized(&entries[..])))
+}
+
+/// Store a sorted set into a buffer.
+///
+/// Values are sorted as they are stored, so they can be provided in any order.
+/// Duplicate values are only stored once.
+///
+/// This returns a [`SetRef`] which can be bound into a [`Set`] through the
+/// [`bind()`] method for convenience.
+///
+/// See the [module level documentation] for more information.
+///
+/// [`bind()`]: crate::buf::Buf::bind
+/// [`Set`]: crate::sorted::Set
+/// [module level documentation]: crate::sorted
+///
+/// # Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let set = sorted::store_set(&mut buf, [5, 1, 3, 1])?;
+/// let set = buf.bind(set)?;
+///
+/// assert_eq!(set.len(), 3);
+/// assert!(set.contains(&3)?);
+/// assert!(!set.contains(&2)?);
+/// assert_eq!(set.first(), Some(&1));
+/// assert_eq!(set.last(), Some(&5));
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub fn store_set<T, I, S>(buf: &mut S, values: I) -> Result<SetRef<T, S::ByteOrder, S::Size>, Error>
+where
+    T: Visit + ZeroCopy,
+    T::Target: Ord,
+    I: IntoIterator<Item = T>,
+    S: ?Sized + StoreBuf,
+{
+    let mut values = values.into_iter().collect::<Vec<_>>();
+    sort_dedup(buf.as_buf(), &mut values, |v| v)?;
+    Ok(SetRef::new(buf.store_unsized(&values[..])))
+}
+
+/// Sort values by key, keeping only the last value provided for each key.
+fn sort_dedup<T, K, F>(buf: &Buf, values: &mut Vec<T>, key: F) -> Result<(), Error>
+where
+    K: Visit,
+    K::Target: Ord,
+    F: Fn(&T) -> &K,
+{
+    let mut error = None;
+
+    let mut cmp = |a: &T, b: &T| match key(a).visit(buf, |a| key(b).visit(buf, |b| a.cmp(b))) {
+        Ok(Ok(ordering)) => ordering,
+        Ok(Err(e)) | Err(e) => {
+            error.get_or_insert(e);
+            Ordering::Equal
+        }
+    };
+
+    // Sorting is stable, so by reversing before and after de-duplication the
+    // last value provided for any given key is the one which is retained.
+    values.sort_by(&mut cmp);
+    values.reverse();
+    values.dedup_by(|a, b| cmp(a, b).is_eq());
+    values.reverse();
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
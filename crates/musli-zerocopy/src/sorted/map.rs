@@ -0,0 +1,924 @@
+//! A map which stores its entries sorted by key, supporting ordered queries.
+//!
+//! There's two types provided by this module:
+//! * [`Map<K, V>`] which is a *bound* reference to a map, providing a
+//!   convenient map-like access.
+//! * [`MapRef<K, V>`] which is the *pointer* of the map. This is what you store
+//!   in [`ZeroCopy`] types and is what is returned by [`sorted::store_map`].
+//!
+//! [`sorted::store_map`]: crate::sorted::store_map
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::ops::{Range, RangeBounds};
+use core::slice;
+
+use crate::buf::{Bindable, Buf, Visit};
+use crate::endian::{ByteOrder, Native};
+use crate::error::{Error, ErrorKind};
+use crate::pointer::{DefaultSize, Ref, Size};
+use crate::sorted::Entry;
+use crate::ZeroCopy;
+
+/// A map bound to a [`Buf`] through [`Buf::bind`] for convenience.
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = sorted::store_map(&mut buf, [(3, 4), (1, 2), (2, 3)])?;
+/// let map = buf.bind(map)?;
+///
+/// assert_eq!(map.get(&1)?, Some(&2));
+/// assert_eq!(map.get(&4)?, None);
+/// assert_eq!(map.first(), Some((&1, &2)));
+/// assert_eq!(map.last(), Some((&3, &4)));
+///
+/// let entries = map.range(2..)?.collect::<Vec<_>>();
+/// assert_eq!(entries, [(&2, &3), (&3, &4)]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub struct Map<'a, K, V> {
+    entries: &'a [Entry<K, V>],
+    buf: &'a Buf,
+}
+
+impl<'a, K, V> Map<'a, K, V>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+{
+    /// Get a value from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.get(&1)?, Some(&2));
+    /// assert_eq!(map.get(&2)?, Some(&3));
+    /// assert_eq!(map.get(&3)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get<T>(&self, key: &T) -> Result<Option<&'a V>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        Ok(self.get_entry(key)?.map(|(_, v)| v))
+    }
+
+    /// Test if the map contains the given `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert!(map.contains_key(&1)?);
+    /// assert!(!map.contains_key(&3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn contains_key<T>(&self, key: &T) -> Result<bool, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        Ok(self.get_entry(key)?.is_some())
+    }
+
+    /// Get an entry from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.get_entry(&1)?, Some((&1, &2)));
+    /// assert_eq!(map.get_entry(&3)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get_entry<T>(&self, key: &T) -> Result<Option<(&'a K, &'a V)>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        let index = crate::sorted::find(self.entries.len(), key, |n, q| self.compare(n, q))?;
+        Ok(index.map(|n| self.entry(n)))
+    }
+
+    /// Get the first entry in the map which has a key that is not less than
+    /// `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(10, 1), (20, 2)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.lower_bound(&10)?, Some((&10, &1)));
+    /// assert_eq!(map.lower_bound(&11)?, Some((&20, &2)));
+    /// assert_eq!(map.lower_bound(&21)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn lower_bound<T>(&self, key: &T) -> Result<Option<(&'a K, &'a V)>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        let n = crate::sorted::lower_bound(self.entries.len(), key, |n, q| self.compare(n, q))?;
+        Ok(self.entries.get(n).map(|e| (&e.key, &e.value)))
+    }
+
+    /// Get the first entry in the map which has a key that is greater than
+    /// `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(10, 1), (20, 2)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.upper_bound(&9)?, Some((&10, &1)));
+    /// assert_eq!(map.upper_bound(&10)?, Some((&20, &2)));
+    /// assert_eq!(map.upper_bound(&20)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn upper_bound<T>(&self, key: &T) -> Result<Option<(&'a K, &'a V)>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        let n = crate::sorted::upper_bound(self.entries.len(), key, |n, q| self.compare(n, q))?;
+        Ok(self.entries.get(n).map(|e| (&e.key, &e.value)))
+    }
+
+    /// Iterate over the entries in the map whose keys are within `range`, in
+    /// ascending key order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, (0..10).map(|n| (n * 10, n)))?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let values = map.range(15..=40)?.map(|(_, v)| *v).collect::<Vec<_>>();
+    /// assert_eq!(values, [2, 3, 4]);
+    ///
+    /// let values = map.range(..20)?.rev().map(|(_, v)| *v).collect::<Vec<_>>();
+    /// assert_eq!(values, [1, 0]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn range<T, R>(&self, range: R) -> Result<Iter<'a, K, V>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+        R: RangeBounds<T>,
+    {
+        let range = crate::sorted::range(self.entries.len(), &range, |n, q| self.compare(n, q))?;
+
+        Ok(Iter {
+            iter: self.entries[range].iter(),
+        })
+    }
+
+    /// Get the entry with the smallest key in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(2, 3), (1, 2)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.first(), Some((&1, &2)));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn first(&self) -> Option<(&'a K, &'a V)> {
+        let e = self.entries.first()?;
+        Some((&e.key, &e.value))
+    }
+
+    /// Get the entry with the largest key in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(2, 3), (1, 2)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// assert_eq!(map.last(), Some((&2, &3)));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn last(&self) -> Option<(&'a K, &'a V)> {
+        let e = self.entries.last()?;
+        Some((&e.key, &e.value))
+    }
+
+    /// Get the number of entries in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the entries of the map in ascending key order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(2, 3), (1, 2)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let entries = map.iter().collect::<Vec<_>>();
+    /// assert_eq!(entries, [(&1, &2), (&2, &3)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, K, V> {
+        Iter {
+            iter: self.entries.iter(),
+        }
+    }
+
+    /// Iterate over the keys of the map in ascending order.
+    #[inline]
+    pub fn keys(&self) -> Keys<'a, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Iterate over the values of the map in ascending key order.
+    #[inline]
+    pub fn values(&self) -> Values<'a, K, V> {
+        Values { iter: self.iter() }
+    }
+
+    #[inline]
+    fn entry(&self, n: usize) -> (&'a K, &'a V) {
+        let e = &self.entries[n];
+        (&e.key, &e.value)
+    }
+
+    #[inline]
+    fn compare<T>(&self, n: usize, key: &T) -> Result<Ordering, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        crate::sorted::compare(self.buf, &self.entries[n].key, key)
+    }
+}
+
+/// Bind a [`MapRef`] into a [`Map`].
+impl<K, V, E, O> Bindable for MapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Bound<'a>
+        = Map<'a, K, V>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn bind(self, buf: &Buf) -> Result<Self::Bound<'_>, Error> {
+        Ok(Map {
+            entries: buf.load(self.entries)?,
+            buf,
+        })
+    }
+}
+
+/// A stored reference to a sorted map.
+///
+/// Note that operating over the methods provided in [`MapRef`] does not demand
+/// that the entire contents of the map is validated as would be the case when
+/// [`bind()`] is used. Only the entries visited by a lookup are validated,
+/// which might result in better performance if the data is infrequently
+/// accessed.
+///
+/// Constructed through [`sorted::store_map`].
+///
+/// [`sorted::store_map`]: crate::sorted::store_map
+/// [`bind()`]: crate::buf::Buf::bind
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = sorted::store_map(&mut buf, [(3, 4), (1, 2), (2, 3)])?;
+///
+/// assert_eq!(map.get(&buf, &1)?, Some(&2));
+/// assert_eq!(map.get(&buf, &4)?, None);
+/// assert_eq!(map.first(&buf)?, Some((&1, &2)));
+///
+/// let entries = map.range(&buf, 2..)?.collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(entries, [(&2, &3), (&3, &4)]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+pub struct MapRef<K, V, E = Native, O = DefaultSize>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    entries: Ref<[Entry<K, V>], E, O>,
+}
+
+impl<K, V, E, O> MapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    #[cfg(feature = "alloc")]
+    pub(crate) fn new(entries: Ref<[Entry<K, V>], E, O>) -> Self {
+        Self { entries }
+    }
+
+    /// Get a value from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert_eq!(map.get(&buf, &1)?, Some(&2));
+    /// assert_eq!(map.get(&buf, &3)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get<'buf, T>(&self, buf: &'buf Buf, key: &T) -> Result<Option<&'buf V>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: 'buf + Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        Ok(self.get_entry(buf, key)?.map(|(_, v)| v))
+    }
+
+    /// Test if the map contains the given `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert!(map.contains_key(&buf, &1)?);
+    /// assert!(!map.contains_key(&buf, &3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn contains_key<T>(&self, buf: &Buf, key: &T) -> Result<bool, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        Ok(self.get_entry(buf, key)?.is_some())
+    }
+
+    /// Get an entry from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// assert_eq!(map.get_entry(&buf, &1)?, Some((&1, &2)));
+    /// assert_eq!(map.get_entry(&buf, &3)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn get_entry<'buf, T>(
+        &self,
+        buf: &'buf Buf,
+        key: &T,
+    ) -> Result<Option<(&'buf K, &'buf V)>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: 'buf + Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        let index = crate::sorted::find(self.entries.len(), key, |n, q| self.compare(buf, n, q))?;
+
+        match index {
+            Some(n) => Ok(Some(self.entry(buf, n)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the first entry in the map which has a key that is not less than
+    /// `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(10, 1), (20, 2)])?;
+    ///
+    /// assert_eq!(map.lower_bound(&buf, &11)?, Some((&20, &2)));
+    /// assert_eq!(map.lower_bound(&buf, &21)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn lower_bound<'buf, T>(
+        &self,
+        buf: &'buf Buf,
+        key: &T,
+    ) -> Result<Option<(&'buf K, &'buf V)>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: 'buf + Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        let n =
+            crate::sorted::lower_bound(self.entries.len(), key, |n, q| self.compare(buf, n, q))?;
+
+        self.get_index(buf, n)
+    }
+
+    /// Get the first entry in the map which has a key that is greater than
+    /// `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(10, 1), (20, 2)])?;
+    ///
+    /// assert_eq!(map.upper_bound(&buf, &10)?, Some((&20, &2)));
+    /// assert_eq!(map.upper_bound(&buf, &20)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn upper_bound<'buf, T>(
+        &self,
+        buf: &'buf Buf,
+        key: &T,
+    ) -> Result<Option<(&'buf K, &'buf V)>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: 'buf + Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        let n =
+            crate::sorted::upper_bound(self.entries.len(), key, |n, q| self.compare(buf, n, q))?;
+
+        self.get_index(buf, n)
+    }
+
+    /// Iterate over the entries in the map whose keys are within `range`, in
+    /// ascending key order.
+    ///
+    /// Each entry is validated as it is loaded from the buffer.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, (0..10).map(|n| (n * 10, n)))?;
+    ///
+    /// let values = map
+    ///     .range(&buf, 15..=40)?
+    ///     .map(|e| Ok(*e?.1))
+    ///     .collect::<Result<Vec<_>, musli_zerocopy::Error>>()?;
+    ///
+    /// assert_eq!(values, [2, 3, 4]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn range<'buf, T, R>(
+        &self,
+        buf: &'buf Buf,
+        range: R,
+    ) -> Result<RefIter<'buf, K, V, E, O>, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: 'buf + Visit,
+        K::Target: Borrow<T::Target>,
+        R: RangeBounds<T>,
+    {
+        let range =
+            crate::sorted::range(self.entries.len(), &range, |n, q| self.compare(buf, n, q))?;
+
+        Ok(RefIter {
+            entries: self.entries,
+            buf,
+            range,
+        })
+    }
+
+    /// Get the entry with the smallest key in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(2, 3), (1, 2)])?;
+    ///
+    /// assert_eq!(map.first(&buf)?, Some((&1, &2)));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn first<'buf>(&self, buf: &'buf Buf) -> Result<Option<(&'buf K, &'buf V)>, Error>
+    where
+        K: 'buf,
+    {
+        self.get_index(buf, 0)
+    }
+
+    /// Get the entry with the largest key in the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = sorted::store_map(&mut buf, [(2, 3), (1, 2)])?;
+    ///
+    /// assert_eq!(map.last(&buf)?, Some((&2, &3)));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn last<'buf>(&self, buf: &'buf Buf) -> Result<Option<(&'buf K, &'buf V)>, Error>
+    where
+        K: 'buf,
+    {
+        match self.entries.len().checked_sub(1) {
+            Some(n) => self.get_index(buf, n),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the number of entries in the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the entries of the map in ascending key order.
+    ///
+    /// Each entry is validated as it is loaded from the buffer.
+    #[inline]
+    pub fn iter<'buf>(&self, buf: &'buf Buf) -> RefIter<'buf, K, V, E, O> {
+        RefIter {
+            entries: self.entries,
+            buf,
+            range: 0..self.entries.len(),
+        }
+    }
+
+    #[inline]
+    fn entry<'buf>(&self, buf: &'buf Buf, n: usize) -> Result<(&'buf K, &'buf V), Error>
+    where
+        K: 'buf,
+    {
+        let Some(e) = self.entries.get(n) else {
+            return Err(Error::new(ErrorKind::IndexOutOfBounds {
+                index: n,
+                len: self.entries.len(),
+            }));
+        };
+
+        let e = buf.load(e)?;
+        Ok((&e.key, &e.value))
+    }
+
+    #[inline]
+    fn get_index<'buf>(&self, buf: &'buf Buf, n: usize) -> Result<Option<(&'buf K, &'buf V)>, Error>
+    where
+        K: 'buf,
+    {
+        if n < self.entries.len() {
+            Ok(Some(self.entry(buf, n)?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    #[inline]
+    fn compare<T>(&self, buf: &Buf, n: usize, key: &T) -> Result<Ordering, Error>
+    where
+        T: ?Sized + Visit,
+        T::Target: Ord,
+        K: Visit,
+        K::Target: Borrow<T::Target>,
+    {
+        let (k, _) = self.entry(buf, n)?;
+        crate::sorted::compare(buf, k, key)
+    }
+}
+
+impl<K, V, E, O> Clone for MapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K, V, E, O> Copy for MapRef<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+}
+
+/// An iterator over the entries of a [`Map`].
+///
+/// See [`Map::iter`] and [`Map::range`].
+pub struct Iter<'a, K, V> {
+    iter: slice::Iter<'a, Entry<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let e = self.iter.next()?;
+        Some((&e.key, &e.value))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let e = self.iter.next_back()?;
+        Some((&e.key, &e.value))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+/// An iterator over the keys of a [`Map`].
+///
+/// See [`Map::keys`].
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.0)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Keys<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next_back()?.0)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Keys<'_, K, V> {}
+
+/// An iterator over the values of a [`Map`].
+///
+/// See [`Map::values`].
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.1)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Values<'_, K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next_back()?.1)
+    }
+}
+
+impl<K, V> ExactSizeIterator for Values<'_, K, V> {}
+
+/// An iterator over the entries of a [`MapRef`] which validates each entry as
+/// it is loaded.
+///
+/// See [`MapRef::iter`] and [`MapRef::range`].
+pub struct RefIter<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    entries: Ref<[Entry<K, V>], E, O>,
+    buf: &'buf Buf,
+    range: Range<usize>,
+}
+
+impl<'buf, K, V, E, O> RefIter<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn load(&mut self, n: usize) -> Result<(&'buf K, &'buf V), Error> {
+        let result = match self.entries.get(n) {
+            Some(e) => self.buf.load(e).map(|e| (&e.key, &e.value)),
+            None => Err(Error::new(ErrorKind::IndexOutOfBounds {
+                index: n,
+                len: self.entries.len(),
+            })),
+        };
+
+        if result.is_err() {
+            self.range = 0..0;
+        }
+
+        result
+    }
+}
+
+impl<'buf, K, V, E, O> Iterator for RefIter<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<(&'buf K, &'buf V), Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.range.next()?;
+        Some(self.load(n))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'buf, K, V, E, O> DoubleEndedIterator for RefIter<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let n = self.range.next_back()?;
+        Some(self.load(n))
+    }
+}
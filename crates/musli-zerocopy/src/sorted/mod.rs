@@ -0,0 +1,130 @@
+//! A ZeroCopy [`Map`] and [`Set`] which store their elements in sorted order.
+//!
+//! Unlike [`phf`] and [`swiss`], these support ordered queries such as
+//! iterating over a range of keys, accessing the first and last elements, and
+//! finding the lower or upper bound of a key. Lookups are performed using a
+//! binary search, so they are `O(log n)` rather than `O(1)`.
+//!
+//! Elements are sorted and de-duplicated when the map or set is constructed
+//! through [`store_map`] or [`store_set`]. If a loaded map is not sorted, the
+//! results of any lookups are unspecified but will never be unsound.
+//!
+//! [`phf`]: crate::phf
+//! [`swiss`]: crate::swiss
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::ops::{Bound, Range, RangeBounds};
+
+use crate::buf::{Buf, Visit};
+use crate::error::Error;
+
+pub(crate) use self::entry::Entry;
+mod entry;
+
+#[doc(inline)]
+pub use self::map::{Map, MapRef};
+pub mod map;
+
+#[doc(inline)]
+pub use self::set::{Set, SetRef};
+pub mod set;
+
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use self::factory::*;
+#[cfg(feature = "alloc")]
+mod factory;
+
+/// Compare a stored `key` against the searched for key `q`.
+#[inline]
+pub(crate) fn compare<K, Q>(buf: &Buf, key: &K, q: &Q) -> Result<Ordering, Error>
+where
+    K: ?Sized + Visit,
+    Q: ?Sized + Visit,
+    K::Target: Borrow<Q::Target>,
+    Q::Target: Ord,
+{
+    q.visit(buf, |q| key.visit(buf, |key| key.borrow().cmp(q)))?
+}
+
+/// Find the first index in `0..len` for which `pred` returns `false`,
+/// assuming that `pred` partitions the indexes.
+#[inline]
+pub(crate) fn partition_point<F>(len: usize, mut pred: F) -> Result<usize, Error>
+where
+    F: FnMut(usize) -> Result<bool, Error>,
+{
+    let mut lo = 0;
+    let mut hi = len;
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+
+        if pred(mid)? {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    Ok(lo)
+}
+
+/// Find the index of the first element which is not less than `q`.
+#[inline]
+pub(crate) fn lower_bound<Q, F>(len: usize, q: &Q, mut cmp: F) -> Result<usize, Error>
+where
+    Q: ?Sized,
+    F: FnMut(usize, &Q) -> Result<Ordering, Error>,
+{
+    partition_point(len, |n| Ok(cmp(n, q)?.is_lt()))
+}
+
+/// Find the index of the first element which is greater than `q`.
+#[inline]
+pub(crate) fn upper_bound<Q, F>(len: usize, q: &Q, mut cmp: F) -> Result<usize, Error>
+where
+    Q: ?Sized,
+    F: FnMut(usize, &Q) -> Result<Ordering, Error>,
+{
+    partition_point(len, |n| Ok(cmp(n, q)?.is_le()))
+}
+
+/// Find the index of the element equal to `q`.
+#[inline]
+pub(crate) fn find<Q, F>(len: usize, q: &Q, mut cmp: F) -> Result<Option<usize>, Error>
+where
+    Q: ?Sized,
+    F: FnMut(usize, &Q) -> Result<Ordering, Error>,
+{
+    let index = lower_bound(len, q, &mut cmp)?;
+
+    if index < len && cmp(index, q)?.is_eq() {
+        Ok(Some(index))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Translate a range of keys into a range of indexes.
+pub(crate) fn range<Q, R, F>(len: usize, range: &R, mut cmp: F) -> Result<Range<usize>, Error>
+where
+    Q: ?Sized,
+    R: ?Sized + RangeBounds<Q>,
+    F: FnMut(usize, &Q) -> Result<Ordering, Error>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(q) => lower_bound(len, q, &mut cmp)?,
+        Bound::Excluded(q) => upper_bound(len, q, &mut cmp)?,
+        Bound::Unbounded => 0,
+    };
+
+    let end = match range.end_bound() {
+        Bound::Included(q) => upper_bound(len, q, &mut cmp)?,
+        Bound::Excluded(q) => lower_bound(len, q, &mut cmp)?,
+        Bound::Unbounded => len,
+    };
+
+    Ok(start..end.max(start))
+}
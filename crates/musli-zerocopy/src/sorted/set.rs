@@ -0,0 +1,649 @@
+//! A set which stores its values in sorted order, supporting ordered queries.
+//!
+//! There's two types provided by this module:
+//! * [`Set<T>`] which is a *bound* reference to a set, providing a convenient
+//!   set-like access.
+//! * [`SetRef<T>`] which is the *pointer* of the set. This is what you store in
+//!   [`ZeroCopy`] types and is what is returned by [`sorted::store_set`].
+//!
+//! [`sorted::store_set`]: crate::sorted::store_set
+
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::ops::{Range, RangeBounds};
+use core::slice;
+
+use crate::buf::{Bindable, Buf, Visit};
+use crate::endian::{ByteOrder, Native};
+use crate::error::{Error, ErrorKind};
+use crate::pointer::{DefaultSize, Ref, Size};
+use crate::ZeroCopy;
+
+/// A set bound to a [`Buf`] through [`Buf::bind`] for convenience.
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let set = sorted::store_set(&mut buf, [3, 1, 2])?;
+/// let set = buf.bind(set)?;
+///
+/// assert!(set.contains(&1)?);
+/// assert!(!set.contains(&4)?);
+/// assert_eq!(set.first(), Some(&1));
+/// assert_eq!(set.last(), Some(&3));
+///
+/// let values = set.range(2..)?.collect::<Vec<_>>();
+/// assert_eq!(values, [&2, &3]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+pub struct Set<'a, T> {
+    entries: &'a [T],
+    buf: &'a Buf,
+}
+
+impl<'a, T> Set<'a, T>
+where
+    T: ZeroCopy,
+{
+    /// Test if the set contains the given `value`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, [1, 2])?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// assert!(set.contains(&1)?);
+    /// assert!(set.contains(&2)?);
+    /// assert!(!set.contains(&3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn contains<Q>(&self, value: &Q) -> Result<bool, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: Visit,
+        T::Target: Borrow<Q::Target>,
+    {
+        let index = crate::sorted::find(self.entries.len(), value, |n, q| self.compare(n, q))?;
+        Ok(index.is_some())
+    }
+
+    /// Get the first value in the set which is not less than `value`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, [10, 20])?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// assert_eq!(set.lower_bound(&10)?, Some(&10));
+    /// assert_eq!(set.lower_bound(&11)?, Some(&20));
+    /// assert_eq!(set.lower_bound(&21)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn lower_bound<Q>(&self, value: &Q) -> Result<Option<&'a T>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: Visit,
+        T::Target: Borrow<Q::Target>,
+    {
+        let n = crate::sorted::lower_bound(self.entries.len(), value, |n, q| self.compare(n, q))?;
+        Ok(self.entries.get(n))
+    }
+
+    /// Get the first value in the set which is greater than `value`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, [10, 20])?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// assert_eq!(set.upper_bound(&9)?, Some(&10));
+    /// assert_eq!(set.upper_bound(&10)?, Some(&20));
+    /// assert_eq!(set.upper_bound(&20)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn upper_bound<Q>(&self, value: &Q) -> Result<Option<&'a T>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: Visit,
+        T::Target: Borrow<Q::Target>,
+    {
+        let n = crate::sorted::upper_bound(self.entries.len(), value, |n, q| self.compare(n, q))?;
+        Ok(self.entries.get(n))
+    }
+
+    /// Iterate over the values in the set which are within `range`, in
+    /// ascending order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, (0..10).map(|n| n * 10))?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// let values = set.range(15..=40)?.copied().collect::<Vec<_>>();
+    /// assert_eq!(values, [20, 30, 40]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Result<Iter<'a, T>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: Visit,
+        T::Target: Borrow<Q::Target>,
+        R: RangeBounds<Q>,
+    {
+        let range = crate::sorted::range(self.entries.len(), &range, |n, q| self.compare(n, q))?;
+
+        Ok(Iter {
+            iter: self.entries[range].iter(),
+        })
+    }
+
+    /// Get the smallest value in the set.
+    #[inline]
+    pub fn first(&self) -> Option<&'a T> {
+        self.entries.first()
+    }
+
+    /// Get the largest value in the set.
+    #[inline]
+    pub fn last(&self) -> Option<&'a T> {
+        self.entries.last()
+    }
+
+    /// Get the number of values in the set.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, [1, 3])?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// assert!(!set.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the values of the set in ascending order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, [3, 1, 2])?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// let values = set.iter().collect::<Vec<_>>();
+    /// assert_eq!(values, [&1, &2, &3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            iter: self.entries.iter(),
+        }
+    }
+
+    #[inline]
+    fn compare<Q>(&self, n: usize, value: &Q) -> Result<Ordering, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: Visit,
+        T::Target: Borrow<Q::Target>,
+    {
+        crate::sorted::compare(self.buf, &self.entries[n], value)
+    }
+}
+
+/// Bind a [`SetRef`] into a [`Set`].
+impl<T, E, O> Bindable for SetRef<T, E, O>
+where
+    T: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Bound<'a>
+        = Set<'a, T>
+    where
+        Self: 'a;
+
+    #[inline]
+    fn bind(self, buf: &Buf) -> Result<Self::Bound<'_>, Error> {
+        Ok(Set {
+            entries: buf.load(self.entries)?,
+            buf,
+        })
+    }
+}
+
+/// A stored reference to a sorted set.
+///
+/// Note that operating over the methods provided in [`SetRef`] does not demand
+/// that the entire contents of the set is validated as would be the case when
+/// [`bind()`] is used. Only the values visited by a lookup are validated, which
+/// might result in better performance if the data is infrequently accessed.
+///
+/// Constructed through [`sorted::store_set`].
+///
+/// [`sorted::store_set`]: crate::sorted::store_set
+/// [`bind()`]: crate::buf::Buf::bind
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::sorted;
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let set = sorted::store_set(&mut buf, [3, 1, 2])?;
+///
+/// assert!(set.contains(&buf, &1)?);
+/// assert!(!set.contains(&buf, &4)?);
+/// assert_eq!(set.first(&buf)?, Some(&1));
+///
+/// let values = set.range(&buf, 2..)?.collect::<Result<Vec<_>, _>>()?;
+/// assert_eq!(values, [&2, &3]);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[derive(Debug, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+pub struct SetRef<T, E = Native, O = DefaultSize>
+where
+    T: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    entries: Ref<[T], E, O>,
+}
+
+impl<T, E, O> SetRef<T, E, O>
+where
+    T: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    #[cfg(feature = "alloc")]
+    pub(crate) fn new(entries: Ref<[T], E, O>) -> Self {
+        Self { entries }
+    }
+
+    /// Test if the set contains the given `value`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, [1, 2])?;
+    ///
+    /// assert!(set.contains(&buf, &1)?);
+    /// assert!(!set.contains(&buf, &3)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn contains<Q>(&self, buf: &Buf, value: &Q) -> Result<bool, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: Visit,
+        T::Target: Borrow<Q::Target>,
+    {
+        let index = crate::sorted::find(self.entries.len(), value, |n, q| self.compare(buf, n, q))?;
+
+        Ok(index.is_some())
+    }
+
+    /// Get the first value in the set which is not less than `value`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, [10, 20])?;
+    ///
+    /// assert_eq!(set.lower_bound(&buf, &11)?, Some(&20));
+    /// assert_eq!(set.lower_bound(&buf, &21)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn lower_bound<'buf, Q>(&self, buf: &'buf Buf, value: &Q) -> Result<Option<&'buf T>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: 'buf + Visit,
+        T::Target: Borrow<Q::Target>,
+    {
+        let n =
+            crate::sorted::lower_bound(self.entries.len(), value, |n, q| self.compare(buf, n, q))?;
+
+        self.get_index(buf, n)
+    }
+
+    /// Get the first value in the set which is greater than `value`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, [10, 20])?;
+    ///
+    /// assert_eq!(set.upper_bound(&buf, &10)?, Some(&20));
+    /// assert_eq!(set.upper_bound(&buf, &20)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn upper_bound<'buf, Q>(&self, buf: &'buf Buf, value: &Q) -> Result<Option<&'buf T>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: 'buf + Visit,
+        T::Target: Borrow<Q::Target>,
+    {
+        let n =
+            crate::sorted::upper_bound(self.entries.len(), value, |n, q| self.compare(buf, n, q))?;
+
+        self.get_index(buf, n)
+    }
+
+    /// Iterate over the values in the set which are within `range`, in
+    /// ascending order.
+    ///
+    /// Each value is validated as it is loaded from the buffer.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::sorted;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = sorted::store_set(&mut buf, (0..10).map(|n| n * 10))?;
+    ///
+    /// let values = set
+    ///     .range(&buf, 15..=40)?
+    ///     .map(|v| Ok(*v?))
+    ///     .collect::<Result<Vec<_>, musli_zerocopy::Error>>()?;
+    ///
+    /// assert_eq!(values, [20, 30, 40]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn range<'buf, Q, R>(
+        &self,
+        buf: &'buf Buf,
+        range: R,
+    ) -> Result<RefIter<'buf, T, E, O>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: 'buf + Visit,
+        T::Target: Borrow<Q::Target>,
+        R: RangeBounds<Q>,
+    {
+        let range =
+            crate::sorted::range(self.entries.len(), &range, |n, q| self.compare(buf, n, q))?;
+
+        Ok(RefIter {
+            entries: self.entries,
+            buf,
+            range,
+        })
+    }
+
+    /// Get the smallest value in the set.
+    #[inline]
+    pub fn first<'buf>(&self, buf: &'buf Buf) -> Result<Option<&'buf T>, Error>
+    where
+        T: 'buf,
+    {
+        self.get_index(buf, 0)
+    }
+
+    /// Get the largest value in the set.
+    #[inline]
+    pub fn last<'buf>(&self, buf: &'buf Buf) -> Result<Option<&'buf T>, Error>
+    where
+        T: 'buf,
+    {
+        match self.entries.len().checked_sub(1) {
+            Some(n) => self.get_index(buf, n),
+            None => Ok(None),
+        }
+    }
+
+    /// Get the number of values in the set.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Test if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Iterate over the values of the set in ascending order.
+    ///
+    /// Each value is validated as it is loaded from the buffer.
+    #[inline]
+    pub fn iter<'buf>(&self, buf: &'buf Buf) -> RefIter<'buf, T, E, O> {
+        RefIter {
+            entries: self.entries,
+            buf,
+            range: 0..self.entries.len(),
+        }
+    }
+
+    #[inline]
+    fn get_index<'buf>(&self, buf: &'buf Buf, n: usize) -> Result<Option<&'buf T>, Error>
+    where
+        T: 'buf,
+    {
+        match self.entries.get(n) {
+            Some(value) => Ok(Some(buf.load(value)?)),
+            None => Ok(None),
+        }
+    }
+
+    #[inline]
+    fn compare<Q>(&self, buf: &Buf, n: usize, value: &Q) -> Result<Ordering, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Ord,
+        T: Visit,
+        T::Target: Borrow<Q::Target>,
+    {
+        let Some(entry) = self.entries.get(n) else {
+            return Err(Error::new(ErrorKind::IndexOutOfBounds {
+                index: n,
+                len: self.entries.len(),
+            }));
+        };
+
+        crate::sorted::compare(buf, buf.load(entry)?, value)
+    }
+}
+
+impl<T, E, O> Clone for SetRef<T, E, O>
+where
+    T: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, E, O> Copy for SetRef<T, E, O>
+where
+    T: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+}
+
+/// An iterator over the values of a [`Set`].
+///
+/// See [`Set::iter`] and [`Set::range`].
+pub struct Iter<'a, T> {
+    iter: slice::Iter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+/// An iterator over the values of a [`SetRef`] which validates each value as it
+/// is loaded.
+///
+/// See [`SetRef::iter`] and [`SetRef::range`].
+pub struct RefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    entries: Ref<[T], E, O>,
+    buf: &'buf Buf,
+    range: Range<usize>,
+}
+
+impl<'buf, T, E, O> RefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn load(&mut self, n: usize) -> Result<&'buf T, Error> {
+        let result = match self.entries.get(n) {
+            Some(value) => self.buf.load(value),
+            None => Err(Error::new(ErrorKind::IndexOutOfBounds {
+                index: n,
+                len: self.entries.len(),
+            })),
+        };
+
+        if result.is_err() {
+            self.range = 0..0;
+        }
+
+        result
+    }
+}
+
+impl<'buf, T, E, O> Iterator for RefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<&'buf T, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let n = self.range.next()?;
+        Some(self.load(n))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.range.size_hint()
+    }
+}
+
+impl<'buf, T, E, O> DoubleEndedIterator for RefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let n = self.range.next_back()?;
+        Some(self.load(n))
+    }
+}
@@ -10,7 +10,9 @@ use core::ptr::NonNull;
 
 use crate::buf::{self, Buf, StoreBuf};
 use crate::error::{Error, ErrorKind};
-use crate::swiss::raw::{h2, is_full, probe_seq, special_is_empty, Group, ProbeSeq};
+use crate::swiss::raw::{
+    h2, is_full, probe_seq, special_is_empty, Group, ProbeSeq, DELETED, EMPTY,
+};
 use crate::traits::ZeroCopy;
 
 /// Construction of a raw swiss table.
@@ -72,6 +74,32 @@ where
         }
     }
 
+    /// Wrap an existing table in the given buffer, which can hold
+    /// `growth_left` more elements before it has to grow.
+    ///
+    /// The control bytes at `ctrl_ptr` should be the ones of a previously
+    /// constructed table. Since every access to the buffer is checked, a table
+    /// which doesn't uphold this results in errors or panics.
+    pub(crate) fn with_growth_left(
+        buf: &'a mut S,
+        ctrl_ptr: usize,
+        base_ptr: usize,
+        buckets: usize,
+        growth_left: usize,
+    ) -> Self {
+        debug_assert!(buckets.is_power_of_two());
+        debug_assert!(growth_left <= bucket_mask_to_capacity(buckets - 1));
+
+        Self {
+            buf,
+            bucket_mask: buckets - 1,
+            ctrl_ptr,
+            base_ptr,
+            growth_left,
+            _marker: PhantomData,
+        }
+    }
+
     /// Access the underlying buffer.
     pub(crate) fn buf(&self) -> &Buf {
         self.buf.as_buf()
@@ -84,7 +112,7 @@ where
 
     /// Get the length of the table.
     pub(crate) fn len(&self) -> usize {
-        self.bucket_mask - self.growth_left
+        bucket_mask_to_capacity(self.bucket_mask) - self.growth_left
     }
 
     /// Returns the number of buckets in the table.
@@ -93,6 +121,45 @@ where
         self.bucket_mask + 1
     }
 
+    /// Number of elements that can be inserted before the table has to grow.
+    #[inline]
+    pub(crate) fn growth_left(&self) -> usize {
+        self.growth_left
+    }
+
+    /// Erase the element at the given index from the table.
+    ///
+    /// The bucket is marked as deleted unless no probe sequence can have
+    /// passed through it, in which case it is marked as empty and its capacity
+    /// is reclaimed.
+    pub(crate) fn erase(&mut self, index: usize) {
+        debug_assert!(self.is_bucket_full(index));
+
+        let index_before = index.wrapping_sub(Group::WIDTH) & self.bucket_mask;
+
+        // SAFETY: Both indexes are within the table, and are followed by a full
+        // group of control bytes.
+        let (empty_before, empty_after) = unsafe {
+            (
+                Group::load(self.ctrl_group(index_before).as_ptr()).match_empty(),
+                Group::load(self.ctrl_group(index).as_ptr()).match_empty(),
+            )
+        };
+
+        // If we are inside a continuous block of Group::WIDTH full or deleted
+        // cells then a probe window may have seen a full block when trying to
+        // insert. We therefore need to keep that block non-empty so that
+        // lookups will continue searching to the next probe window.
+        let ctrl = if empty_before.leading_zeros() + empty_after.trailing_zeros() >= Group::WIDTH {
+            DELETED
+        } else {
+            self.growth_left += 1;
+            EMPTY
+        };
+
+        self.set_ctrl(index, ctrl);
+    }
+
     /// Insert the given zero copy value into the table.
     pub(crate) fn insert(&mut self, hash: u64, value: &T) -> Result<Bucket<'_, T>, Error>
     where
@@ -206,79 +273,40 @@ where
     /// `group.match_empty_or_deleted().lowest_set_bit()` after masking
     /// (`(probe_seq.pos + bit) & self.bucket_mask`) may point to a full bucket
     /// that is already occupied. We detect this situation here and perform a
-    /// second scan starting at the beginning of the table. This second scan is
-    /// guaranteed to find an empty slot (due to the load factor) before hitting
-    /// the trailing control bytes (containing [`EMPTY`] bytes).
+    /// second scan starting at the beginning of the table. Due to the load
+    /// factor, this second scan finds an empty slot in any table which has
+    /// room left before hitting the trailing control bytes.
     ///
-    /// If this function is called correctly, it is guaranteed to return
-    /// [`InsertSlot`] with an index of an empty or deleted bucket in the range
-    /// `0..self.buckets()` (see `Warning` and `Safety`).
+    /// The returned [`InsertSlot`] always has the index of an empty or deleted
+    /// bucket in the range `0..self.buckets()`.
     ///
     /// [`EMPTY`]: super::raw::EMPTY
     ///
-    /// # Warning
-    ///
-    /// The table must have at least 1 empty or deleted `bucket`, otherwise if
-    /// the table is less than the group width (`self.buckets() < Group::WIDTH`)
-    /// this function returns an index outside of the table indices range
-    /// `0..self.buckets()` (`0..=self.bucket_mask`). Attempt to write data at
-    /// that index will cause immediate [`undefined behavior`].
-    ///
-    /// # Safety
-    ///
-    /// The safety rules are directly derived from the safety rules for
-    /// [`Constructor::ctrl`] method. Thus, in order to uphold those safety
-    /// contracts, as well as for the correct logic of the work of this crate,
-    /// the following rules are necessary and sufficient:
-    ///
-    /// * The [`Constructor`] must have properly initialized control bytes
-    ///   otherwise calling this function results in [`undefined behavior`].
-    ///
-    /// * This function must only be used on insertion slots found by
-    ///   [`find_insert_slot_in_group`] (after the `find_insert_slot_in_group`
-    ///   function, but before insertion into the table).
+    /// # Panics
     ///
-    /// * The `index` must not be greater than the `self.bucket_mask`, i.e.
-    ///   `(index + 1) <= self.buckets()` (this one is provided by the
-    ///   [`find_insert_slot_in_group`] function).
-    ///
-    /// Calling this function with an index not provided by
-    /// [`find_insert_slot_in_group`] may result in [`undefined behavior`] even
-    /// if the index satisfies the safety rules of the [`ctrl`] function (`index
-    /// < self.bucket_mask + 1 + Group::WIDTH`).
-    ///
-    /// [`ctrl`]: Self::ctrl
-    /// [`find_insert_slot_in_group`]: Self::find_insert_slot_in_group
-    /// [`undefined behavior`]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    /// Panics if the second scan doesn't find an empty or deleted bucket
+    /// inside of the table. This only happens if the control bytes have been
+    /// modified since the table was constructed so that every bucket is full.
     #[inline]
     fn fix_insert_slot(&mut self, mut index: usize) -> InsertSlot {
-        // SAFETY: The caller of this function ensures that `index` is in the range `0..=self.bucket_mask`.
         if unlikely(self.is_bucket_full(index)) {
             debug_assert!(self.bucket_mask < Group::WIDTH);
 
-            // SAFETY:
-            //
-            // * Since the caller of this function ensures that the control bytes are properly
-            //   initialized and `ptr = self.ctrl(0)` points to the start of the array of control
-            //   bytes, therefore: `ctrl` is valid for reads, properly aligned to `Group::WIDTH`
-            //   and points to the properly initialized control bytes (see also
-            //   `TableLayout::calculate_layout_for` and `ptr::read`);
+            // NB: Tables wrapped through `with_growth_left` live in a buffer
+            // which might have been modified since they were constructed, so
+            // the control bytes are neither assumed to be aligned nor to
+            // contain an empty or deleted bucket.
             //
-            // * Because the caller of this function ensures that the index was provided by the
-            //   `self.find_insert_slot_in_group()` function, so for for tables larger than the
-            //   group width (self.buckets() >= Group::WIDTH), we will never end up in the given
-            //   branch, since `(probe_seq.pos + bit) & self.bucket_mask` in `find_insert_slot_in_group`
-            //   cannot return a full bucket index. For tables smaller than the group width, calling
-            //   the `unwrap_unchecked` function is also safe, as the trailing control bytes outside
-            //   the range of the table are filled with EMPTY bytes (and we know for sure that there
-            //   is at least one FULL bucket), so this second scan either finds an empty slot (due to
-            //   the load factor) or hits the trailing control bytes (containing EMPTY).
-            index = unsafe {
-                Group::load_aligned(self.ctrl_group(0).as_ptr())
-                    .match_empty_or_deleted()
-                    .lowest_set_bit()
-                    .unwrap_unchecked()
+            // SAFETY: `ctrl_group` returns a full group of control bytes.
+            let group = unsafe { Group::load(self.ctrl_group(0).as_ptr()) };
+
+            let bit = group.match_empty_or_deleted().lowest_set_bit();
+
+            let Some(bit) = bit.filter(|&bit| bit <= self.bucket_mask) else {
+                panic!("Missing empty or deleted bucket in table");
             };
+
+            index = bit;
         }
 
         InsertSlot { index }
@@ -290,9 +318,9 @@ where
     /// This function does not make any changes to the `data` parts of the table,
     /// or any changes to the the `items` or `growth_left` field of the table.
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// The caller must ensure that the `index` is not out of bounds of the control allocation.
+    /// Panics if `index` is out of bounds of the control allocation.
     #[inline]
     fn set_ctrl_h2(&mut self, index: usize, hash: u64) {
         self.set_ctrl(index, h2(hash));
@@ -304,10 +332,9 @@ where
     /// This function does not make any changes to the `data` parts of the table,
     /// or any changes to the the `items` or `growth_left` field of the table.
     ///
-    /// # Safety
+    /// # Panics
     ///
-    /// The caller must ensure that `index` is not out of bounds of the control
-    /// allocation.
+    /// Panics if `index` is out of bounds of the control allocation.
     #[inline]
     fn set_ctrl(&mut self, index: usize, ctrl: u8) {
         // Replicate the first Group::WIDTH control bytes at the end of
@@ -336,17 +363,18 @@ where
         // because the number of buckets is a power of two, and `self.bucket_mask = self.buckets() - 1`.
         let index2 = ((index.wrapping_sub(Group::WIDTH)) & self.bucket_mask) + Group::WIDTH;
 
-        // SAFETY: The caller must uphold the safety rules for the
-        // `Constructor::set_ctrl`.
         *self.ctrl_mut(index) = ctrl;
         *self.ctrl_mut(index2) = ctrl;
     }
 
     /// Checks whether the bucket at `index` is full.
     ///
-    /// # Safety
+    /// The `index` is expected to be less than the number of buckets, which is
+    /// checked in debug builds.
+    ///
+    /// # Panics
     ///
-    /// The caller must ensure `index` is less than the number of buckets.
+    /// Panics if `index` is out of bounds of the control allocation.
     #[inline]
     fn is_bucket_full(&self, index: usize) -> bool {
         debug_assert!(index < self.buckets());
@@ -448,7 +476,7 @@ pub struct InsertSlot {
 /// Returns the maximum effective capacity for the given bucket mask, taking
 /// the maximum load factor into account.
 #[inline]
-fn bucket_mask_to_capacity(bucket_mask: usize) -> usize {
+pub(crate) fn bucket_mask_to_capacity(bucket_mask: usize) -> usize {
     if bucket_mask < 8 {
         // For tables with 1/2/4/8 buckets, we always reserve one empty slot.
         // Keep in mind that the bucket mask is one less than the bucket count.
@@ -1,7 +1,7 @@
 use core::hash::{Hash, Hasher};
 use core::mem::size_of;
 
-use crate::buf::{Buf, OwnedBuf, StoreBuf, Visit};
+use crate::buf::{Buf, StoreBuf, Visit};
 use crate::endian::ByteOrder;
 use crate::error::Error;
 use crate::pointer::{Ref, Size};
@@ -12,7 +12,7 @@ use crate::swiss::raw::{self};
 use crate::swiss::{Entry, MapRef, SetRef};
 use crate::ZeroCopy;
 
-const FIXED_SEED: u64 = 1234567890;
+pub(crate) const FIXED_SEED: u64 = 1234567890;
 
 /// Store a [SwissTable] map into an [`OwnedBuf`].
 ///
@@ -22,6 +22,7 @@ const FIXED_SEED: u64 = 1234567890;
 /// See the [module level documentation] for more information.
 ///
 /// [`bind()`]: crate::buf::Buf::bind
+/// [`OwnedBuf`]: crate::buf::OwnedBuf
 /// [`Map`]: crate::swiss::Map
 /// [SwissTable]: https://abseil.io/about/design/swisstables
 /// [module level documentation]: crate::swiss
@@ -104,10 +105,7 @@ const FIXED_SEED: u64 = 1234567890;
 /// assert_eq!(map.get(&())?, Some(&()));
 /// # Ok::<_, musli_zerocopy::Error>(())
 /// ```
-pub fn store_map<K, V, I, E, O>(
-    buf: &mut OwnedBuf<E, O>,
-    entries: I,
-) -> Result<MapRef<K, V, E, O>, Error>
+pub fn store_map<K, V, I, E, O, S>(buf: &mut S, entries: I) -> Result<MapRef<K, V, E, O>, Error>
 where
     K: Visit + ZeroCopy,
     V: ZeroCopy,
@@ -116,6 +114,7 @@ where
     I::IntoIter: ExactSizeIterator,
     E: ByteOrder,
     O: Size,
+    S: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
 {
     let (key, ctrl, buckets, bucket_mask, len) = store_raw(entries, buf, |buf, (k, v), hasher| {
         k.visit(buf, |key| key.hash(hasher))?;
@@ -136,6 +135,7 @@ where
 /// See the [module level documentation] for more information.
 ///
 /// [`bind()`]: crate::buf::Buf::bind
+/// [`OwnedBuf`]: crate::buf::OwnedBuf
 /// [`Set`]: crate::swiss::Set
 /// [SwissTable]: https://abseil.io/about/design/swisstables
 /// [module level documentation]: crate::swiss
@@ -248,23 +248,9 @@ where
         panic!("Capacity overflow");
     };
 
-    let ctrl_len = buckets + size_of::<raw::Group>();
-    let ctrl_align = raw::Group::WIDTH;
-
-    debug_assert!(ctrl_align.is_power_of_two());
-
-    buf.next_offset_with_and_reserve(ctrl_align, ctrl_len);
-    let ctrl_ptr = buf.len();
-
-    // All ones indicates that the table is empty, since the ctrl byte for empty
-    // buckets is 1111_1111.
-    buf.fill(raw::EMPTY, ctrl_len + size_of::<raw::Group>());
-
-    let base_ptr = buf.next_offset::<U>();
-    buf.fill(0, size_of::<T>().wrapping_mul(buckets));
+    let (ctrl_ptr, ctrl_len, base_ptr) = alloc_raw::<U, _>(buf, buckets);
 
     let (bucket_mask, len) = {
-        buf.align_in_place();
         let mut table = Constructor::<U, _>::with_buf(buf, ctrl_ptr, base_ptr, buckets);
 
         for v in entries {
@@ -281,3 +267,29 @@ where
     let buckets = Ref::with_metadata(base_ptr, buckets);
     Ok((key, ctrl, buckets, bucket_mask, len))
 }
+
+/// Allocate an empty table with the given number of buckets at the end of the
+/// buffer, returning the offset and length of its control bytes, and the offset
+/// of its buckets.
+pub(crate) fn alloc_raw<U, S>(buf: &mut S, buckets: usize) -> (usize, usize, usize)
+where
+    U: ZeroCopy,
+    S: ?Sized + StoreBuf,
+{
+    let ctrl_len = buckets + size_of::<raw::Group>();
+    let ctrl_align = raw::Group::WIDTH;
+
+    debug_assert!(ctrl_align.is_power_of_two());
+
+    buf.next_offset_with_and_reserve(ctrl_align, ctrl_len);
+    let ctrl_ptr = buf.len();
+
+    // All ones indicates that the table is empty, since the ctrl byte for empty
+    // buckets is 1111_1111.
+    buf.fill(raw::EMPTY, ctrl_len + size_of::<raw::Group>());
+
+    let base_ptr = buf.next_offset::<U>();
+    buf.fill(0, size_of::<U>().wrapping_mul(buckets));
+    buf.align_in_place();
+    (ctrl_ptr, ctrl_len, base_ptr)
+}
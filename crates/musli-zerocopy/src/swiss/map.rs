@@ -10,19 +10,31 @@
 //! * [`MapRef<K, V>`] which is the *pointer* of the map. This is what you store
 //!   in [`ZeroCopy`] types and is what is returned by [`swiss::store_map`].
 //!
+//! A stored map can also be modified in place through [`MapMut<K, V>`].
+//!
 //! [`swiss::store_map`]: crate::swiss::store_map
 
 use core::borrow::Borrow;
 use core::convert::identity as likely;
 use core::hash::{Hash, Hasher};
+#[cfg(feature = "alloc")]
+use core::mem;
 use core::mem::size_of;
 
+#[cfg(feature = "alloc")]
+use crate::buf::StoreBuf;
 use crate::buf::{Bindable, Buf, Visit};
 use crate::endian::{ByteOrder, Native};
 use crate::error::{Error, ErrorKind};
 use crate::pointer::{DefaultSize, Ref, Size};
 use crate::sip::SipHasher13;
-use crate::swiss::raw::{h2, probe_seq, Group};
+#[cfg(feature = "alloc")]
+use crate::swiss::constructor::{bucket_mask_to_capacity, Constructor};
+#[cfg(feature = "alloc")]
+use crate::swiss::factory::{alloc_raw, FIXED_SEED};
+#[cfg(feature = "alloc")]
+use crate::swiss::raw::{self, is_full, EMPTY};
+use crate::swiss::raw::{h2, probe_seq, BitMaskIter, Group};
 use crate::swiss::Entry;
 use crate::{Endian, ZeroCopy};
 
@@ -180,6 +192,80 @@ where
         value.hash(&mut hasher);
         hasher.finish()
     }
+
+    /// Iterate over the entries of the map.
+    ///
+    /// Empty buckets are skipped one group of control bytes at a time, and
+    /// entries are produced in bucket order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let mut entries = map.iter().collect::<Vec<_>>();
+    /// entries.sort();
+    /// assert_eq!(entries, [(&1, &2), (&2, &3)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, K, V> {
+        Iter {
+            iter: self.table.iter(),
+        }
+    }
+
+    /// Iterate over the keys of the map in bucket order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let mut keys = map.keys().collect::<Vec<_>>();
+    /// keys.sort();
+    /// assert_eq!(keys, [&1, &2]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn keys(&self) -> Keys<'a, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// Iterate over the values of the map in bucket order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let map = buf.bind(map)?;
+    ///
+    /// let mut values = map.values().collect::<Vec<_>>();
+    /// values.sort();
+    /// assert_eq!(values, [&2, &3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn values(&self) -> Values<'a, K, V> {
+        Values { iter: self.iter() }
+    }
 }
 
 /// Bind a [`MapRef`] into a [`Map`].
@@ -380,6 +466,82 @@ where
         value.hash(&mut hasher);
         hasher.finish()
     }
+
+    /// Iterate over the entries of the map.
+    ///
+    /// Empty buckets are skipped one group of control bytes at a time, and
+    /// each entry is validated as it is loaded from the buffer. Iteration
+    /// stops producing values after the first error.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// let mut entries = map.iter(&buf).collect::<Result<Vec<_>, _>>()?;
+    /// entries.sort();
+    /// assert_eq!(entries, [(&1, &2), (&2, &3)]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter<'buf>(&self, buf: &'buf Buf) -> RefIter<'buf, K, V, E, O> {
+        RefIter {
+            iter: self.table.iter(buf),
+        }
+    }
+
+    /// Iterate over the keys of the map in bucket order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// let mut keys = map.keys(&buf).collect::<Result<Vec<_>, _>>()?;
+    /// keys.sort();
+    /// assert_eq!(keys, [&1, &2]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn keys<'buf>(&self, buf: &'buf Buf) -> RefKeys<'buf, K, V, E, O> {
+        RefKeys {
+            iter: self.iter(buf),
+        }
+    }
+
+    /// Iterate over the values of the map in bucket order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    ///
+    /// let mut values = map.values(&buf).collect::<Result<Vec<_>, _>>()?;
+    /// values.sort();
+    /// assert_eq!(values, [&2, &3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn values<'buf>(&self, buf: &'buf Buf) -> RefValues<'buf, K, V, E, O> {
+        RefValues {
+            iter: self.iter(buf),
+        }
+    }
 }
 
 impl<K, V, E, O> Clone for MapRef<K, V, E, O>
@@ -403,91 +565,605 @@ where
 {
 }
 
-pub(crate) struct RawTable<'a, T> {
-    ctrl: &'a [u8],
-    entries: &'a [T],
-    bucket_mask: usize,
-    len: usize,
+/// A map stored in a buffer which can be modified in place.
+///
+/// Entries are inserted into and removed from the table stored in the buffer,
+/// so the map can still be read through [`MapRef`] and [`Map`] as if it was
+/// constructed through [`swiss::store_map`]. Removed entries leave tombstones
+/// behind in the table, which are cleared out the next time it is resized.
+///
+/// Once the table runs out of capacity a larger one is stored at the end of
+/// the buffer and every entry is rehashed into it. The space used by the
+/// previous table is not reclaimed, and since the table moves the current
+/// [`MapRef`] has to be retrieved through [`MapMut::to_ref`] after the map has
+/// been modified.
+///
+/// [`swiss::store_map`]: crate::swiss::store_map
+///
+/// ## Examples
+///
+/// ```
+/// use musli_zerocopy::OwnedBuf;
+/// use musli_zerocopy::swiss::{self, MapMut};
+///
+/// let mut buf = OwnedBuf::new();
+///
+/// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+/// let mut map = MapMut::new(&buf, map)?;
+///
+/// assert_eq!(map.insert(&mut buf, 3, 4)?, None);
+/// assert_eq!(map.insert(&mut buf, 1, 5)?, Some(2));
+/// assert_eq!(map.remove(&mut buf, &2)?, Some(3));
+///
+/// let map = buf.bind(map.to_ref())?;
+///
+/// assert_eq!(map.get(&1)?, Some(&5));
+/// assert_eq!(map.get(&2)?, None);
+/// assert_eq!(map.get(&3)?, Some(&4));
+/// assert_eq!(map.len(), 2);
+/// # Ok::<_, musli_zerocopy::Error>(())
+/// ```
+#[cfg(feature = "alloc")]
+pub struct MapMut<K, V, E = Native, O = DefaultSize>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    map: MapRef<K, V, E, O>,
+    // Number of elements that can be inserted before the table has to grow.
+    growth_left: usize,
 }
 
-impl<'a, T> RawTable<'a, T> {
-    /// Searches for an element in the table.
-    #[inline]
-    pub(crate) fn find(
-        &self,
-        hash: u64,
-        mut eq: impl FnMut(&T) -> Result<bool, Error>,
-    ) -> Result<Option<&'a T>, Error> {
-        let result = self.find_inner(hash, &mut |index| {
-            let entry = self.entry(index)?;
-            eq(entry)
-        })?;
+#[cfg(feature = "alloc")]
+impl<K, V, E, O> MapMut<K, V, E, O>
+where
+    K: ZeroCopy,
+    V: ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    /// Store an empty map which can hold at least `capacity` entries before it
+    /// has to grow.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss::MapMut;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let mut map = MapMut::<u32, u32>::with_capacity(&mut buf, 10);
+    /// assert!(map.is_empty());
+    /// assert!(map.capacity() >= 10);
+    ///
+    /// for n in 0..100 {
+    ///     map.insert(&mut buf, n, n * 2)?;
+    /// }
+    ///
+    /// assert_eq!(map.len(), 100);
+    /// assert_eq!(map.get(&buf, &42u32)?, Some(&84));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn with_capacity<S>(buf: &mut S, capacity: usize) -> Self
+    where
+        S: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
+    {
+        let Some(buckets) = raw::capacity_to_buckets(capacity) else {
+            panic!("Capacity overflow");
+        };
 
-        Ok(match result {
-            Some(index) => Some(self.entry(index)?),
-            None => None,
-        })
-    }
+        let (ctrl_ptr, ctrl_len, base_ptr) = alloc_raw::<Entry<K, V>, _>(buf, buckets);
 
-    fn entry(&self, index: usize) -> Result<&'a T, Error> {
-        let Some(entry) = self.entries.get(index) else {
-            return Err(Error::new(ErrorKind::IndexOutOfBounds {
-                index,
-                len: self.entries.len(),
-            }));
-        };
+        let table = RawTableRef::new(
+            Ref::with_metadata(ctrl_ptr, ctrl_len),
+            Ref::with_metadata(base_ptr, buckets),
+            buckets - 1,
+            0,
+        );
 
-        Ok(entry)
+        Self {
+            map: MapRef::new(FIXED_SEED, table),
+            growth_left: bucket_mask_to_capacity(buckets - 1),
+        }
     }
 
-    /// Searches for an element in a table, returning the `index` of the found
-    /// element.
-    #[inline(always)]
-    fn find_inner(
-        &self,
-        hash: u64,
-        eq: &mut dyn FnMut(usize) -> Result<bool, Error>,
-    ) -> Result<Option<usize>, Error> {
-        let h2_hash = h2(hash);
-        let mut probe_seq = probe_seq(self.bucket_mask, hash);
+    /// Modify the map referenced by `map` which is stored in `buf`.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the table referenced by `map` is not correctly stored in
+    /// `buf`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss::{self, MapMut};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let mut map = MapMut::new(&buf, map)?;
+    ///
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.get(&buf, &1)?, Some(&2));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn new(buf: &Buf, map: MapRef<K, V, E, O>) -> Result<Self, Error> {
+        let buckets = map.table.entries.len();
+        let bucket_mask = map.table.bucket_mask.to_ne();
 
-        loop {
-            let range = probe_seq.pos..probe_seq.pos + size_of::<Group>();
+        if !buckets.is_power_of_two() || bucket_mask != buckets - 1 {
+            return Err(Error::new(ErrorKind::BucketMaskMismatch {
+                bucket_mask,
+                buckets,
+            }));
+        }
 
-            let Some(bytes) = self.ctrl.get(range.clone()) else {
-                return Err(Error::new(ErrorKind::ControlRangeOutOfBounds {
-                    range,
-                    len: self.ctrl.len(),
-                }));
-            };
+        buf.load(map.table.entries)?;
+        let ctrl = buf.load(map.table.ctrl)?;
 
-            // SAFETY: We've made sure to provide this load with a buffer of the
-            // appropriate size.
-            let group = unsafe { Group::load(bytes.as_ptr()) };
+        // Every bucket must be followed by a full group of control bytes,
+        // since that is what is accessed when probing the table.
+        let end = buckets + size_of::<Group>();
 
-            for bit in group.match_byte(h2_hash) {
-                // This is the same as `(probe_seq.pos + bit) % self.buckets()` because the number
-                // of buckets is a power of two, and `self.bucket_mask = self.buckets() - 1`.
-                let index = (probe_seq.pos + bit) & self.bucket_mask;
+        if ctrl.len() < end {
+            return Err(Error::new(ErrorKind::ControlRangeOutOfBounds {
+                range: 0..end,
+                len: ctrl.len(),
+            }));
+        }
 
-                if likely(eq(index)?) {
-                    return Ok(Some(index));
-                }
-            }
+        // Both full and deleted buckets use up capacity.
+        let used = ctrl[..buckets].iter().filter(|&&c| c != EMPTY).count();
 
-            if likely(group.match_empty().any_bit_set()) {
-                return Ok(None);
-            }
+        Ok(Self {
+            map,
+            growth_left: bucket_mask_to_capacity(bucket_mask).saturating_sub(used),
+        })
+    }
 
-            probe_seq.move_next(self.bucket_mask)?;
-        }
+    /// Get the [`MapRef`] to the current table of the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss::MapMut;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let mut map = MapMut::with_capacity(&mut buf, 0);
+    /// map.insert(&mut buf, 1u32, 2u32)?;
+    ///
+    /// let map = map.to_ref();
+    /// assert_eq!(map.get(&buf, &1u32)?, Some(&2));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn to_ref(&self) -> MapRef<K, V, E, O> {
+        self.map
     }
-}
 
-#[derive(Debug, ZeroCopy)]
-#[repr(C)]
-#[zero_copy(crate)]
-pub(crate) struct RawTableRef<T, E = Native, O = DefaultSize>
+    /// Get a value from the map.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss::MapMut;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let mut map = MapMut::with_capacity(&mut buf, 0);
+    /// map.insert(&mut buf, 1, 2)?;
+    ///
+    /// assert_eq!(map.get(&buf, &1)?, Some(&2));
+    /// assert_eq!(map.get(&buf, &2)?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn get<'a, Q>(&self, buf: &'a Buf, key: &Q) -> Result<Option<&'a V>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Eq + Hash,
+        K: 'a + Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        self.map.get(buf, key)
+    }
+
+    /// Test if the map contains the given `key`.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss::MapMut;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let mut map = MapMut::with_capacity(&mut buf, 0);
+    /// map.insert(&mut buf, 1, 2)?;
+    ///
+    /// assert!(map.contains_key(&buf, &1)?);
+    /// assert!(!map.contains_key(&buf, &2)?);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn contains_key<Q>(&self, buf: &Buf, key: &Q) -> Result<bool, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Eq + Hash,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        self.map.contains_key(buf, key)
+    }
+
+    /// Get the length of the map.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Test if the map is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// The number of entries the map can hold before the table has to grow.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.len() + self.growth_left
+    }
+
+    /// Insert a value into the map, returning the previous value associated
+    /// with `key` if there was one.
+    ///
+    /// If the table is out of capacity, a larger table is stored at the end of
+    /// `buf` and every entry is rehashed into it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the table needs to grow and the buffer cannot hold the larger
+    /// table, as can be the case with a [`SliceMut`].
+    ///
+    /// [`SliceMut`]: crate::SliceMut
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss::MapMut;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let first = buf.store_unsized("first");
+    /// let second = buf.store_unsized("second");
+    ///
+    /// let mut map = MapMut::with_capacity(&mut buf, 0);
+    ///
+    /// assert_eq!(map.insert(&mut buf, first, 1u32)?, None);
+    /// assert_eq!(map.insert(&mut buf, second, 2u32)?, None);
+    /// assert_eq!(map.insert(&mut buf, first, 3u32)?, Some(1));
+    ///
+    /// let map = buf.bind(map.to_ref())?;
+    /// assert_eq!(map.get("first")?, Some(&3));
+    /// assert_eq!(map.get("second")?, Some(&2));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn insert<S>(&mut self, buf: &mut S, key: K, value: V) -> Result<Option<V>, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
+        K: Visit,
+        K::Target: Eq + Hash,
+    {
+        let hash = key.visit(buf.as_buf(), |k| self.map.hash(k))?;
+
+        if let Some(index) = self.find(buf.as_buf(), hash, &key)? {
+            let entry = buf
+                .as_mut_buf()
+                .load_mut(self.map.table.entry_ref(index)?)?;
+            return Ok(Some(mem::replace(&mut entry.value, value)));
+        }
+
+        if self.growth_left == 0 {
+            self.reserve(buf, 1)?;
+        }
+
+        let mut table = self.constructor(buf);
+        table.insert(hash, &Entry::new(key, value))?;
+        self.growth_left = table.growth_left();
+        self.map.table.len = Endian::new(self.map.table.len() + 1);
+        Ok(None)
+    }
+
+    /// Remove a key from the map, returning the value associated with it if
+    /// it was present.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss::{self, MapMut};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let mut map = MapMut::new(&buf, map)?;
+    ///
+    /// assert_eq!(map.remove(&mut buf, &1)?, Some(2));
+    /// assert_eq!(map.remove(&mut buf, &1)?, None);
+    /// assert_eq!(map.len(), 1);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn remove<S, Q>(&mut self, buf: &mut S, key: &Q) -> Result<Option<V>, Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
+        Q: ?Sized + Visit,
+        Q::Target: Eq + Hash,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        let hash = key.visit(buf.as_buf(), |k| self.map.hash(k))?;
+
+        let Some(index) = self.find(buf.as_buf(), hash, key)? else {
+            return Ok(None);
+        };
+
+        let entry = self.map.table.entry_ref(index)?;
+        let entry = buf
+            .as_buf()
+            .load_sized_unaligned::<Entry<K, V>>(entry.offset())?;
+
+        let mut table = self.constructor(buf);
+        table.erase(index);
+        self.growth_left = table.growth_left();
+        self.map.table.len = Endian::new(self.map.table.len().saturating_sub(1));
+        Ok(Some(entry.value))
+    }
+
+    /// Reserve capacity for at least `additional` more entries.
+    ///
+    /// If the table needs to grow, a larger table is stored at the end of
+    /// `buf` and every entry is rehashed into it. If enough entries have been
+    /// removed, a table of the same size is stored instead to clear out any
+    /// tombstones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer cannot hold the larger table, as can be the case
+    /// with a [`SliceMut`].
+    ///
+    /// [`SliceMut`]: crate::SliceMut
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss::{self, MapMut};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let map = swiss::store_map(&mut buf, [(1, 2), (2, 3)])?;
+    /// let mut map = MapMut::new(&buf, map)?;
+    ///
+    /// map.reserve(&mut buf, 100)?;
+    /// assert!(map.capacity() >= 102);
+    ///
+    /// let len = buf.len();
+    ///
+    /// for n in 3..103 {
+    ///     map.insert(&mut buf, n, n + 1)?;
+    /// }
+    ///
+    /// assert_eq!(buf.len(), len);
+    /// assert_eq!(map.get(&buf, &1)?, Some(&2));
+    /// assert_eq!(map.get(&buf, &102)?, Some(&103));
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn reserve<S>(&mut self, buf: &mut S, additional: usize) -> Result<(), Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
+        K: Visit,
+        K::Target: Hash,
+    {
+        if additional <= self.growth_left {
+            return Ok(());
+        }
+
+        let Some(new_items) = self.len().checked_add(additional) else {
+            panic!("Capacity overflow");
+        };
+
+        let full_capacity = bucket_mask_to_capacity(self.map.table.bucket_mask.to_ne());
+
+        let capacity = if new_items <= full_capacity / 2 {
+            full_capacity
+        } else {
+            new_items.max(full_capacity + 1)
+        };
+
+        let Some(buckets) = raw::capacity_to_buckets(capacity) else {
+            panic!("Capacity overflow");
+        };
+
+        self.resize(buf, buckets)
+    }
+
+    /// Store a new table with the given number of buckets and rehash every
+    /// entry into it.
+    fn resize<S>(&mut self, buf: &mut S, buckets: usize) -> Result<(), Error>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
+        K: Visit,
+        K::Target: Hash,
+    {
+        let map = self.map;
+        let (ctrl_ptr, ctrl_len, base_ptr) = alloc_raw::<Entry<K, V>, _>(buf, buckets);
+        let mut table = Constructor::<Entry<K, V>, _>::with_buf(buf, ctrl_ptr, base_ptr, buckets);
+
+        for index in 0..map.table.entries.len() {
+            let buf = table.buf();
+
+            if !matches!(buf.load(map.table.ctrl)?.get(index), Some(&c) if is_full(c)) {
+                continue;
+            }
+
+            let entry = map.table.entry_ref(index)?;
+            let entry = buf.load_sized_unaligned::<Entry<K, V>>(entry.offset())?;
+            let hash = entry.key.visit(buf, |k| map.hash(k))?;
+            table.insert(hash, &entry)?;
+        }
+
+        self.growth_left = table.growth_left();
+
+        self.map.table = RawTableRef::new(
+            Ref::with_metadata(ctrl_ptr, ctrl_len),
+            Ref::with_metadata(base_ptr, buckets),
+            buckets - 1,
+            map.table.len(),
+        );
+
+        Ok(())
+    }
+
+    /// Find the index of the bucket containing `key`.
+    fn find<Q>(&self, buf: &Buf, hash: u64, key: &Q) -> Result<Option<usize>, Error>
+    where
+        Q: ?Sized + Visit,
+        Q::Target: Eq,
+        K: Visit,
+        K::Target: Borrow<Q::Target>,
+    {
+        self.map.table.find_inner(buf, hash, &mut |index| {
+            let e = self.map.table.entry(index, buf)?;
+            key.visit(buf, |b| e.key.visit(buf, |a| a.borrow() == b))?
+        })
+    }
+
+    /// Construct a raw table over the current table in the buffer.
+    fn constructor<'a, S>(&self, buf: &'a mut S) -> Constructor<'a, Entry<K, V>, S>
+    where
+        S: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
+    {
+        Constructor::with_growth_left(
+            buf,
+            self.map.table.ctrl.offset(),
+            self.map.table.entries.offset(),
+            self.map.table.entries.len(),
+            self.growth_left,
+        )
+    }
+}
+
+pub(crate) struct RawTable<'a, T> {
+    ctrl: &'a [u8],
+    entries: &'a [T],
+    bucket_mask: usize,
+    len: usize,
+}
+
+impl<'a, T> RawTable<'a, T> {
+    /// The number of occupied buckets in the table.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Iterate over the occupied buckets in the table.
+    #[inline]
+    pub(crate) fn iter(&self) -> RawIter<'a, T> {
+        RawIter {
+            ctrl: self.ctrl,
+            entries: self.entries,
+            pos: 0,
+            group: None,
+        }
+    }
+
+    /// Searches for an element in the table.
+    #[inline]
+    pub(crate) fn find(
+        &self,
+        hash: u64,
+        mut eq: impl FnMut(&T) -> Result<bool, Error>,
+    ) -> Result<Option<&'a T>, Error> {
+        let result = self.find_inner(hash, &mut |index| {
+            let entry = self.entry(index)?;
+            eq(entry)
+        })?;
+
+        Ok(match result {
+            Some(index) => Some(self.entry(index)?),
+            None => None,
+        })
+    }
+
+    fn entry(&self, index: usize) -> Result<&'a T, Error> {
+        let Some(entry) = self.entries.get(index) else {
+            return Err(Error::new(ErrorKind::IndexOutOfBounds {
+                index,
+                len: self.entries.len(),
+            }));
+        };
+
+        Ok(entry)
+    }
+
+    /// Searches for an element in a table, returning the `index` of the found
+    /// element.
+    #[inline(always)]
+    fn find_inner(
+        &self,
+        hash: u64,
+        eq: &mut dyn FnMut(usize) -> Result<bool, Error>,
+    ) -> Result<Option<usize>, Error> {
+        let h2_hash = h2(hash);
+        let mut probe_seq = probe_seq(self.bucket_mask, hash);
+
+        loop {
+            let range = probe_seq.pos..probe_seq.pos + size_of::<Group>();
+
+            let Some(bytes) = self.ctrl.get(range.clone()) else {
+                return Err(Error::new(ErrorKind::ControlRangeOutOfBounds {
+                    range,
+                    len: self.ctrl.len(),
+                }));
+            };
+
+            // SAFETY: We've made sure to provide this load with a buffer of the
+            // appropriate size.
+            let group = unsafe { Group::load(bytes.as_ptr()) };
+
+            for bit in group.match_byte(h2_hash) {
+                // This is the same as `(probe_seq.pos + bit) % self.buckets()` because the number
+                // of buckets is a power of two, and `self.bucket_mask = self.buckets() - 1`.
+                let index = (probe_seq.pos + bit) & self.bucket_mask;
+
+                if likely(eq(index)?) {
+                    return Ok(Some(index));
+                }
+            }
+
+            if likely(group.match_empty().any_bit_set()) {
+                return Ok(None);
+            }
+
+            probe_seq.move_next(self.bucket_mask)?;
+        }
+    }
+}
+
+#[derive(Debug, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+pub(crate) struct RawTableRef<T, E = Native, O = DefaultSize>
 where
     T: ZeroCopy,
     E: ByteOrder,
@@ -523,14 +1199,45 @@ where
 
     #[inline]
     pub(crate) fn bind<'buf>(&self, buf: &'buf Buf) -> Result<RawTable<'buf, T>, Error> {
+        let ctrl = buf.load(self.ctrl)?;
+        let entries = buf.load(self.entries)?;
+
+        // Every bucket must be covered by a full group of control bytes, which
+        // is what allows iteration over a bound table to be infallible.
+        let end = entries.len() + size_of::<Group>();
+
+        if ctrl.len() < end {
+            return Err(Error::new(ErrorKind::ControlRangeOutOfBounds {
+                range: 0..end,
+                len: ctrl.len(),
+            }));
+        }
+
         Ok(RawTable {
-            ctrl: buf.load(self.ctrl)?,
-            entries: buf.load(self.entries)?,
+            ctrl,
+            entries,
             bucket_mask: self.bucket_mask.to_ne(),
             len: self.len.to_ne(),
         })
     }
 
+    /// The number of occupied buckets in the table.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.len.to_ne()
+    }
+
+    /// Iterate over the occupied buckets in the table.
+    #[inline]
+    pub(crate) fn iter<'buf>(&self, buf: &'buf Buf) -> RawRefIter<'buf, T, E, O> {
+        RawRefIter {
+            table: *self,
+            buf,
+            pos: 0,
+            group: None,
+        }
+    }
+
     /// Searches for an element in the table.
     #[inline]
     pub(crate) fn find<'buf>(
@@ -548,6 +1255,10 @@ where
     }
 
     fn entry<'buf>(&self, index: usize, buf: &'buf Buf) -> Result<&'buf T, Error> {
+        buf.load(self.entry_ref(index)?)
+    }
+
+    fn entry_ref(&self, index: usize) -> Result<Ref<T, E, O>, Error> {
         let Some(entry) = self.entries.get(index) else {
             return Err(Error::new(ErrorKind::IndexOutOfBounds {
                 index,
@@ -555,7 +1266,7 @@ where
             }));
         };
 
-        buf.load(entry)
+        Ok(entry)
     }
 
     /// Searches for an element in a table, returning the `index` of the found
@@ -621,3 +1332,269 @@ where
     O: Size,
 {
 }
+
+/// Iterator over the occupied buckets of a bound [`RawTable`].
+pub(crate) struct RawIter<'a, T> {
+    ctrl: &'a [u8],
+    entries: &'a [T],
+    pos: usize,
+    group: Option<(usize, BitMaskIter)>,
+}
+
+impl<'a, T> Iterator for RawIter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((base, bits)) = &mut self.group {
+                if let Some(bit) = bits.next() {
+                    // Tables smaller than a group have trailing control bytes
+                    // which do not correspond to any bucket.
+                    match self.entries.get(*base + bit) {
+                        Some(entry) => return Some(entry),
+                        None => continue,
+                    }
+                }
+            }
+
+            if self.pos >= self.entries.len() {
+                return None;
+            }
+
+            // Bounds have been checked when the table was bound.
+            let bytes = self.ctrl.get(self.pos..self.pos + size_of::<Group>())?;
+
+            // SAFETY: We've made sure to provide this load with a buffer of the
+            // appropriate size.
+            let group = unsafe { Group::load(bytes.as_ptr()) };
+            self.group = Some((self.pos, group.match_full().into_iter()));
+            self.pos += size_of::<Group>();
+        }
+    }
+}
+
+/// Iterator over the occupied buckets of a [`RawTableRef`], which loads
+/// control bytes and entries lazily from the buffer.
+pub(crate) struct RawRefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    table: RawTableRef<T, E, O>,
+    buf: &'buf Buf,
+    pos: usize,
+    group: Option<(usize, BitMaskIter)>,
+}
+
+impl<'buf, T, E, O> RawRefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    fn load_group(&self) -> Result<BitMaskIter, Error> {
+        let ctrl = self.buf.load(self.table.ctrl)?;
+        let range = self.pos..self.pos + size_of::<Group>();
+
+        let Some(bytes) = ctrl.get(range.clone()) else {
+            return Err(Error::new(ErrorKind::ControlRangeOutOfBounds {
+                range,
+                len: ctrl.len(),
+            }));
+        };
+
+        // SAFETY: We've made sure to provide this load with a buffer of the
+        // appropriate size.
+        let group = unsafe { Group::load(bytes.as_ptr()) };
+        Ok(group.match_full().into_iter())
+    }
+}
+
+impl<'buf, T, E, O> Iterator for RawRefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<&'buf T, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let buckets = self.table.entries.len();
+
+        loop {
+            if let Some((base, bits)) = &mut self.group {
+                if let Some(bit) = bits.next() {
+                    let index = *base + bit;
+
+                    // Tables smaller than a group have trailing control bytes
+                    // which do not correspond to any bucket.
+                    if index >= buckets {
+                        continue;
+                    }
+
+                    let result = self.table.entry(index, self.buf);
+
+                    if result.is_err() {
+                        self.group = None;
+                        self.pos = buckets;
+                    }
+
+                    return Some(result);
+                }
+            }
+
+            if self.pos >= buckets {
+                return None;
+            }
+
+            match self.load_group() {
+                Ok(bits) => {
+                    self.group = Some((self.pos, bits));
+                    self.pos += size_of::<Group>();
+                }
+                Err(error) => {
+                    self.group = None;
+                    self.pos = buckets;
+                    return Some(Err(error));
+                }
+            }
+        }
+    }
+}
+
+/// An iterator over the entries of a [`Map`].
+///
+/// See [`Map::iter`].
+pub struct Iter<'a, K, V> {
+    iter: RawIter<'a, Entry<K, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let e = self.iter.next()?;
+        Some((&e.key, &e.value))
+    }
+}
+
+/// An iterator over the keys of a [`Map`].
+///
+/// See [`Map::keys`].
+pub struct Keys<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.0)
+    }
+}
+
+/// An iterator over the values of a [`Map`].
+///
+/// See [`Map::values`].
+pub struct Values<'a, K, V> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.1)
+    }
+}
+
+/// An iterator over the entries of a [`MapRef`] which validates each entry as
+/// it is loaded.
+///
+/// See [`MapRef::iter`].
+pub struct RefIter<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    iter: RawRefIter<'buf, Entry<K, V>, E, O>,
+}
+
+impl<'buf, K, V, E, O> Iterator for RefIter<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<(&'buf K, &'buf V), Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.map(|e| (&e.key, &e.value)))
+    }
+}
+
+/// An iterator over the keys of a [`MapRef`].
+///
+/// See [`MapRef::keys`].
+pub struct RefKeys<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    iter: RefIter<'buf, K, V, E, O>,
+}
+
+impl<'buf, K, V, E, O> Iterator for RefKeys<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<&'buf K, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.map(|(k, _)| k))
+    }
+}
+
+/// An iterator over the values of a [`MapRef`].
+///
+/// See [`MapRef::values`].
+pub struct RefValues<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    iter: RefIter<'buf, K, V, E, O>,
+}
+
+impl<'buf, K, V, E, O> Iterator for RefValues<'buf, K, V, E, O>
+where
+    K: 'buf + ZeroCopy,
+    V: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<&'buf V, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.iter.next()?.map(|(_, v)| v))
+    }
+}
@@ -27,6 +27,9 @@ mod raw;
 pub(crate) use self::entry::Entry;
 mod entry;
 
+#[cfg(feature = "alloc")]
+#[doc(inline)]
+pub use self::map::MapMut;
 #[doc(inline)]
 pub use self::map::{Map, MapRef};
 pub mod map;
@@ -33,6 +33,7 @@ mod bitmask;
 use crate::error::Error;
 use crate::error::ErrorKind;
 
+pub(crate) use self::bitmask::BitMaskIter;
 pub(crate) use self::imp::Group;
 
 use core::mem;
@@ -114,6 +115,10 @@ pub(crate) fn h2(hash: u64) -> u8 {
 /// Control byte value for an empty bucket.
 pub(crate) const EMPTY: u8 = 0b1111_1111;
 
+/// Control byte value for a deleted bucket.
+#[cfg(feature = "alloc")]
+pub(crate) const DELETED: u8 = 0b1000_0000;
+
 /// Checks whether a control byte represents a full bucket (top bit is clear).
 #[inline]
 #[cfg(feature = "alloc")]
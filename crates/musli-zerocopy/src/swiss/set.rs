@@ -20,7 +20,7 @@ use crate::endian::{ByteOrder, Native};
 use crate::error::Error;
 use crate::pointer::{DefaultSize, Size};
 use crate::sip::SipHasher13;
-use crate::swiss::map::{RawTable, RawTableRef};
+use crate::swiss::map::{RawIter, RawRefIter, RawTable, RawTableRef};
 use crate::ZeroCopy;
 
 /// A set bound to a [`Buf`] through [`Buf::bind`] for convenience.
@@ -93,6 +93,62 @@ where
         value.hash(&mut hasher);
         hasher.finish()
     }
+
+    /// Get the number of values in the set.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = swiss::store_set(&mut buf, [1, 3])?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// assert!(!set.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Test if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.table.len() == 0
+    }
+
+    /// Iterate over the values of the set.
+    ///
+    /// Empty buckets are skipped one group of control bytes at a time, and
+    /// values are produced in bucket order.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = swiss::store_set(&mut buf, [1, 3])?;
+    /// let set = buf.bind(set)?;
+    ///
+    /// let mut values = set.iter().collect::<Vec<_>>();
+    /// values.sort();
+    /// assert_eq!(values, [&1, &3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> Iter<'a, T> {
+        Iter {
+            iter: self.table.iter(),
+        }
+    }
 }
 
 /// Bind a [`SetRef`] into a [`Set`].
@@ -205,4 +261,102 @@ where
         value.hash(&mut hasher);
         hasher.finish()
     }
+
+    /// Get the number of values in the set.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = swiss::store_set(&mut buf, [1, 3])?;
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// assert!(!set.is_empty());
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Test if the set is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.table.len() == 0
+    }
+
+    /// Iterate over the values of the set.
+    ///
+    /// Empty buckets are skipped one group of control bytes at a time, and
+    /// each value is validated as it is loaded from the buffer. Iteration
+    /// stops producing values after the first error.
+    ///
+    /// ## Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::OwnedBuf;
+    /// use musli_zerocopy::swiss;
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let set = swiss::store_set(&mut buf, [1, 3])?;
+    ///
+    /// let mut values = set.iter(&buf).collect::<Result<Vec<_>, _>>()?;
+    /// values.sort();
+    /// assert_eq!(values, [&1, &3]);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[inline]
+    pub fn iter<'buf>(&self, buf: &'buf Buf) -> RefIter<'buf, T, E, O> {
+        RefIter {
+            iter: self.table.iter(buf),
+        }
+    }
+}
+
+/// An iterator over the values of a [`Set`].
+///
+/// See [`Set::iter`].
+pub struct Iter<'a, T> {
+    iter: RawIter<'a, T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+/// An iterator over the values of a [`SetRef`] which validates each value as
+/// it is loaded.
+///
+/// See [`SetRef::iter`].
+pub struct RefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    iter: RawRefIter<'buf, T, E, O>,
+}
+
+impl<'buf, T, E, O> Iterator for RefIter<'buf, T, E, O>
+where
+    T: 'buf + ZeroCopy,
+    E: ByteOrder,
+    O: Size,
+{
+    type Item = Result<&'buf T, Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
 }
@@ -0,0 +1,122 @@
+use anyhow::Result;
+
+use crate::archive::{Archive, MAGIC};
+use crate::endian::{Big, Little};
+use crate::{Buf, OwnedBuf, Ref, SliceMut, ZeroCopy};
+
+#[derive(ZeroCopy)]
+#[zero_copy(crate)]
+#[repr(C)]
+struct Root<E = crate::endian::Native, O = crate::DefaultSize>
+where
+    E: crate::ByteOrder,
+    O: crate::Size,
+{
+    name: Ref<str, E, O>,
+    values: Ref<[u32], E, O>,
+}
+
+#[test]
+fn archive_round_trip() -> Result<()> {
+    let archive = Archive::new().with_version(7).with_checksum();
+
+    let mut buf = OwnedBuf::new();
+
+    archive.store(&mut buf, |buf| {
+        let name = buf.store_unsized("root");
+        let values = buf.store_slice(&[1, 2, 3]);
+        Ok(buf.store(&Root { name, values }))
+    })?;
+
+    let header = archive.load_header(&buf)?;
+    assert_eq!(header.magic(), MAGIC);
+    assert_eq!(header.version(), 7);
+    assert_eq!(header.len(), buf.len() as u64);
+    assert!(header.checksum().is_some());
+
+    let root = archive.load::<Root>(&buf)?;
+    assert_eq!(buf.load(root.name)?, "root");
+    assert_eq!(buf.load(root.values)?, &[1, 2, 3]);
+    Ok(())
+}
+
+#[test]
+fn archive_slice_mut() -> Result<()> {
+    let archive = Archive::new().with_checksum();
+
+    let mut bytes = [0; 256];
+    let mut buf = SliceMut::with_alignment::<u64>(&mut bytes);
+
+    archive.store(&mut buf, |buf| {
+        let name = buf.store_unsized("root");
+        let values = buf.store_slice(&[1, 2, 3]);
+        Ok(buf.store(&Root { name, values }))
+    })?;
+
+    let root = archive.load::<Root>(&buf)?;
+    assert_eq!(buf.load(root.name)?, "root");
+    assert_eq!(buf.load(root.values)?, &[1, 2, 3]);
+    Ok(())
+}
+
+#[test]
+fn archive_mismatches() -> Result<()> {
+    let archive = Archive::new().with_byte_order::<Big>().with_size::<u64>();
+
+    let mut buf = OwnedBuf::new().with_byte_order::<Big>().with_size::<u64>();
+    archive.store(&mut buf, |buf| Ok(buf.store(&42u32)))?;
+
+    assert_eq!(archive.load::<u32>(&buf)?, &42);
+
+    assert!(Archive::new()
+        .with_byte_order::<Little>()
+        .with_size::<u64>()
+        .load::<u32>(&buf)
+        .is_err());
+    assert!(Archive::new()
+        .with_byte_order::<Big>()
+        .load::<u32>(&buf)
+        .is_err());
+    assert!(archive.with_version(1).load::<u32>(&buf).is_err());
+    assert!(archive.with_magic(*b"OTHERMAG").load::<u32>(&buf).is_err());
+    // No checksum was stored, so requiring one fails.
+    assert!(archive.with_checksum().load::<u32>(&buf).is_err());
+
+    // Storing into a non-empty buffer fails.
+    assert!(archive
+        .store(&mut buf, |buf| Ok(buf.store(&42u32)))
+        .is_err());
+    Ok(())
+}
+
+#[test]
+fn archive_corruption() -> Result<()> {
+    let archive = Archive::new().with_checksum();
+
+    let mut buf = OwnedBuf::new();
+    archive.store(&mut buf, |buf| {
+        let name = buf.store_unsized("root");
+        let values = buf.store_slice(&[1, 2, 3]);
+        Ok(buf.store(&Root { name, values }))
+    })?;
+
+    let mut bytes = buf.as_slice().to_vec();
+
+    // Truncated archive.
+    let truncated = crate::buf::aligned_buf::<u64>(&bytes[..bytes.len() - 1]);
+    assert!(archive.load::<Root>(&truncated).is_err());
+
+    // Corrupted body.
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xff;
+    let corrupted = crate::buf::aligned_buf::<u64>(&bytes);
+    assert!(archive.load::<Root>(&corrupted).is_err());
+
+    // Misaligned buffer.
+    let mut unaligned = OwnedBuf::new();
+    unaligned.extend_from_slice(&[0]);
+    unaligned.extend_from_slice(buf.as_slice());
+    let unaligned = Buf::new(&unaligned.as_slice()[1..]);
+    assert!(archive.load::<Root>(unaligned).is_err());
+    Ok(())
+}
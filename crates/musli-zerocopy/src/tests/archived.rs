@@ -0,0 +1,164 @@
+use std::boxed::Box;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::string::String;
+use std::vec::Vec;
+use std::{format, vec};
+
+use anyhow::Result;
+
+use crate::{ArchivedOption, OwnedBuf, Ref, ToArchived, ZeroCopy};
+
+#[derive(ToArchived)]
+#[to_archived(crate)]
+struct Inner {
+    label: String,
+    values: Vec<u32>,
+}
+
+#[derive(ToArchived)]
+#[to_archived(crate)]
+struct Outer {
+    id: u64,
+    inner: Inner,
+    inners: Vec<Inner>,
+    maybe: Option<Inner>,
+    missing: Option<u32>,
+    lookup: HashMap<String, Inner>,
+    ordered: BTreeMap<u32, String>,
+    tags: HashSet<String>,
+    sorted_tags: BTreeSet<String>,
+}
+
+#[derive(ToArchived)]
+#[to_archived(crate, name = ArchivedNode)]
+struct Node {
+    value: u32,
+    next: Option<Box<Node>>,
+}
+
+#[derive(ToArchived)]
+#[to_archived(crate)]
+struct Pair(String, i16);
+
+#[derive(ToArchived)]
+#[to_archived(crate)]
+struct Unit;
+
+fn inner(n: u32) -> Inner {
+    Inner {
+        label: format!("inner{n}"),
+        values: (0..n).collect(),
+    }
+}
+
+fn check_inner(buf: &OwnedBuf, archived: &ArchivedInner, n: u32) -> Result<()> {
+    assert_eq!(buf.load(archived.label)?, format!("inner{n}"));
+    assert!(buf.load(archived.values)?.iter().copied().eq(0..n));
+    Ok(())
+}
+
+#[test]
+fn archive_object_graph() -> Result<()> {
+    let outer = Outer {
+        id: 42,
+        inner: inner(1),
+        inners: vec![inner(2), inner(3)],
+        maybe: Some(inner(4)),
+        missing: None,
+        lookup: (5..10).map(|n| (format!("key{n}"), inner(n))).collect(),
+        ordered: (0..10).map(|n| (n * 3, format!("value{n}"))).collect(),
+        tags: ["a", "b", "c"].into_iter().map(String::from).collect(),
+        sorted_tags: ["x", "y"].into_iter().map(String::from).collect(),
+    };
+
+    let mut buf = OwnedBuf::new();
+    let root: Ref<ArchivedOuter> = outer.store(&mut buf)?;
+
+    let archived = buf.load(root)?;
+    assert_eq!(archived.id, 42);
+    check_inner(&buf, &archived.inner, 1)?;
+
+    let inners = buf.load(archived.inners)?;
+    assert_eq!(inners.len(), 2);
+    check_inner(&buf, &inners[0], 2)?;
+    check_inner(&buf, &inners[1], 3)?;
+
+    let ArchivedOption::Some(maybe) = &archived.maybe else {
+        panic!("expected some");
+    };
+
+    check_inner(&buf, maybe, 4)?;
+    assert_eq!(Option::from(archived.missing), None::<u32>);
+
+    assert_eq!(archived.lookup.len(), 5);
+
+    for n in 5..10 {
+        let key = format!("key{n}");
+        let value = archived.lookup.get(&buf, key.as_str())?.expect("missing");
+        check_inner(&buf, value, n)?;
+    }
+
+    assert!(archived.lookup.get(&buf, "key0")?.is_none());
+
+    let ordered = archived
+        .ordered
+        .iter(&buf)
+        .map(|e| {
+            let (k, v) = e?;
+            Ok((*k, String::from(buf.load(*v)?)))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let expected = outer
+        .ordered
+        .iter()
+        .map(|(k, v)| (*k, v.clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(ordered, expected);
+
+    assert!(archived.tags.contains(&buf, "b")?);
+    assert!(!archived.tags.contains(&buf, "d")?);
+    assert!(archived.sorted_tags.contains(&buf, "y")?);
+    assert!(!archived.sorted_tags.contains(&buf, "z")?);
+    Ok(())
+}
+
+#[test]
+fn archive_recursive_and_tuple() -> Result<()> {
+    let list = Node {
+        value: 1,
+        next: Some(Box::new(Node {
+            value: 2,
+            next: Some(Box::new(Node {
+                value: 3,
+                next: None,
+            })),
+        })),
+    };
+
+    let mut buf = OwnedBuf::new();
+    let list = list.store(&mut buf)?;
+    let pair = Pair(String::from("pair"), -7).store(&mut buf)?;
+    let _: ArchivedUnit = Unit.to_archived(&mut buf)?;
+
+    let mut node = buf.load(list)?;
+    let mut values = Vec::new();
+
+    loop {
+        values.push(node.value);
+
+        let ArchivedOption::Some(next) = node.next else {
+            break;
+        };
+
+        node = buf.load(next)?;
+    }
+
+    assert_eq!(values, [1, 2, 3]);
+
+    let pair = buf.load(pair)?;
+    assert_eq!(buf.load(pair.0)?, "pair");
+    assert_eq!(pair.1, -7);
+
+    const _: () = assert!(<ArchivedUnit as ZeroCopy>::ANY_BITS);
+    Ok(())
+}
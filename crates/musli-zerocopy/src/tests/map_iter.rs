@@ -0,0 +1,114 @@
+use std::collections::BTreeMap;
+use std::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{phf, swiss, OwnedBuf};
+
+fn expected(len: u32) -> BTreeMap<u32, u64> {
+    (0..len).map(|n| (n, u64::from(n) * 3)).collect()
+}
+
+#[test]
+fn swiss_map_iter() -> Result<()> {
+    // Covers tables smaller than a single group of control bytes as well as
+    // tables spanning many groups.
+    for len in [0, 1, 3, 17, 1000] {
+        let expected = expected(len);
+
+        let mut buf = OwnedBuf::new();
+        let map = swiss::store_map(&mut buf, expected.iter().map(|(k, v)| (*k, *v)))?;
+
+        let unbound = map
+            .iter(&buf)
+            .map(|e| e.map(|(k, v)| (*k, *v)))
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+        assert_eq!(unbound, expected);
+        assert_eq!(map.keys(&buf).count(), expected.len());
+        assert_eq!(map.values(&buf).count(), expected.len());
+
+        let map = buf.bind(map)?;
+        let bound = map
+            .iter()
+            .map(|(k, v)| (*k, *v))
+            .collect::<BTreeMap<_, _>>();
+        assert_eq!(bound, expected);
+        assert_eq!(map.keys().count(), map.len());
+        assert_eq!(
+            map.values().copied().sum::<u64>(),
+            expected.values().sum::<u64>()
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn swiss_set_iter() -> Result<()> {
+    for len in [0, 1, 3, 17, 1000] {
+        let mut buf = OwnedBuf::new();
+        let set = swiss::store_set(&mut buf, 0..len)?;
+
+        let mut unbound = set
+            .iter(&buf)
+            .map(|v| v.copied())
+            .collect::<Result<Vec<_>, _>>()?;
+        unbound.sort();
+        assert!(unbound.iter().copied().eq(0..len));
+
+        let set = buf.bind(set)?;
+        let mut bound = set.iter().copied().collect::<Vec<_>>();
+        bound.sort();
+        assert!(bound.iter().copied().eq(0..len));
+        assert_eq!(set.len(), len as usize);
+    }
+
+    Ok(())
+}
+
+#[test]
+fn phf_map_iter() -> Result<()> {
+    for len in [0, 1, 3, 17] {
+        let expected = expected(len);
+
+        let mut buf = OwnedBuf::new();
+        let map = phf::store_map(&mut buf, expected.iter().map(|(k, v)| (*k, *v)))?;
+        assert_eq!(map.len(), expected.len());
+
+        let unbound = map
+            .iter(&buf)
+            .map(|e| e.map(|(k, v)| (*k, *v)))
+            .collect::<Result<BTreeMap<_, _>, _>>()?;
+        assert_eq!(unbound, expected);
+
+        let map = buf.bind(map)?;
+        let bound = map
+            .iter()
+            .map(|(k, v)| (*k, *v))
+            .collect::<BTreeMap<_, _>>();
+        assert_eq!(bound, expected);
+        assert_eq!(map.keys().len(), expected.len());
+        assert_eq!(map.values().len(), expected.len());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn phf_set_iter() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+
+    let first = buf.store_unsized("first");
+    let second = buf.store_unsized("second");
+    let set = phf::store_set(&mut buf, [first, second])?;
+
+    let mut values = Vec::new();
+
+    for value in set.iter(&buf) {
+        values.push(buf.load(*value?)?);
+    }
+
+    values.sort();
+    assert_eq!(values, ["first", "second"]);
+    Ok(())
+}
@@ -1,2 +1,9 @@
+mod archive;
+mod archived;
 mod enum_byte_order;
+mod map_iter;
+mod option_ref;
 mod primitives;
+mod sorted;
+mod swiss_mut;
+mod validate_deep;
@@ -0,0 +1,62 @@
+use anyhow::Result;
+
+use crate::endian::{Big, Little};
+use crate::error::{Error, ErrorKind};
+use crate::pointer::{OptionRef, Ref};
+use crate::{OwnedBuf, ZeroCopy};
+
+#[derive(ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+struct Entry {
+    name: OptionRef<str, Big, u16>,
+    values: OptionRef<[u32], Big, u16>,
+    next: OptionRef<u32, Big, u16>,
+}
+
+#[test]
+fn option_ref_byte_orders() -> Result<()> {
+    let mut buf = OwnedBuf::new().with_byte_order::<Big>().with_size::<u16>();
+
+    let name = buf.store_unsized("entry");
+    let next = buf.store(&42u32.to_be());
+
+    let entry = buf.store(&Entry {
+        name: OptionRef::some(name),
+        values: OptionRef::none(),
+        next: OptionRef::some(next),
+    });
+
+    let entry = buf.load(entry)?;
+    assert_eq!(buf.bind(entry.name)?, Some("entry"));
+    assert_eq!(buf.bind(entry.values)?, None);
+    assert_eq!(buf.bind(entry.next)?.map(|n| u32::from_be(*n)), Some(42));
+
+    // Absent references are represented the same in any byte order.
+    let mut little = OptionRef::<[u32], Little, u16>::none();
+    let mut big = entry.values;
+    assert_eq!(little.to_bytes(), big.to_bytes());
+    Ok(())
+}
+
+#[test]
+fn option_ref_load_none() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+
+    let value = OptionRef::some(buf.store(&1u32));
+    let none = OptionRef::<u32>::none();
+
+    assert_eq!(buf.load(value)?, &1);
+    assert_eq!(
+        buf.load(none),
+        Err(Error::new(ErrorKind::NoneRef { name: "u32" }))
+    );
+
+    *buf.load_mut(value)? = 2;
+    assert_eq!(buf.load(value)?, &2);
+    assert!(buf.load_mut(none).is_err());
+
+    assert_eq!(Option::<Ref<u32>>::from(none), None);
+    assert_eq!(OptionRef::from(value.get()), value);
+    Ok(())
+}
@@ -250,7 +250,8 @@ fn enum_boundaries() -> Result<()> {
             assert_eq!(buf.load(after_min)?, &$name::AfterMin);
             assert_eq!(
                 buf.load(v4),
-                Err(Error::__illegal_enum_discriminant::<$name>(<$num>::MAX - 1))
+                Err(Error::__illegal_enum_discriminant::<$name>(<$num>::MAX - 1)
+                    .with_load::<$name>(v4.offset()))
             );
         }};
     }
@@ -320,7 +321,8 @@ fn test_signed_wraparound() -> Result<()> {
             assert_eq!(buf.load(one)?, &$name::One);
             assert_eq!(
                 buf.load(v4),
-                Err(Error::__illegal_enum_discriminant::<$name>(<$num>::MAX))
+                Err(Error::__illegal_enum_discriminant::<$name>(<$num>::MAX)
+                    .with_load::<$name>(v4.offset()))
             );
         }};
     }
@@ -365,7 +367,8 @@ fn test_neg0() -> Result<()> {
             assert_eq!(buf.load(one)?, &$name::One);
             assert_eq!(
                 buf.load(v4),
-                Err(Error::__illegal_enum_discriminant::<$name>(<$num>::MAX))
+                Err(Error::__illegal_enum_discriminant::<$name>(<$num>::MAX)
+                    .with_load::<$name>(v4.offset()))
             );
         }};
     }
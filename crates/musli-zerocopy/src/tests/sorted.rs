@@ -0,0 +1,168 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::ops::Bound;
+use std::vec::Vec;
+
+use anyhow::Result;
+
+use crate::{sorted, OwnedBuf};
+
+#[test]
+fn sorted_map_matches_btree_map() -> Result<()> {
+    for len in [0u32, 1, 2, 7, 100] {
+        let expected = (0..len).map(|n| (n * 2, n)).collect::<BTreeMap<_, _>>();
+
+        let mut buf = OwnedBuf::new();
+        // Insert in reverse to exercise sorting during construction.
+        let map = sorted::store_map(&mut buf, expected.iter().rev().map(|(k, v)| (*k, *v)))?;
+
+        let bounds = [
+            (Bound::Unbounded, Bound::Unbounded),
+            (Bound::Included(3), Bound::Excluded(11)),
+            (Bound::Excluded(4), Bound::Included(12)),
+            (Bound::Included(0), Bound::Included(0)),
+            (Bound::Excluded(10), Bound::Excluded(5)),
+            (Bound::Unbounded, Bound::Excluded(len)),
+        ];
+
+        for key in 0..len * 2 + 1 {
+            assert_eq!(map.get(&buf, &key)?, expected.get(&key));
+
+            let lower = expected.range(key..).next();
+            let upper = expected
+                .range((Bound::Excluded(key), Bound::Unbounded))
+                .next();
+            assert_eq!(map.lower_bound(&buf, &key)?, lower);
+            assert_eq!(map.upper_bound(&buf, &key)?, upper);
+        }
+
+        for range in bounds {
+            let actual = map.range(&buf, range)?.collect::<Result<Vec<_>, _>>()?;
+
+            // `BTreeMap::range` panics on inverted ranges.
+            let expected = if is_inverted(range) {
+                Vec::new()
+            } else {
+                expected.range(range).collect::<Vec<_>>()
+            };
+
+            assert_eq!(actual, expected);
+        }
+
+        assert_eq!(map.first(&buf)?, expected.first_key_value());
+        assert_eq!(map.last(&buf)?, expected.last_key_value());
+
+        let map = buf.bind(map)?;
+        assert_eq!(map.len(), expected.len());
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            expected.iter().collect::<Vec<_>>()
+        );
+        assert_eq!(map.first(), expected.first_key_value());
+        assert_eq!(map.last(), expected.last_key_value());
+
+        for key in 0..len * 2 + 1 {
+            assert_eq!(map.get(&key)?, expected.get(&key));
+            assert_eq!(map.lower_bound(&key)?, expected.range(key..).next());
+        }
+
+        for range in bounds {
+            let expected = if is_inverted(range) {
+                Vec::new()
+            } else {
+                expected.range(range).rev().collect::<Vec<_>>()
+            };
+
+            assert_eq!(map.range(range)?.rev().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn sorted_map_unsized_keys() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+
+    let words = ["delta", "alpha", "charlie", "bravo", "echo"];
+
+    let entries = words
+        .iter()
+        .enumerate()
+        .map(|(n, w)| (buf.store_unsized(*w), n as u32))
+        .collect::<Vec<_>>();
+
+    let map = sorted::store_map(&mut buf, entries)?;
+    let map = buf.bind(map)?;
+
+    let keys = map
+        .keys()
+        .map(|k| buf.load(*k))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(keys, ["alpha", "bravo", "charlie", "delta", "echo"]);
+    assert_eq!(map.get("charlie")?, Some(&2));
+
+    let values = map.range("b".."d")?.map(|(_, v)| *v).collect::<Vec<_>>();
+    assert_eq!(values, [3, 2]);
+
+    let (k, _) = map.upper_bound("d")?.expect("missing entry");
+    assert_eq!(buf.load(*k)?, "delta");
+    Ok(())
+}
+
+#[test]
+fn sorted_set_matches_btree_set() -> Result<()> {
+    let values = [9u32, 1, 5, 3, 7, 1, 9];
+    let expected = values.iter().copied().collect::<BTreeSet<_>>();
+
+    let mut buf = OwnedBuf::new();
+    let set = sorted::store_set(&mut buf, values)?;
+
+    assert_eq!(set.len(), expected.len());
+
+    for n in 0u32..11 {
+        assert_eq!(set.contains(&buf, &n)?, expected.contains(&n));
+        assert_eq!(set.lower_bound(&buf, &n)?, expected.range(n..).next());
+    }
+
+    let actual = set.range(&buf, 2u32..=7)?.collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(actual, expected.range(2..=7).collect::<Vec<_>>());
+
+    let set = buf.bind(set)?;
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        expected.iter().collect::<Vec<_>>()
+    );
+    assert_eq!(set.first(), expected.first());
+    assert_eq!(set.last(), expected.last());
+    Ok(())
+}
+
+#[test]
+fn sorted_map_incremental_validation() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+    let map = sorted::store_map(&mut buf, [(1u32, 10u32), (2, 20), (3, 30)])?;
+
+    // A buffer which is too short to contain the entries of the map, which is
+    // only detected once an entry is accessed.
+    let mut short = OwnedBuf::new();
+    short.store(&0u32);
+
+    assert_eq!(map.len(), 3);
+    assert!(map.get(&short, &1u32).is_err());
+    assert!(map.first(&short).is_err());
+    assert!(map.iter(&short).next().expect("missing entry").is_err());
+    assert!(short.bind(map).is_err());
+
+    assert_eq!(map.get(&buf, &2u32)?, Some(&20));
+    Ok(())
+}
+
+fn is_inverted(range: (Bound<u32>, Bound<u32>)) -> bool {
+    match range {
+        (Bound::Included(a) | Bound::Excluded(a), Bound::Included(b) | Bound::Excluded(b)) => {
+            a > b || (a == b && !matches!(range, (Bound::Included(_), Bound::Included(_))))
+        }
+        _ => false,
+    }
+}
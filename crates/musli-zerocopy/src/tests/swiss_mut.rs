@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+use std::format;
+use std::vec::Vec;
+
+use anyhow::Result;
+
+use crate::swiss::{self, MapMut};
+use crate::{OwnedBuf, Ref};
+
+#[test]
+fn swiss_map_mut_matches_btree_map() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+    let mut map = MapMut::<u32, u64>::with_capacity(&mut buf, 0);
+    let mut expected = BTreeMap::new();
+
+    // Interleave insertions, replacements and removals so that the table is
+    // both grown and rehashed to clear out tombstones.
+    for round in 0..8u32 {
+        for n in 0..200u32 {
+            let key = (n * 7919 + round * 31) % 500;
+            let value = u64::from(n) * u64::from(round + 1);
+            assert_eq!(
+                map.insert(&mut buf, key, value)?,
+                expected.insert(key, value)
+            );
+        }
+
+        for n in 0..150u32 {
+            let key = (n * 104729 + round) % 500;
+            assert_eq!(map.remove(&mut buf, &key)?, expected.remove(&key));
+        }
+
+        assert_eq!(map.len(), expected.len());
+        assert!(map.capacity() >= map.len());
+
+        for key in 0..500u32 {
+            assert_eq!(map.get(&buf, &key)?, expected.get(&key));
+        }
+    }
+
+    let map_ref = map.to_ref();
+
+    let unbound = map_ref
+        .iter(&buf)
+        .map(|e| e.map(|(k, v)| (*k, *v)))
+        .collect::<Result<BTreeMap<_, _>, _>>()?;
+    assert_eq!(unbound, expected);
+
+    let bound = buf.bind(map_ref)?;
+    assert_eq!(bound.len(), expected.len());
+
+    for (key, value) in &expected {
+        assert_eq!(bound.get(key)?, Some(value));
+    }
+
+    // Reopening the table must account for the tombstones left behind.
+    let mut map = MapMut::new(&buf, map_ref)?;
+
+    for key in 500..600u32 {
+        map.insert(&mut buf, key, u64::from(key))?;
+        expected.insert(key, u64::from(key));
+    }
+
+    let bound = buf.bind(map.to_ref())?;
+    assert_eq!(bound.len(), expected.len());
+
+    for (key, value) in &expected {
+        assert_eq!(bound.get(key)?, Some(value));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn swiss_map_mut_unsized_keys() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+
+    let mut keys = Vec::new();
+
+    for n in 0..64u32 {
+        keys.push((buf.store_unsized(format!("key{n}").as_str()), n));
+    }
+
+    let map = swiss::store_map(&mut buf, keys[..4].iter().copied())?;
+    let mut map = MapMut::new(&buf, map)?;
+
+    for &(key, value) in &keys[4..] {
+        assert_eq!(map.insert(&mut buf, key, value)?, None);
+    }
+
+    let other: Ref<str> = buf.store_unsized("key10");
+    assert_eq!(map.insert(&mut buf, other, 100)?, Some(10));
+
+    for n in (0..64u32).step_by(2) {
+        let expected = if n == 10 { 100 } else { n };
+        assert_eq!(
+            map.remove(&mut buf, format!("key{n}").as_str())?,
+            Some(expected)
+        );
+    }
+
+    let map = buf.bind(map.to_ref())?;
+    assert_eq!(map.len(), 32);
+
+    for n in 0..64u32 {
+        let key = format!("key{n}");
+        let expected = (n % 2 == 1).then_some(n);
+        assert_eq!(map.get(key.as_str())?.copied(), expected);
+    }
+
+    Ok(())
+}
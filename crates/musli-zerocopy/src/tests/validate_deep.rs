@@ -0,0 +1,153 @@
+use core::any::type_name;
+
+use anyhow::Result;
+
+use crate::error::PathSegment;
+use crate::pointer::{OptionRef, Ref};
+use crate::{OwnedBuf, ZeroCopy};
+
+#[derive(ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+struct Flags {
+    enabled: bool,
+}
+
+#[derive(ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+struct Node {
+    name: Ref<str>,
+    flags: Ref<Flags>,
+    next: OptionRef<Node>,
+}
+
+#[derive(Debug, ZeroCopy)]
+#[repr(C)]
+#[zero_copy(crate)]
+struct Bits {
+    values: [bool; 4],
+}
+
+fn load(name: &'static str, offset: usize) -> PathSegment {
+    PathSegment::Load { name, offset }
+}
+
+fn field<T>(field: &'static str) -> PathSegment {
+    PathSegment::Field {
+        name: type_name::<T>(),
+        field,
+    }
+}
+
+#[test]
+fn validate_deep_cycle() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+
+    let enabled = buf.store(&Flags { enabled: true });
+    let illegal = Ref::<Flags>::new(buf.store(&2u8).offset());
+    let out_of_bounds = Ref::<Flags>::new(4096);
+
+    let a_name = buf.store_unsized("a");
+    let b_name = buf.store_unsized("b");
+    let bytes = buf.store_unsized(&[0xffu8, 0xfe][..]);
+    let c_name = Ref::<str>::with_metadata(bytes.offset(), bytes.len());
+
+    let c = buf.store(&Node {
+        name: c_name,
+        flags: out_of_bounds,
+        next: OptionRef::none(),
+    });
+
+    let b = buf.store(&Node {
+        name: b_name,
+        flags: illegal,
+        next: OptionRef::some(c),
+    });
+
+    let a = buf.store(&Node {
+        name: a_name,
+        flags: enabled,
+        next: OptionRef::some(b),
+    });
+
+    buf.load_mut(c)?.next = OptionRef::some(a);
+
+    // Loading only validates the nodes themselves.
+    assert!(buf.load(a).is_ok());
+
+    let errors = buf.validate_deep(a).unwrap_err();
+    assert_eq!(errors.len(), 3);
+
+    let node = type_name::<Node>();
+
+    let to_b = [load(node, a.offset()), field::<Node>("next")];
+    let to_c = [load(node, b.offset()), field::<Node>("next")];
+
+    assert_eq!(
+        errors[0].path(),
+        [
+            &to_b[..],
+            &[
+                load(node, b.offset()),
+                field::<Node>("flags"),
+                load(type_name::<Flags>(), illegal.offset()),
+                field::<Flags>("enabled"),
+            ],
+        ]
+        .concat()
+    );
+
+    assert_eq!(
+        errors[1].path(),
+        [
+            &to_b[..],
+            &to_c[..],
+            &[
+                load(node, c.offset()),
+                field::<Node>("name"),
+                load("str", c_name.offset()),
+            ],
+        ]
+        .concat()
+    );
+
+    assert_eq!(
+        errors[2].path(),
+        [
+            &to_b[..],
+            &to_c[..],
+            &[
+                load(node, c.offset()),
+                field::<Node>("flags"),
+                load(type_name::<Flags>(), 4096),
+            ],
+        ]
+        .concat()
+    );
+
+    assert!(buf.validate_deep(enabled).is_ok());
+    Ok(())
+}
+
+#[test]
+fn load_error_path() -> Result<()> {
+    let mut buf = OwnedBuf::new();
+
+    let bits = Ref::<Bits>::new(buf.store(&[1u8, 0, 2, 1]).offset());
+    let error = buf.load(bits).unwrap_err();
+
+    assert_eq!(
+        error.path(),
+        [
+            load(type_name::<Bits>(), bits.offset()),
+            field::<Bits>("values"),
+            PathSegment::Index { index: 2 },
+        ]
+    );
+
+    let bits = buf.store_unsized(&[true, false][..]);
+    let bits = Ref::<[bool]>::with_metadata(bits.offset(), 4);
+    assert!(buf.load(bits).is_err());
+    Ok(())
+}
@@ -24,7 +24,7 @@ use core::ptr::NonNull;
 use core::slice;
 use core::str;
 
-use crate::buf::{Buf, Padder, Validator, Visit};
+use crate::buf::{Buf, Padder, RefVisitor, Validator, Visit};
 use crate::endian::ByteOrder;
 use crate::error::{Error, ErrorKind};
 use crate::pointer::{Pointee, Size};
@@ -242,6 +242,11 @@ where
     {
         Wrapping(T::swap_bytes::<E>(self.0))
     }
+
+    #[inline]
+    fn visit_refs(&self, visitor: &mut RefVisitor<'_>) {
+        self.0.visit_refs(visitor);
+    }
 }
 
 /// `()` can be ignored as a zero-sized field.
@@ -448,6 +453,24 @@ pub unsafe trait ZeroCopy: Sized {
     #[doc(hidden)]
     unsafe fn validate(validator: &mut Validator<'_, Self>) -> Result<(), Error>;
 
+    /// Visit every reference contained in this value.
+    ///
+    /// This is used by [`Buf::validate_deep`] to walk every reference which is
+    /// reachable from a value. It is implemented by the
+    /// [`ZeroCopy`][derive@crate::ZeroCopy] derive, and only needs to be
+    /// implemented manually for types which contain references that are not
+    /// stored as a [`Ref`].
+    ///
+    /// The default implementation does not visit anything.
+    ///
+    /// See [`RefVisitor::visit_ref`] for an example.
+    ///
+    /// [`Ref`]: crate::pointer::Ref
+    #[inline]
+    fn visit_refs(&self, visitor: &mut RefVisitor<'_>) {
+        let _ = visitor;
+    }
+
     /// Ensure that the padding for the current value is initialized.
     ///
     /// This can be used in combination with [`to_bytes_unchecked()`] to relax
@@ -747,8 +770,10 @@ where
         if !T::ANY_BITS {
             let mut validator = Validator::<[T]>::new(data);
 
-            for _ in 0..metadata {
-                validator.validate_only::<T>()?;
+            for index in 0..metadata {
+                validator
+                    .validate_only::<T>()
+                    .map_err(|error| error.with_index(index))?;
             }
         }
 
@@ -1249,8 +1274,10 @@ where
 
     #[inline]
     unsafe fn validate(validator: &mut Validator<'_, Self>) -> Result<(), Error> {
-        for _ in 0..N {
-            validator.validate_only::<T>()?;
+        for index in 0..N {
+            validator
+                .validate_only::<T>()
+                .map_err(|error| error.with_index(index))?;
         }
 
         Ok(())
@@ -1264,6 +1291,13 @@ where
         let mut iter = self.into_iter();
         array::from_fn(move |_| T::swap_bytes::<E>(iter.next().unwrap()))
     }
+
+    #[inline]
+    fn visit_refs(&self, visitor: &mut RefVisitor<'_>) {
+        for (index, value) in self.iter().enumerate() {
+            visitor.index(index, |visitor| value.visit_refs(visitor));
+        }
+    }
 }
 
 impl<T> Visit for [T; 0] {
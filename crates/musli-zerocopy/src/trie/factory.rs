@@ -5,10 +5,11 @@ use core::mem::replace;
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+use crate::buf::StoreBuf;
 use crate::endian::Native;
 use crate::pointer::{Coerce, Pointee};
 use crate::slice::{BinarySearch, Slice};
-use crate::{Buf, ByteOrder, Error, OwnedBuf, Ref, Size, ZeroCopy};
+use crate::{Buf, ByteOrder, Error, Ref, Size, ZeroCopy};
 
 use super::{prefix, DefaultFlavor, Flavor, LinksRef, NodeRef, TrieRef};
 
@@ -47,22 +48,20 @@ use super::{prefix, DefaultFlavor, Flavor, LinksRef, NodeRef, TrieRef};
 /// # Ok::<_, musli_zerocopy::Error>(())
 /// ```
 #[cfg(feature = "alloc")]
-pub fn store<S, E, O, I, T>(
-    buf: &mut OwnedBuf<E, O>,
-    it: I,
-) -> Result<TrieRef<T, DefaultFlavor<E, O>>, Error>
+pub fn store<S, E, O, I, T, B>(buf: &mut B, it: I) -> Result<TrieRef<T, DefaultFlavor<E, O>>, Error>
 where
     I: IntoIterator<Item = (Ref<S, E, O>, T)>,
     T: ZeroCopy,
     S: ?Sized + Pointee + Coerce<[u8]>,
     E: ByteOrder,
     O: Size,
+    B: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
 {
     // First step is to construct the trie in-memory.
     let mut trie = Builder::with_flavor();
 
     for (string, value) in it {
-        trie.insert(buf, string, value)?;
+        trie.insert(buf.as_buf(), string, value)?;
     }
 
     trie.build(buf)
@@ -250,11 +249,12 @@ where
     /// assert_eq!(trie.get(&buf, "working")?, Some(&[4][..]));
     /// # Ok::<_, musli_zerocopy::Error>(())
     /// ```
-    pub fn build<E, O>(self, buf: &mut OwnedBuf<E, O>) -> Result<TrieRef<T, F>, Error>
+    pub fn build<E, O, B>(self, buf: &mut B) -> Result<TrieRef<T, F>, Error>
     where
         T: ZeroCopy,
         E: ByteOrder,
         O: Size,
+        B: ?Sized + StoreBuf<ByteOrder = E, Size = O>,
     {
         Ok(TrieRef {
             links: self.links.into_ref(buf)?,
@@ -284,14 +284,13 @@ impl<T> Links<T> {
         }
     }
 
-    fn into_ref<E, O, F>(self, buf: &mut OwnedBuf<E, O>) -> Result<LinksRef<T, F>, Error>
+    fn into_ref<S, F>(self, buf: &mut S) -> Result<LinksRef<T, F>, Error>
     where
         T: ZeroCopy,
-        E: ByteOrder,
-        O: Size,
+        S: ?Sized + StoreBuf,
         F: Flavor,
     {
-        let values = F::Values::try_from_ref(buf.store_slice(&self.values))?;
+        let values = F::Values::try_from_ref(buf.store_unsized(&self.values[..]))?;
 
         let mut children = Vec::with_capacity(self.children.len());
 
@@ -299,7 +298,7 @@ impl<T> Links<T> {
             children.push(node.into_ref(buf)?);
         }
 
-        let children = F::Children::try_from_ref(buf.store_slice(&children))?;
+        let children = F::Children::try_from_ref(buf.store_unsized(&children[..]))?;
         Ok(LinksRef { values, children })
     }
 }
@@ -319,11 +318,10 @@ impl<T> Node<T> {
         }
     }
 
-    fn into_ref<E, O, F>(self, buf: &mut OwnedBuf<E, O>) -> Result<NodeRef<T, F>, Error>
+    fn into_ref<S, F>(self, buf: &mut S) -> Result<NodeRef<T, F>, Error>
     where
         T: ZeroCopy,
-        E: ByteOrder,
-        O: Size,
+        S: ?Sized + StoreBuf,
         F: Flavor,
     {
         Ok(NodeRef {
@@ -0,0 +1,179 @@
+#![allow(clippy::type_complexity)]
+
+use crate::error::ErrorKind;
+use crate::slice::Slice;
+use crate::stack::Stack;
+use crate::{Buf, Error, ZeroCopy};
+
+use super::walk::prefix_string;
+use super::{Flavor, LinksRef, StackEntry};
+
+/// The widest band of the edit distance matrix we need to keep track of, which
+/// corresponds to `2 * u8::MAX + 1`.
+const BAND: usize = 2 * u8::MAX as usize + 1;
+
+/// A depth-first walk over every key in the trie which is within a bounded
+/// edit distance of a string.
+pub(super) struct FuzzyWalk<'a, 'buf, T, F, S>
+where
+    T: ZeroCopy,
+    F: Flavor,
+    S: Stack<StackEntry<'buf, T, F>>,
+{
+    // Buffer being walked.
+    buf: &'buf Buf,
+    // The string being matched against.
+    string: &'a [u8],
+    // The maximum edit distance of keys to yield.
+    max: u8,
+    // The root of the trie, which is taken once the walk starts.
+    root: Option<LinksRef<T, F>>,
+    // A stack which indicates the links who's children we should visit next,
+    // and an index corresponding to the child to visit.
+    stack: S,
+}
+
+impl<'a, 'buf, T, F, S> FuzzyWalk<'a, 'buf, T, F, S>
+where
+    T: ZeroCopy,
+    F: Flavor,
+    S: Stack<StackEntry<'buf, T, F>>,
+{
+    pub(super) fn new(buf: &'buf Buf, links: LinksRef<T, F>, string: &'a [u8], max: u8) -> Self {
+        Self {
+            buf,
+            string,
+            max,
+            root: Some(links),
+            stack: S::new(),
+        }
+    }
+
+    pub(super) fn poll(&mut self) -> Result<Option<(&'buf [u8], u8, &'buf [T])>, Error> {
+        if let Some(links) = self.root.take() {
+            self.push((links, 0, &[]))?;
+
+            if let Some(distance) = self.distance(&[]) {
+                let values = self.buf.load(links.values)?;
+
+                if !values.is_empty() {
+                    return Ok(Some((&[], distance, values)));
+                }
+            }
+        }
+
+        while let Some((links, index, prefix)) = self.stack.pop() {
+            let Some(node) = links.children.get(index) else {
+                continue;
+            };
+
+            self.push((links, index + 1, prefix))?;
+
+            let node = self.buf.load(node)?;
+            let key = self.buf.load(prefix_string(node.string, prefix.len())?)?;
+
+            let Some(row) = bounded_distance(key, self.string, self.max) else {
+                // No key which has this node as a prefix can be within the
+                // edit distance, so the whole subtree is skipped.
+                continue;
+            };
+
+            self.push((node.links, 0, key))?;
+
+            if row > u16::from(self.max) {
+                continue;
+            }
+
+            let values = self.buf.load(node.links.values)?;
+
+            if !values.is_empty() {
+                return Ok(Some((key, row as u8, values)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    #[inline]
+    fn distance(&self, key: &[u8]) -> Option<u8> {
+        let distance = bounded_distance(key, self.string, self.max)?;
+        u8::try_from(distance).ok().filter(|d| *d <= self.max)
+    }
+
+    #[inline]
+    fn push(&mut self, entry: StackEntry<'buf, T, F>) -> Result<(), Error> {
+        if !self.stack.try_push(entry) {
+            return Err(Error::new(ErrorKind::StackOverflow {
+                capacity: S::CAPACITY,
+            }));
+        }
+
+        Ok(())
+    }
+}
+
+/// Calculate the Levenshtein distance between `key` and `string`, only
+/// considering distances up to `max`.
+///
+/// This only keeps track of the band of the distance matrix which is within
+/// `max` of its diagonal, since every other cell is guaranteed to exceed it.
+///
+/// Returns `None` if neither `key` nor any string which has `key` as a prefix
+/// can be within `max` of `string`. Otherwise returns the distance between
+/// `key` and `string`, which is `max + 1` if it exceeds `max`.
+pub(super) fn bounded_distance(key: &[u8], string: &[u8], max: u8) -> Option<u16> {
+    let k = usize::from(max);
+    let width = 2 * k + 1;
+    let inf = u16::from(max) + 1;
+
+    let mut a = [inf; BAND];
+    let mut b = [inf; BAND];
+
+    let (mut prev, mut cur) = (&mut a[..width], &mut b[..width]);
+
+    // Cell `d` in the row for `key[..i]` corresponds to the distance to
+    // `string[..i + d - k]`.
+    for (d, cell) in prev.iter_mut().enumerate() {
+        if let Some(j) = d.checked_sub(k) {
+            if j <= string.len() {
+                *cell = j as u16;
+            }
+        }
+    }
+
+    for (i, &c) in key.iter().enumerate() {
+        let i = i + 1;
+        let mut min = inf;
+
+        for d in 0..width {
+            let value = match (i + d).checked_sub(k) {
+                Some(j) if j > string.len() => inf,
+                None => inf,
+                Some(0) => i.min(usize::from(inf)) as u16,
+                Some(j) => {
+                    let replace = prev[d] + u16::from(string[j - 1] != c);
+                    let delete = prev.get(d + 1).map_or(inf, |v| *v + 1);
+                    let insert = d.checked_sub(1).map_or(inf, |d| cur[d] + 1);
+                    replace.min(delete).min(insert).min(inf)
+                }
+            };
+
+            cur[d] = value;
+            min = min.min(value);
+        }
+
+        if min == inf {
+            return None;
+        }
+
+        (prev, cur) = (cur, prev);
+    }
+
+    let distance = (string.len() + k)
+        .checked_sub(key.len())
+        .and_then(|d| prev.get(d))
+        .copied()
+        .unwrap_or(inf);
+
+    Some(distance)
+}
@@ -8,6 +8,9 @@ pub use self::factory::{store, Builder};
 #[cfg(feature = "alloc")]
 mod factory;
 
+use self::fuzzy::FuzzyWalk;
+mod fuzzy;
+
 use self::walk::Walk;
 mod walk;
 
@@ -766,6 +769,364 @@ where
             iter: Walk::find(buf, self.links, prefix.as_ref()),
         }
     }
+
+    /// Construct an iterator over all entries in the trie whose key is a
+    /// prefix of `string`, from the shortest to the longest key.
+    ///
+    /// Each item is the prefix of `string` which matched, and the values
+    /// associated with it. This walks the trie directly and does not allocate.
+    ///
+    /// # Errors
+    ///
+    /// This errors in case the trie being iterated over is structurally
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{trie, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let values = [
+    ///     (buf.store_unsized("work"), 1),
+    ///     (buf.store_unsized("worker"), 2),
+    ///     (buf.store_unsized("workers"), 3),
+    ///     (buf.store_unsized("working"), 4),
+    ///     (buf.store_unsized("run"), 5),
+    /// ];
+    ///
+    /// let trie = trie::store(&mut buf, values)?;
+    ///
+    /// let prefixes = trie.prefixes(&buf, "workersunion").collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// assert_eq! {
+    ///     prefixes,
+    ///     [
+    ///         (&b"work"[..], &[1][..]),
+    ///         (&b"worker"[..], &[2][..]),
+    ///         (&b"workers"[..], &[3][..]),
+    ///     ]
+    /// };
+    ///
+    /// assert_eq!(trie.prefixes(&buf, "wor").count(), 0);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn prefixes<'a, 'buf, S>(&self, buf: &'buf Buf, string: &'a S) -> Prefixes<'a, 'buf, T, F>
+    where
+        S: ?Sized + AsRef<[u8]>,
+    {
+        Prefixes {
+            buf,
+            string: string.as_ref(),
+            state: Some((self.links, 0)),
+            values: true,
+        }
+    }
+
+    /// Find the longest key in the trie which is a prefix of `string`.
+    ///
+    /// This returns the prefix of `string` which matched, and the values
+    /// associated with it. This walks the trie directly and does not allocate.
+    ///
+    /// # Errors
+    ///
+    /// This errors in case the trie being searched is structurally invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{trie, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let values = [
+    ///     (buf.store_unsized("東"), 1),
+    ///     (buf.store_unsized("東京"), 2),
+    ///     (buf.store_unsized("京都"), 3),
+    /// ];
+    ///
+    /// let trie = trie::store(&mut buf, values)?;
+    ///
+    /// assert_eq!(trie.longest_prefix(&buf, "東京都")?, Some(("東京".as_bytes(), &[2][..])));
+    /// assert_eq!(trie.longest_prefix(&buf, "東の")?, Some(("東".as_bytes(), &[1][..])));
+    /// assert_eq!(trie.longest_prefix(&buf, "京")?, None);
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn longest_prefix<'a, 'buf, S>(
+        &self,
+        buf: &'buf Buf,
+        string: &'a S,
+    ) -> Result<Option<(&'a [u8], &'buf [T])>, Error>
+    where
+        S: ?Sized + AsRef<[u8]>,
+    {
+        let mut longest = None;
+
+        for result in self.prefixes(buf, string) {
+            longest = Some(result?);
+        }
+
+        Ok(longest)
+    }
+
+    /// Construct an iterator over all entries in the trie whose key is within
+    /// a Levenshtein distance of `max_distance` from `string`.
+    ///
+    /// Each item is the key of the entry, its distance to `string` and the
+    /// values associated with it. Subtrees which cannot contain a match are
+    /// skipped, so only the parts of the trie which are close to `string` are
+    /// visited and validated.
+    ///
+    /// Note that the distance is calculated over bytes, so a single
+    /// substituted multi-byte character counts as multiple edits. The
+    /// iteration order is unspecified and might change in future versions.
+    ///
+    /// # Errors
+    ///
+    /// This errors in case the trie being iterated over is structurally
+    /// invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::str::from_utf8;
+    ///
+    /// use musli_zerocopy::{trie, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let values = [
+    ///     (buf.store_unsized("work"), 1),
+    ///     (buf.store_unsized("worker"), 2),
+    ///     (buf.store_unsized("word"), 3),
+    ///     (buf.store_unsized("fork"), 4),
+    ///     (buf.store_unsized("run"), 5),
+    /// ];
+    ///
+    /// let trie = trie::store(&mut buf, values)?;
+    ///
+    /// let mut matches = Vec::new();
+    ///
+    /// for result in trie.fuzzy(&buf, "work", 1) {
+    ///     let (key, distance, values) = result?;
+    ///     matches.push((from_utf8(key)?, distance, values));
+    /// }
+    ///
+    /// matches.sort();
+    ///
+    /// assert_eq! {
+    ///     matches,
+    ///     [
+    ///         ("fork", 1, &[4][..]),
+    ///         ("word", 1, &[3][..]),
+    ///         ("work", 0, &[1][..]),
+    ///     ]
+    /// };
+    /// # Ok::<_, anyhow::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn fuzzy<'a, 'buf, S>(
+        &self,
+        buf: &'buf Buf,
+        string: &'a S,
+        max_distance: u8,
+    ) -> Fuzzy<'a, 'buf, T, F>
+    where
+        S: ?Sized + AsRef<[u8]>,
+    {
+        Fuzzy {
+            iter: FuzzyWalk::new(buf, self.links, string.as_ref(), max_distance),
+        }
+    }
+
+    /// Construct an iterator over all entries in the trie whose key is within
+    /// a Levenshtein distance of `max_distance` from `string` using a fixed max
+    /// iteration depth of `N`.
+    ///
+    /// See [`TrieRef::fuzzy()`] for details.
+    ///
+    /// # Errors
+    ///
+    /// This errors in case the trie being iterated over is structurally
+    /// invalid or if the iteration depth exceeds `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli_zerocopy::{trie, OwnedBuf};
+    ///
+    /// let mut buf = OwnedBuf::new();
+    ///
+    /// let values = [
+    ///     (buf.store_unsized("work"), 1),
+    ///     (buf.store_unsized("worker"), 2),
+    ///     (buf.store_unsized("run"), 3),
+    /// ];
+    ///
+    /// let trie = trie::store(&mut buf, values)?;
+    ///
+    /// let mut matches = trie
+    ///     .fuzzy_fixed::<16, _>(&buf, "workr", 1)
+    ///     .collect::<Result<Vec<_>, _>>()?;
+    ///
+    /// matches.sort();
+    ///
+    /// assert_eq! {
+    ///     matches,
+    ///     [
+    ///         (&b"work"[..], 1, &[1][..]),
+    ///         (&b"worker"[..], 1, &[2][..]),
+    ///     ]
+    /// };
+    /// # Ok::<_, musli_zerocopy::Error>(())
+    /// ```
+    pub fn fuzzy_fixed<'a, 'buf, const N: usize, S>(
+        &self,
+        buf: &'buf Buf,
+        string: &'a S,
+        max_distance: u8,
+    ) -> FuzzyFixed<'a, 'buf, N, T, F>
+    where
+        S: ?Sized + AsRef<[u8]>,
+    {
+        FuzzyFixed {
+            iter: FuzzyWalk::new(buf, self.links, string.as_ref(), max_distance),
+        }
+    }
+}
+
+/// An iterator over entries in a [`TrieRef`] whose key is a prefix of a
+/// string.
+///
+/// See [`TrieRef::prefixes()`].
+pub struct Prefixes<'a, 'buf, T, F>
+where
+    T: 'buf + ZeroCopy,
+    F: Flavor,
+{
+    buf: &'buf Buf,
+    string: &'a [u8],
+    // The links being visited and the length of the string they correspond to.
+    state: Option<(LinksRef<T, F>, usize)>,
+    // Whether the values of the current links should be yielded.
+    values: bool,
+}
+
+impl<'a, 'buf, T, F> Prefixes<'a, 'buf, T, F>
+where
+    T: 'buf + ZeroCopy,
+    F: Flavor,
+{
+    #[allow(clippy::type_complexity)]
+    fn poll(&mut self) -> Result<Option<(&'a [u8], &'buf [T])>, Error> {
+        while let Some((this, len)) = self.state.take() {
+            if self.values {
+                let values = self.buf.load(this.values)?;
+                self.values = false;
+                self.state = Some((this, len));
+
+                if !values.is_empty() {
+                    return Ok(Some((&self.string[..len], values)));
+                }
+
+                continue;
+            }
+
+            let string = &self.string[len..];
+
+            let search = binary_search_by(self.buf, this.children, |c| {
+                Ok(self.buf.load(c.string)?.cmp(string))
+            })?;
+
+            // Thanks to structural invariants, the only child which might be a
+            // prefix of the string is the exact match or the one immediately
+            // preceeding where it would be inserted.
+            let n = match search {
+                BinarySearch::Found(n) => n,
+                BinarySearch::Missing(0) => break,
+                BinarySearch::Missing(n) => n - 1,
+            };
+
+            let child = self.buf.load(this.children.get_unchecked(n))?;
+            let prefix = self.buf.load(child.string)?;
+
+            if prefix.is_empty() || !string.starts_with(prefix) {
+                break;
+            }
+
+            self.state = Some((child.links, len + prefix.len()));
+            self.values = true;
+        }
+
+        Ok(None)
+    }
+}
+
+impl<'a, 'buf, T, F> Iterator for Prefixes<'a, 'buf, T, F>
+where
+    T: 'buf + ZeroCopy,
+    F: Flavor,
+{
+    type Item = Result<(&'a [u8], &'buf [T]), Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.poll().transpose()
+    }
+}
+
+/// An iterator over entries in a [`TrieRef`] whose key is within a bounded
+/// edit distance of a string.
+///
+/// See [`TrieRef::fuzzy()`].
+#[cfg(feature = "alloc")]
+pub struct Fuzzy<'a, 'buf, T, F>
+where
+    T: 'buf + ZeroCopy,
+    F: Flavor,
+{
+    iter: FuzzyWalk<'a, 'buf, T, F, Vec<StackEntry<'buf, T, F>>>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, 'buf, T, F> Iterator for Fuzzy<'a, 'buf, T, F>
+where
+    T: 'buf + ZeroCopy,
+    F: Flavor,
+{
+    type Item = Result<(&'buf [u8], u8, &'buf [T]), Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.poll().transpose()
+    }
+}
+
+/// An iterator over entries in a [`TrieRef`] whose key is within a bounded
+/// edit distance of a string using a fixed max iteration depth of `N`.
+///
+/// See [`TrieRef::fuzzy_fixed()`].
+pub struct FuzzyFixed<'a, 'buf, const N: usize, T, F>
+where
+    T: 'buf + ZeroCopy,
+    F: Flavor,
+{
+    iter: FuzzyWalk<'a, 'buf, T, F, ArrayStack<StackEntry<'buf, T, F>, N>>,
+}
+
+impl<'a, 'buf, const N: usize, T, F> Iterator for FuzzyFixed<'a, 'buf, N, T, F>
+where
+    T: 'buf + ZeroCopy,
+    F: Flavor,
+{
+    type Item = Result<(&'buf [u8], u8, &'buf [T]), Error>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.poll().transpose()
+    }
 }
 
 /// An iterator over values matching a `prefix` in a [`TrieRef`].
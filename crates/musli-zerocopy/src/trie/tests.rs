@@ -174,3 +174,125 @@ fn entries() -> Result<()> {
     assert_eq!(values, [("running", 8),]);
     Ok(())
 }
+
+#[test]
+fn longest_prefix() -> Result<(), Error> {
+    let mut buf = OwnedBuf::new();
+
+    let values = [
+        (buf.store_unsized("a"), 1),
+        (buf.store_unsized("ab"), 2),
+        (buf.store_unsized("abcd"), 3),
+        (buf.store_unsized("abce"), 4),
+        (buf.store_unsized("b"), 5),
+    ];
+
+    let trie = store(&mut buf, values)?;
+
+    let prefixes = trie
+        .prefixes(&buf, "abcdef")
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(
+        prefixes,
+        [
+            (&b"a"[..], &[1][..]),
+            (&b"ab"[..], &[2][..]),
+            (&b"abcd"[..], &[3][..])
+        ]
+    );
+
+    // "abc" is only an intermediate node in the trie.
+    assert_eq!(
+        trie.longest_prefix(&buf, "abc")?,
+        Some((&b"ab"[..], &[2][..]))
+    );
+    assert_eq!(
+        trie.longest_prefix(&buf, "abce")?,
+        Some((&b"abce"[..], &[4][..]))
+    );
+    assert_eq!(trie.longest_prefix(&buf, "c")?, None);
+    assert_eq!(trie.longest_prefix(&buf, "")?, None);
+    Ok(())
+}
+
+#[test]
+fn fuzzy() -> Result<()> {
+    fn levenshtein(a: &[u8], b: &[u8]) -> usize {
+        let mut prev = (0..=b.len()).collect::<Vec<_>>();
+
+        for (i, &ca) in a.iter().enumerate() {
+            let mut cur = Vec::with_capacity(b.len() + 1);
+            cur.push(i + 1);
+
+            for (j, &cb) in b.iter().enumerate() {
+                let value = (prev[j] + usize::from(ca != cb))
+                    .min(prev[j + 1] + 1)
+                    .min(cur[j] + 1);
+                cur.push(value);
+            }
+
+            prev = cur;
+        }
+
+        prev[b.len()]
+    }
+
+    let words = [
+        "work", "worker", "workers", "working", "word", "fork", "forks", "run", "running",
+        "runner", "wok", "w", "",
+    ];
+
+    let mut buf = OwnedBuf::new();
+
+    let values = words
+        .iter()
+        .enumerate()
+        .map(|(n, w)| (buf.store_unsized(*w), n as u32))
+        .collect::<Vec<_>>();
+
+    let trie = store(&mut buf, values)?;
+
+    for query in ["", "w", "wrok", "runing", "forker", "xyz", "workingman"] {
+        for max in 0..4u8 {
+            let mut expected = words
+                .iter()
+                .enumerate()
+                .filter_map(|(n, w)| {
+                    let distance = levenshtein(w.as_bytes(), query.as_bytes());
+                    (distance <= usize::from(max)).then_some((
+                        w.as_bytes(),
+                        distance as u8,
+                        n as u32,
+                    ))
+                })
+                .collect::<Vec<_>>();
+
+            expected.sort();
+
+            let mut actual = Vec::new();
+
+            for result in trie.fuzzy(&buf, query, max) {
+                let (key, distance, values) = result?;
+
+                for value in values {
+                    actual.push((key, distance, *value));
+                }
+            }
+
+            actual.sort();
+            assert_eq!(actual, expected, "query: {query:?}, max: {max}");
+
+            let fixed = trie
+                .fuzzy_fixed::<16, _>(&buf, query, max)
+                .collect::<Result<Vec<_>, _>>()?;
+            assert_eq!(fixed.len(), trie.fuzzy(&buf, query, max).count());
+        }
+    }
+
+    // Stack too small for the depth of the trie.
+    assert!(trie
+        .fuzzy_fixed::<1, _>(&buf, "working", 1)
+        .any(|result| result.is_err()));
+    Ok(())
+}
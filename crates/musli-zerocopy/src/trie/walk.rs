@@ -172,7 +172,10 @@ where
 /// We use the fact that during construction the trie must have been provided a
 /// complete string reference, so any substring that we constructed must be
 /// prefixed with its complete counterpart.
-fn prefix_string<S>(string: S, prefix_len: usize) -> Result<Ref<[u8], Native, usize>, Error>
+pub(super) fn prefix_string<S>(
+    string: S,
+    prefix_len: usize,
+) -> Result<Ref<[u8], Native, usize>, Error>
 where
     S: Slice<Item = u8>,
 {
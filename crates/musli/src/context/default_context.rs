@@ -12,7 +12,7 @@ use crate::alloc::System;
 use crate::alloc::{self, Allocator, String, Vec};
 use crate::Context;
 
-use super::{Access, ErrorMarker, Shared};
+use super::{Access, Checkpoint, ErrorMarker, Shared};
 
 /// The default context which uses an allocator to track the location of errors.
 ///
@@ -128,6 +128,14 @@ where
         }
     }
 
+    /// The depth of the current path, including steps which are over capacity.
+    fn path_depth(&self) -> usize {
+        let _access = self.access.shared();
+
+        // SAFETY: We've checked that we have shared access just above.
+        unsafe { (*self.path.get()).len() + self.cap.get() }
+    }
+
     /// Pop the last path.
     fn pop_path(&self) {
         let cap = self.cap.get();
@@ -244,6 +252,37 @@ where
         self.mark.get()
     }
 
+    #[inline]
+    fn checkpoint(&self) -> Checkpoint {
+        let _access = self.access.shared();
+
+        // SAFETY: We've checked that we have shared access just above.
+        let errors = unsafe { (*self.errors.get()).len() };
+        Checkpoint::new(errors, self.path_depth())
+    }
+
+    fn restore(&self, checkpoint: Checkpoint) -> Option<Self::String<'_>> {
+        while self.path_depth() > checkpoint.depth() {
+            self.pop_path();
+        }
+
+        let _access = self.access.exclusive();
+
+        // SAFETY: We've checked that we have exclusive access just above.
+        let errors = unsafe { &mut (*self.errors.get()) };
+
+        let mut first = None;
+
+        while errors.len() > checkpoint.errors() {
+            first = errors.pop();
+        }
+
+        let (_, error) = first?;
+        let mut string = String::new_in(self.alloc);
+        string.write_str(&error).ok()?;
+        Some(string)
+    }
+
     #[inline]
     fn advance(&self, n: usize) {
         self.mark.set(self.mark.get().wrapping_add(n));
@@ -2,6 +2,9 @@
 //!
 //! [`Context`]: crate::Context
 
+#[doc(inline)]
+pub use musli_core::Checkpoint;
+
 mod access;
 use self::access::{Access, Shared};
 
@@ -102,6 +102,42 @@ where
     {
         E::message(message)
     }
+
+    #[inline]
+    fn untagged_mismatch(
+        &self,
+        name: &'static str,
+        errors: &[(&'static str, Self::Error, Option<Self::String<'_>>)],
+    ) -> Self::Error {
+        E::message(UntaggedMismatch { name, errors })
+    }
+}
+
+/// Lists the error produced by each attempted variant of an untagged enum.
+struct UntaggedMismatch<'a, E, S> {
+    name: &'static str,
+    errors: &'a [(&'static str, E, Option<S>)],
+}
+
+impl<E, S> fmt::Display for UntaggedMismatch<'_, E, S>
+where
+    E: fmt::Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Data did not match any variant of untagged enum {}",
+            self.name
+        )?;
+
+        for (index, (variant, error, _)) in self.errors.iter().enumerate() {
+            let sep = if index == 0 { ": " } else { ", " };
+            write!(f, "{sep}variant `{variant}`: {error}")?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "alloc")]
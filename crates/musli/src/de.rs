@@ -18,7 +18,8 @@
 
 #[doc(inline)]
 pub use musli_core::de::{
-    AsDecoder, Decode, DecodeBytes, DecodeOwned, DecodePacked, DecodeTrace, DecodeUnsized,
-    DecodeUnsizedBytes, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SequenceDecoder,
-    SizeHint, Skip, UnsizedVisitor, VariantDecoder, Visitor,
+    AsDecoder, Decode, DecodeBytes, DecodeElements, DecodeEntries, DecodeFlatten, DecodeOwned,
+    DecodePacked, DecodeTrace, DecodeUnsized, DecodeUnsizedBytes, DecodeWith, Decoder,
+    EntriesDecoder, EntryDecoder, MapDecoder, SequenceDecoder, SizeHint, Skip, UnsizedVisitor,
+    VariantDecoder, Visitor,
 };
@@ -363,6 +363,18 @@ where
                 self.0.visit_borrowed(cx, string)
             }
 
+            #[cfg(feature = "bytes")]
+            #[inline]
+            fn visit_shared(
+                self,
+                cx: &C,
+                shared: &'de bytes::Bytes,
+                bytes: &'de [u8],
+            ) -> Result<Self::Ok, C::Error> {
+                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                self.0.visit_shared(cx, shared, string)
+            }
+
             #[inline]
             fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
                 let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
@@ -887,6 +899,11 @@ where
     type DecodeKey<'this> = SelfDecoder<'a, R::Mut<'this>, OPT, C> where Self: 'this;
     type DecodeValue = Self;
 
+    #[inline]
+    fn deny_unknown_fields(&self) -> bool {
+        crate::options::is_deny_unknown_fields::<OPT>()
+    }
+
     #[inline]
     fn decode_key(&mut self) -> Result<Self::DecodeKey<'_>, C::Error> {
         Ok(SelfDecoder::new(self.cx, self.reader.borrow_mut()))
@@ -18,6 +18,6 @@
 
 #[doc(inline)]
 pub use musli_core::en::{
-    Encode, EncodeBytes, EncodePacked, EncodeTrace, Encoder, EntriesEncoder, EntryEncoder,
-    MapEncoder, SequenceEncoder, VariantEncoder,
+    Encode, EncodeBytes, EncodeElements, EncodeEntries, EncodeFlatten, EncodePacked, EncodeTrace,
+    EncodeWith, Encoder, EntriesEncoder, EntryEncoder, MapEncoder, SequenceEncoder, VariantEncoder,
 };
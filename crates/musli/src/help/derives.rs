@@ -107,6 +107,65 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(modes(<path>, ..))]`
+//!
+//! The attributes apply to every listed mode, which avoids having to repeat
+//! the same attribute block once per mode.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! enum Wide {}
+//! enum Compact {}
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(modes(Wide, Compact), name_all = "name")]
+//! struct Person<'a> {
+//!     #[musli(modes(Wide, Compact), name = "n")]
+//!     name: &'a str,
+//!     age: u32,
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(mode = <path>, inherit = <path>)]`
+//!
+//! Only valid on containers. Attributes which are not specified for `mode`
+//! anywhere in the type are looked up in the parent mode before falling back
+//! to the attributes that apply to all modes. Modes can inherit transitively,
+//! but not from themselves.
+//!
+//! Below, `Short` uses the field names declared for `Compact` except for `name`
+//! which it overrides:
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! enum Compact {}
+//! enum Short {}
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(mode = Compact, name_all = "name")]
+//! #[musli(mode = Short, inherit = Compact)]
+//! struct Person<'a> {
+//!     #[musli(mode = Compact, name = "n")]
+//!     #[musli(mode = Short, name = "s")]
+//!     name: &'a str,
+//!     #[musli(mode = Compact, name = "a")]
+//!     age: u32,
+//! }
+//!
+//! const SHORT: musli::json::Encoding<Short> = musli::json::Encoding::new().with_mode();
+//!
+//! let person = Person { name: "Aristotle", age: 61 };
+//! let out = SHORT.to_string(&person)?;
+//! assert_eq!(out, r#"{"s":"Aristotle","a":61}"#);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
 //! #### `#[musli(encode_only)]`
 //!
 //! The attributes only apply when implementing the `Encode` trait.
@@ -376,6 +435,187 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(deny_unknown_fields)]`
+//!
+//! Reject fields which are not recognized when decoding instead of skipping
+//! them, which is useful to catch typos in configuration files. The error is
+//! reported through [`Context::invalid_field_tag_expected`] which lists the
+//! names of the expected fields. When used on an enum this applies to the
+//! fields of every variant.
+//!
+//! Unknown fields can also be rejected for all types decoded by an encoding
+//! through [`options::Builder::with_deny_unknown_fields`].
+//!
+//! This cannot be combined with a field marked with
+//! `#[musli(unknown_fields)]`.
+//!
+//! ```
+//! use musli::Decode;
+//!
+//! #[derive(Debug, Decode)]
+//! #[musli(deny_unknown_fields, name_all = "name")]
+//! struct Config {
+//!     name: String,
+//!     #[musli(default)]
+//!     timeout: u32,
+//! }
+//!
+//! let error = musli::json::from_str::<Config>(r#"{"name":"server","tiemout":10}"#).unwrap_err();
+//! assert!(error.to_string().contains(r#"Invalid field tag "tiemout", expected one of "name", "timeout""#));
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(flattenable)]`
+//!
+//! Allow the struct to be used in a field marked with
+//! [`#[musli(flatten)]`][flatten] by implementing [`EncodeFlatten`] and
+//! [`DecodeFlatten`] for it.
+//!
+//! This is only supported on structs which are encoded as maps with the
+//! default name type. Since the entries of the struct are mixed with those of
+//! the enclosing container, its fields can't be named by their index, which is
+//! the default in [`Binary`] mode.
+//!
+//! [flatten]: #muslflatten
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(flattenable, name_all = "name")]
+//! struct Metadata {
+//!     created: u64,
+//!     updated: u64,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Document {
+//!     id: u32,
+//!     #[musli(flatten)]
+//!     metadata: Metadata,
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(from = <type>)]`, `#[musli(try_from = <type>)]` and `#[musli(into = <type>)]`
+//!
+//! Encode or decode the container through a proxy type instead of its fields.
+//! This is useful for types which uphold invariants that have to be checked
+//! when they are decoded. The type can also be specified as a string, like
+//! `#[musli(from = "T")]`.
+//!
+//! * `from` decodes the proxy type and converts it using [`From`].
+//! * `try_from` decodes the proxy type and converts it using [`TryFrom`]. A
+//!   conversion error is reported through [`Context::custom`], so it's
+//!   associated with the location of the value being decoded. The error type
+//!   therefore has to implement [`Error`].
+//! * `into` converts a clone of the container into the proxy type using
+//!   [`Into`] and encodes it, so the container has to implement [`Clone`].
+//!
+//! `from` and `try_from` cannot be combined.
+//!
+//! ```
+//! use std::fmt;
+//!
+//! use musli::{Decode, Encode};
+//!
+//! #[derive(Debug)]
+//! struct InvalidPort(u16);
+//!
+//! impl fmt::Display for InvalidPort {
+//!     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!         write!(f, "Port {} is reserved", self.0)
+//!     }
+//! }
+//!
+//! impl std::error::Error for InvalidPort {}
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+//! #[musli(try_from = u16, into = u16)]
+//! struct Port(u16);
+//!
+//! impl TryFrom<u16> for Port {
+//!     type Error = InvalidPort;
+//!
+//!     fn try_from(port: u16) -> Result<Self, Self::Error> {
+//!         if port < 1024 {
+//!             return Err(InvalidPort(port));
+//!         }
+//!
+//!         Ok(Port(port))
+//!     }
+//! }
+//!
+//! impl From<Port> for u16 {
+//!     fn from(port: Port) -> Self {
+//!         port.0
+//!     }
+//! }
+//!
+//! assert_eq!(musli::json::to_string(&Port(8080))?, "8080");
+//! assert_eq!(musli::json::from_str::<Port>("8080")?, Port(8080));
+//!
+//! let error = musli::json::from_str::<Port>("80").unwrap_err();
+//! assert!(error.to_string().contains("Port 80 is reserved"));
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(remote = <path>)]`
+//!
+//! Derive an implementation for a type defined in another crate, which can't
+//! implement [`Encode`] or [`Decode`] directly. The derive is placed on a
+//! mirror definition which has the same fields or variants as the foreign
+//! type, and instead of implementing the traits it generates `encode` and
+//! `decode` functions on the mirror so that it can be used through
+//! `#[musli(with = <path>)]`.
+//!
+//! The generated code accesses and constructs the foreign type directly, so
+//! it fails to compile if the mirror doesn't match it. Its fields therefore
+//! also need to be visible. Since the fields of the mirror are never read, it
+//! typically needs `#[allow(dead_code)]`.
+//!
+//! ```
+//! # mod example {
+//! use musli::{Decode, Encode};
+//!
+//! mod other {
+//!     pub struct Duration {
+//!         pub secs: u64,
+//!         pub nanos: u32,
+//!     }
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(remote = other::Duration)]
+//! #[allow(dead_code)]
+//! struct DurationDef {
+//!     secs: u64,
+//!     nanos: u32,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! struct Process {
+//!     #[musli(with = DurationDef)]
+//!     timeout: other::Duration,
+//! }
+//! # }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(validate = <path>)]`
+//!
+//! Call the given function with the container after it has been decoded. An
+//! error is associated with the whole container. See the field attribute of
+//! the same name for more details.
+//!
+//! <br>
+//!
 //! ## Enum attributes
 //!
 //! <br>
@@ -400,6 +640,26 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(untagged)]`
+//!
+//! This attribute causes the enum to be untagged. See [enum
+//! representations](#enum-representations) for details on this representation.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(untagged)]
+//! enum StringOrObject {
+//!     #[musli(transparent)]
+//!     String(String),
+//!     #[musli(name_all = "name")]
+//!     Object { name: String, count: u32 },
+//! }
+//! ```
+//!
+//! <br>
+//!
 //! ## Variant attributes
 //!
 //! *Variant attributes* are attributes which apply to each individual variant
@@ -589,6 +849,40 @@
 //! }
 //! ```
 //!
+//! The variant can also have two fields, in which case the first one captures
+//! the unknown tag and the second one its content. The captured tag and
+//! content are encoded again as they were, so that values with unknown
+//! variants can be passed through unchanged.
+//!
+//! The tag field must be the owned form of the container's name type, such as
+//! `String` for `str` names or `usize` for indexed names. The content can be
+//! any type which can decode the variant, like [`Value`]. A [`Value`] can be
+//! decoded from self-descriptive formats like [`descriptive`] and [`json`], and
+//! from [`wire`] which retains enough of the shape of the content to encode it
+//! again unchanged. It can't be decoded from [`storage`], which instead reports
+//! an error.
+//!
+//! This is only supported for externally tagged enums.
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//! use musli::value::Value;
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! enum Event {
+//!     #[musli(name_all = "name")]
+//!     Created { id: u32 },
+//!     #[musli(default)]
+//!     Unknown(String, Value),
+//! }
+//!
+//! let event: Event = musli::json::from_str(r#"{"Renamed":{"id":1}}"#)?;
+//! assert!(matches!(&event, Event::Unknown(tag, _) if tag == "Renamed"));
+//! assert_eq!(musli::json::to_string(&event)?, r#"{"Renamed":{"id":1}}"#);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
 //! <br>
 //!
 //! ## Field attributes
@@ -687,6 +981,90 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(flatten)]`
+//!
+//! Encode the entries of the field directly into the map of the enclosing
+//! container instead of nesting them under the field's own name. When decoding,
+//! any entry which isn't claimed by the enclosing container is offered to the
+//! flattened fields in declaration order.
+//!
+//! The field must implement [`EncodeFlatten`] and [`DecodeFlatten`], which is
+//! done for structs marked with [`#[musli(flattenable)]`][flattenable] and for
+//! map types such as `HashMap<String, T>`. A flattened map collects all
+//! remaining entries, so it should come last.
+//!
+//! Flattened fields share the names of the enclosing container, so both must
+//! use the same name type. Since the default names in [`Binary`] mode are
+//! field indexes which would conflict, the other fields of the container must
+//! be named through `#[musli(name_all = "..")]` or explicit names.
+//!
+//! This cannot be combined with `#[musli(packed)]` or `#[musli(transparent)]`
+//! containers.
+//!
+//! [flattenable]: #musliflattenable
+//!
+//! ```
+//! use std::collections::HashMap;
+//!
+//! use musli::{Encode, Decode};
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(flattenable, name_all = "name")]
+//! struct Metadata {
+//!     created: u64,
+//!     updated: u64,
+//! }
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Document {
+//!     id: u32,
+//!     #[musli(flatten)]
+//!     metadata: Metadata,
+//!     #[musli(flatten)]
+//!     other: HashMap<String, u32>,
+//! }
+//! ```
+//!
+//! <br>
+//!
+//! #### `#[musli(unknown_fields)]`
+//!
+//! Collect every entry which isn't claimed by the enclosing container or any
+//! of its flattened fields into the field, and write them back when encoding.
+//! This makes it possible to decode, modify and re-encode a value produced by
+//! a newer version of a type without silently dropping the data it added.
+//!
+//! This behaves like `#[musli(flatten)]`, except that the field is always
+//! offered entries last and only one such field is permitted in a container.
+//! It is typically used with [`UnknownFields`], which stores the name and the
+//! value of each entry as a [`Value`]. Since values are captured without
+//! knowing their type this requires a self-descriptive format such as
+//! [`descriptive`] or [`json`], or [`wire`] which retains enough of the shape
+//! of each value to encode it again unchanged. It's not supported by
+//! [`storage`].
+//!
+//! ```
+//! use musli::{Encode, Decode};
+//! use musli::value::UnknownFields;
+//!
+//! #[derive(Encode, Decode)]
+//! #[musli(name_all = "name")]
+//! struct Person {
+//!     name: String,
+//!     #[musli(unknown_fields)]
+//!     unknown: UnknownFields,
+//! }
+//!
+//! let json = r#"{"name":"Aristotle","age":61}"#;
+//! let person: Person = musli::json::from_str(json)?;
+//! assert_eq!(person.unknown.len(), 1);
+//! assert_eq!(musli::json::to_string(&person)?, json);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+//!
+//! <br>
+//!
 //! #### `#[musli(name = ..)]`
 //!
 //! This allows for renaming a field from its default value. It can take any
@@ -916,6 +1294,67 @@
 //!
 //! <br>
 //!
+//! #### `#[musli(with_elements = <path>)]`
+//!
+//! This is like `#[musli(with = <path>)]`, except that the
+//! module is used to encode and decode each element of a container rather than
+//! the container itself. It's supported for [`Option`] and standard sequences
+//! and sets such as [`Vec`], [`VecDeque`] and [`HashSet`], which implement
+//! [`EncodeElements`] and [`DecodeElements`].
+//!
+//! For maps, `#[musli(with_keys = <path>)]` and `#[musli(with_values =
+//! <path>)]` are used instead to adapt the keys and the values respectively,
+//! through [`EncodeEntries`] and [`DecodeEntries`]. Either or both can be
+//! specified, and the side which is not specified uses its default [`Encode`]
+//! or [`Decode`] implementation.
+//!
+//! These cannot be combined with `#[musli(with)]`, `#[musli(packed)]`,
+//! `#[musli(bytes)]` or `#[musli(trace)]`.
+//!
+//! ```
+//! # mod example {
+//! use std::collections::HashMap;
+//! use musli::{Encode, Decode};
+//!
+//! pub struct CustomUuid(u128);
+//!
+//! #[derive(Encode, Decode)]
+//! struct Struct {
+//!     #[musli(with_elements = self::custom_uuid)]
+//!     ids: Vec<CustomUuid>,
+//!     #[musli(with_elements = self::custom_uuid)]
+//!     parent: Option<CustomUuid>,
+//!     #[musli(with_keys = self::custom_uuid)]
+//!     names: HashMap<CustomUuid, String>,
+//! }
+//!
+//! mod custom_uuid {
+//!     use musli::{Decoder, Encoder};
+//!
+//!     use super::CustomUuid;
+//!
+//!     pub fn encode<E>(uuid: &CustomUuid, cx: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+//!     where
+//!         E: Encoder,
+//!     {
+//!         encoder.encode_u128(uuid.0)
+//!     }
+//!
+//!     pub fn decode<'de, D>(cx: &D::Cx, decoder: D) -> Result<CustomUuid, D::Error>
+//!     where
+//!         D: Decoder<'de>,
+//!     {
+//!         Ok(CustomUuid(decoder.decode_u128()?))
+//!     }
+//! }
+//! # impl PartialEq for CustomUuid { fn eq(&self, other: &Self) -> bool { self.0 == other.0 } }
+//! # impl Eq for CustomUuid {}
+//! # impl std::hash::Hash for CustomUuid { fn hash<H: std::hash::Hasher>(&self, state: &mut H) { self.0.hash(state) } }
+//! # }
+//! ```
+//!
+//! <br>
+//!
 //! #### `#[musli(skip_encoding_if = <path>)]`
 //!
 //! This adds a condition to skip encoding a field entirely if the condition is
@@ -932,6 +1371,60 @@
 //! }
 //! ```
 //!
+//! #### `#[musli(validate = <path>)]`
+//!
+//! Call the given function after the field has been decoded. If it returns an
+//! error, decoding fails with the error reported through
+//! [`Context::marked_message`] so that it's associated with the path and the
+//! byte range of the field. Fields which are skipped or missing and filled in
+//! using `#[musli(default)]` are not validated.
+//!
+//! The function receives the context and a reference to the decoded value,
+//! and can return any error which implements [`Display`].
+//!
+//! This can also be used as a container attribute to validate the whole
+//! container after it has been decoded.
+//!
+//! ```
+//! use musli::{Context, Decode, Encode};
+//!
+//! #[derive(Debug, Encode, Decode)]
+//! #[musli(validate = ordered)]
+//! struct Range {
+//!     #[musli(validate = positive)]
+//!     start: i32,
+//!     end: i32,
+//! }
+//!
+//! fn positive<C>(_: &C, value: &i32) -> Result<(), &'static str>
+//! where
+//!     C: ?Sized + Context,
+//! {
+//!     if *value < 0 {
+//!         return Err("Value must be positive");
+//!     }
+//!
+//!     Ok(())
+//! }
+//!
+//! fn ordered<C>(_: &C, range: &Range) -> Result<(), String>
+//! where
+//!     C: ?Sized + Context,
+//! {
+//!     if range.start > range.end {
+//!         return Err(format!("Start {} is after end {}", range.start, range.end));
+//!     }
+//!
+//!     Ok(())
+//! }
+//!
+//! let error = musli::json::from_str::<Range>(r#"{"start":-1,"end":2}"#).unwrap_err();
+//! assert!(error.to_string().contains("Value must be positive"));
+//!
+//! let error = musli::json::from_str::<Range>(r#"{"start":3,"end":2}"#).unwrap_err();
+//! assert!(error.to_string().contains("Start 3 is after end 2"));
+//! ```
+//!
 //! #### `#[musli(trace)]`
 //!
 //! This causes the field to use the [`DecodeTrace`] / [`EncodeTrace`] when
@@ -975,6 +1468,7 @@
 //! * Internally tagged when `#[musli(tag = ..)]` is specified on the enum.
 //! * Adjacently tagged when both `#[musli(tag = ..)]` and `#[musli(content)]`
 //!   are specified.
+//! * Untagged when `#[musli(untagged)]` is specified.
 //!
 //! <br>
 //!
@@ -1034,10 +1528,60 @@
 //! a map for the field corresponding to the `tag`, and then use this to
 //! determine which decoder implementation to call.
 //!
+//! <br>
+//!
+//! ## Untagged
+//!
+//! ```
+//! # use musli::{Encode, Decode};
+//! #[derive(Encode, Decode)]
+//! #[musli(untagged)]
+//! enum StringOrObject {
+//!     #[musli(transparent)]
+//!     String(String),
+//!     #[musli(name_all = "name")]
+//!     Object { name: String, count: u32 },
+//! }
+//! ```
+//!
+//! In JSON, the variants would be represented as:
+//!
+//! ```json
+//! "..."
+//! {"name": "...", "count": 42}
+//! ```
+//!
+//! Each variant is encoded as its body only, without any indication of which
+//! variant it is. When decoding, the value is buffered through
+//! [`Decoder::decode_buffer`] and each variant is attempted in the order they
+//! are declared. The first variant which decodes successfully is used, so more
+//! specific variants should come first.
+//!
+//! If no variant matches, the error lists why each variant failed. If the enum
+//! has a `#[musli(default)]` variant it is used instead of reporting an error.
+//!
+//! Like internally tagged enums, this is only supported by formats which are
+//! *self descriptive*.
+//!
 //! [`Binary`]: crate::mode::Binary
 //! [`Text`]: crate::mode::Text
+//! [`Value`]: crate::value::Value
+//! [`Context::custom`]: crate::Context::custom
+//! [`Context::marked_message`]: crate::Context::marked_message
+//! [`Context::invalid_field_tag_expected`]: crate::Context::invalid_field_tag_expected
+//! [`Display`]: core::fmt::Display
+//! [`Error`]: std::error::Error
+//! [`options::Builder::with_deny_unknown_fields`]: crate::options::Builder::with_deny_unknown_fields
+//! [`UnknownFields`]: crate::value::UnknownFields
+//! [`descriptive`]: crate::descriptive
+//! [`json`]: crate::json
+//! [`storage`]: crate::storage
+//! [`wire`]: crate::wire
 //! [`Decode`]: crate::Decode
 //! [`DecodeBytes`]: crate::de::DecodeBytes
+//! [`DecodeElements`]: crate::de::DecodeElements
+//! [`DecodeEntries`]: crate::de::DecodeEntries
+//! [`DecodeFlatten`]: crate::de::DecodeFlatten
 //! [`DecodePacked`]: crate::de::DecodePacked
 //! [`Decoder::decode_buffer`]: crate::Decoder::decode_buffer
 //! [`Decoder::decode_variant`]: crate::Decoder::decode_variant
@@ -1045,10 +1589,16 @@
 //! [`DecodeTrace`]: crate::de::DecodeTrace
 //! [`Encode`]: crate::Encode
 //! [`EncodeBytes`]: crate::en::EncodeBytes
+//! [`EncodeElements`]: crate::en::EncodeElements
+//! [`EncodeEntries`]: crate::en::EncodeEntries
+//! [`EncodeFlatten`]: crate::en::EncodeFlatten
 //! [`EncodePacked`]: crate::en::EncodePacked
 //! [`Encoder::encode_variant`]: crate::Encoder::encode_variant
 //! [`Encoder`]: crate::Encoder
 //! [`EncodeTrace`]: crate::en::EncodeTrace
+//! [`HashSet`]: std::collections::HashSet
+//! [`Vec`]: std::vec::Vec
+//! [`VecDeque`]: std::collections::VecDeque
 //! [default mode]: crate::mode::Binary
 
 // Parts of this documentation
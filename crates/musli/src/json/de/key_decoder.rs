@@ -4,6 +4,7 @@ use crate::alloc::Vec;
 use crate::de::{Decode, DecodeUnsized, Decoder, SizeHint, Skip, UnsizedVisitor, Visitor};
 use crate::Context;
 
+use super::super::encoding::OPTIONS;
 use super::super::parser::{Parser, Token};
 use super::{JsonDecoder, KeySignedVisitor, KeyUnsignedVisitor, StringReference};
 
@@ -86,7 +87,7 @@ where
 
     #[inline]
     fn skip(self) -> Result<(), C::Error> {
-        JsonDecoder::new(self.cx, self.parser).skip()
+        JsonDecoder::<_, OPTIONS, _>::new(self.cx, self.parser).skip()
     }
 
     #[inline]
@@ -160,7 +161,7 @@ where
     where
         V: UnsizedVisitor<'de, C, str>,
     {
-        JsonDecoder::new(self.cx, self.parser).decode_string(visitor)
+        JsonDecoder::<_, OPTIONS, _>::new(self.cx, self.parser).decode_string(visitor)
     }
 
     #[inline]
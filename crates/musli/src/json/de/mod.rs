@@ -27,11 +27,8 @@ use crate::de::{
     Decode, DecodeUnsized, Decoder, SequenceDecoder, SizeHint, Skip, UnsizedVisitor, Visitor,
 };
 use crate::hint::{MapHint, SequenceHint};
-#[cfg(feature = "value")]
 use crate::options;
-use crate::Context;
-#[cfg(feature = "value")]
-use crate::Options;
+use crate::{Context, Options};
 
 #[cfg(not(feature = "parse-full"))]
 use super::parser::integer::{
@@ -41,18 +38,18 @@ use super::parser::integer::{
 use super::parser::integer::{
     parse_signed_full as parse_signed, parse_unsigned_full as parse_unsigned,
 };
-use super::parser::{integer, Parser, StringReference, Token};
+use super::parser::{integer, visit_signed, visit_unsigned, Parser, StringReference, Token};
 
 #[cfg(feature = "value")]
 const BUFFER_OPTIONS: Options = options::new().with_map_keys_as_numbers(true).build();
 
 /// A JSON decoder for Müsli.
-pub(crate) struct JsonDecoder<'a, P, C: ?Sized> {
+pub(crate) struct JsonDecoder<'a, P, const OPT: Options, C: ?Sized> {
     cx: &'a C,
     parser: P,
 }
 
-impl<'a, 'de, P, C> JsonDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> JsonDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
@@ -100,10 +97,33 @@ where
     fn parse_null(mut self) -> Result<(), C::Error> {
         self.parser.parse_exact(self.cx, "null")
     }
+
+    /// Parse a number, passing on numbers which can't be represented by a
+    /// primitive integer exactly as they appear in the input.
+    fn parse_raw_number<V>(mut self, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        V: Visitor<'de, C>,
+    {
+        let mut scratch = Vec::new_in(self.cx.alloc());
+        integer::read_number(self.cx, self.parser.borrow_mut(), &mut scratch)?;
+        let number = str::from_utf8(scratch.as_slice()).map_err(self.cx.map())?;
+
+        if !number.bytes().any(|b| matches!(b, b'.' | b'e' | b'E')) {
+            if number.starts_with('-') {
+                if let Ok(value) = number.parse::<i128>() {
+                    return visit_signed(self.cx, visitor, value);
+                }
+            } else if let Ok(value) = number.parse::<u128>() {
+                return visit_unsigned(self.cx, visitor, value);
+            }
+        }
+
+        visitor.visit_raw_number(self.cx, number)
+    }
 }
 
 #[crate::decoder(crate)]
-impl<'a, 'de, P, C> Decoder<'de> for JsonDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> Decoder<'de> for JsonDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
@@ -111,15 +131,15 @@ where
     type Cx = C;
     type Error = C::Error;
     type Mode = C::Mode;
-    type WithContext<'this, U> = JsonDecoder<'this, P, U> where U: 'this + Context;
+    type WithContext<'this, U> = JsonDecoder<'this, P, OPT, U> where U: 'this + Context;
     #[cfg(feature = "value")]
     type DecodeBuffer = crate::value::AsValueDecoder<'a, BUFFER_OPTIONS, C>;
-    type DecodePack = JsonSequenceDecoder<'a, P, C>;
-    type DecodeSequence = JsonSequenceDecoder<'a, P, C>;
-    type DecodeMap = JsonObjectDecoder<'a, P, C>;
-    type DecodeMapEntries = JsonObjectDecoder<'a, P, C>;
-    type DecodeSome = JsonDecoder<'a, P, C>;
-    type DecodeVariant = JsonVariantDecoder<'a, P, C>;
+    type DecodePack = JsonSequenceDecoder<'a, P, OPT, C>;
+    type DecodeSequence = JsonSequenceDecoder<'a, P, OPT, C>;
+    type DecodeMap = JsonObjectDecoder<'a, P, OPT, C>;
+    type DecodeMapEntries = JsonObjectDecoder<'a, P, OPT, C>;
+    type DecodeSome = JsonDecoder<'a, P, OPT, C>;
+    type DecodeVariant = JsonVariantDecoder<'a, P, OPT, C>;
 
     #[inline]
     fn cx(&self) -> &Self::Cx {
@@ -441,9 +461,29 @@ where
     where
         V: Visitor<'de, C>,
     {
+        if options::is_raw_numbers::<OPT>() {
+            return self.parse_raw_number(visitor);
+        }
+
         self.parser.parse_number(self.cx, visitor)
     }
 
+    #[inline]
+    fn decode_raw_number<V>(mut self, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        V: UnsizedVisitor<'de, C, str>,
+    {
+        // Numbers are also permitted to be stored as strings.
+        if let Token::String = self.parser.lex(self.cx) {
+            return self.decode_string(visitor);
+        }
+
+        let mut scratch = Vec::new_in(self.cx.alloc());
+        integer::read_number(self.cx, self.parser.borrow_mut(), &mut scratch)?;
+        let number = str::from_utf8(scratch.as_slice()).map_err(self.cx.map())?;
+        visitor.visit_ref(self.cx, number)
+    }
+
     #[inline]
     fn decode_any<V>(mut self, visitor: V) -> Result<V::Ok, C::Error>
     where
@@ -2,12 +2,12 @@ use core::mem::{replace, take};
 
 use crate::de::{Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SizeHint};
 use crate::json::parser::{Parser, Token};
-use crate::Context;
+use crate::{Context, Options};
 
 use super::{JsonDecoder, JsonKeyDecoder, JsonObjectPairDecoder};
 
 #[must_use = "Must call skip_object_remaining to complete decoding"]
-pub(crate) struct JsonObjectDecoder<'a, P, C: ?Sized> {
+pub(crate) struct JsonObjectDecoder<'a, P, const OPT: Options, C: ?Sized> {
     cx: &'a C,
     first: bool,
     len: Option<usize>,
@@ -15,7 +15,7 @@ pub(crate) struct JsonObjectDecoder<'a, P, C: ?Sized> {
     finalized: bool,
 }
 
-impl<'a, 'de, P, C> JsonObjectDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> JsonObjectDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
@@ -110,16 +110,16 @@ where
     }
 }
 
-impl<'a, 'de, P, C> MapDecoder<'de> for JsonObjectDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> MapDecoder<'de> for JsonObjectDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
 {
     type Cx = C;
-    type DecodeEntry<'this> = JsonObjectPairDecoder<'a, P::Mut<'this>, C>
+    type DecodeEntry<'this> = JsonObjectPairDecoder<'a, P::Mut<'this>, OPT, C>
     where
         Self: 'this;
-    type DecodeRemainingEntries<'this> = JsonObjectDecoder<'a, P::Mut<'this>, C>
+    type DecodeRemainingEntries<'this> = JsonObjectDecoder<'a, P::Mut<'this>, OPT, C>
     where
         Self: 'this;
 
@@ -154,7 +154,7 @@ where
     }
 }
 
-impl<'a, 'de, P, C> EntriesDecoder<'de> for JsonObjectDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> EntriesDecoder<'de> for JsonObjectDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
@@ -163,7 +163,7 @@ where
     type DecodeEntryKey<'this> = JsonKeyDecoder<'a, P::Mut<'this>, C>
     where
         Self: 'this;
-    type DecodeEntryValue<'this> = JsonDecoder<'a, P::Mut<'this>, C> where Self: 'this;
+    type DecodeEntryValue<'this> = JsonDecoder<'a, P::Mut<'this>, OPT, C> where Self: 'this;
 
     #[inline]
     fn decode_entry_key(&mut self) -> Result<Option<Self::DecodeEntryKey<'_>>, C::Error> {
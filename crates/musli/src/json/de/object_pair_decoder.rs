@@ -1,22 +1,22 @@
 use crate::de::EntryDecoder;
 use crate::json::parser::{Parser, Token};
-use crate::Context;
+use crate::{Context, Options};
 
 use super::{JsonDecoder, JsonKeyDecoder};
 
-pub(crate) struct JsonObjectPairDecoder<'a, P, C: ?Sized> {
+pub(crate) struct JsonObjectPairDecoder<'a, P, const OPT: Options, C: ?Sized> {
     cx: &'a C,
     parser: P,
 }
 
-impl<'a, P, C: ?Sized> JsonObjectPairDecoder<'a, P, C> {
+impl<'a, P, const OPT: Options, C: ?Sized> JsonObjectPairDecoder<'a, P, OPT, C> {
     #[inline]
     pub(super) fn new(cx: &'a C, parser: P) -> Self {
         Self { cx, parser }
     }
 }
 
-impl<'a, 'de, P, C> EntryDecoder<'de> for JsonObjectPairDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> EntryDecoder<'de> for JsonObjectPairDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
@@ -25,7 +25,12 @@ where
     type DecodeKey<'this> = JsonKeyDecoder<'a, P::Mut<'this>, C>
     where
         Self: 'this;
-    type DecodeValue = JsonDecoder<'a, P, C>;
+    type DecodeValue = JsonDecoder<'a, P, OPT, C>;
+
+    #[inline]
+    fn deny_unknown_fields(&self) -> bool {
+        crate::options::is_deny_unknown_fields::<OPT>()
+    }
 
     #[inline]
     fn decode_key(&mut self) -> Result<Self::DecodeKey<'_>, C::Error> {
@@ -2,12 +2,12 @@ use core::mem;
 
 use crate::de::{Decoder, SequenceDecoder, SizeHint};
 use crate::json::parser::{Parser, Token};
-use crate::Context;
+use crate::{Context, Options};
 
 use super::JsonDecoder;
 
 #[must_use = "Must call skip_sequence_remaining"]
-pub(crate) struct JsonSequenceDecoder<'a, P, C: ?Sized> {
+pub(crate) struct JsonSequenceDecoder<'a, P, const OPT: Options, C: ?Sized> {
     cx: &'a C,
     len: Option<usize>,
     first: bool,
@@ -15,7 +15,7 @@ pub(crate) struct JsonSequenceDecoder<'a, P, C: ?Sized> {
     finalized: bool,
 }
 
-impl<'a, 'de, P, C> JsonSequenceDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> JsonSequenceDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
@@ -89,13 +89,13 @@ where
     }
 }
 
-impl<'a, 'de, P, C> SequenceDecoder<'de> for JsonSequenceDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> SequenceDecoder<'de> for JsonSequenceDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
 {
     type Cx = C;
-    type DecodeNext<'this> = JsonDecoder<'a, P::Mut<'this>, C>
+    type DecodeNext<'this> = JsonDecoder<'a, P::Mut<'this>, OPT, C>
     where
         Self: 'this;
 
@@ -1,15 +1,15 @@
 use crate::de::VariantDecoder;
 use crate::json::parser::{Parser, Token};
-use crate::Context;
+use crate::{Context, Options};
 
 use super::{JsonDecoder, JsonKeyDecoder};
 
-pub(crate) struct JsonVariantDecoder<'a, P, C: ?Sized> {
+pub(crate) struct JsonVariantDecoder<'a, P, const OPT: Options, C: ?Sized> {
     cx: &'a C,
     parser: P,
 }
 
-impl<'a, 'de, P, C> JsonVariantDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> JsonVariantDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
@@ -41,7 +41,7 @@ where
     }
 }
 
-impl<'a, 'de, P, C> VariantDecoder<'de> for JsonVariantDecoder<'a, P, C>
+impl<'a, 'de, P, const OPT: Options, C> VariantDecoder<'de> for JsonVariantDecoder<'a, P, OPT, C>
 where
     P: Parser<'de>,
     C: ?Sized + Context,
@@ -50,7 +50,7 @@ where
     type DecodeTag<'this> = JsonKeyDecoder<'a, P::Mut<'this>, C>
     where
         Self: 'this;
-    type DecodeValue<'this> = JsonDecoder<'a, P::Mut<'this>, C> where Self: 'this;
+    type DecodeValue<'this> = JsonDecoder<'a, P::Mut<'this>, OPT, C> where Self: 'this;
 
     #[inline]
     fn decode_tag(&mut self) -> Result<Self::DecodeTag<'_>, C::Error> {
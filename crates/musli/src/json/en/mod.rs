@@ -17,6 +17,7 @@ use core::fmt;
 
 use crate::en::{Encoder, SequenceEncoder};
 use crate::hint::{MapHint, SequenceHint};
+use crate::number::InvalidNumber;
 use crate::{Context, Encode, Writer};
 
 /// A JSON encoder for Müsli.
@@ -255,6 +256,15 @@ where
         self.encode_string(buf.as_ref())
     }
 
+    #[inline]
+    fn encode_raw_number(mut self, number: &str) -> Result<Self::Ok, C::Error> {
+        if !crate::number::is_valid(number.as_bytes()) {
+            return Err(self.cx.message(InvalidNumber));
+        }
+
+        self.writer.write_bytes(self.cx, number.as_bytes())
+    }
+
     #[inline]
     fn encode_some(self) -> Result<Self::EncodeSome, C::Error> {
         Ok(self)
@@ -9,15 +9,19 @@ use rust_alloc::string::String;
 use rust_alloc::vec::Vec;
 
 use crate::mode::Text;
-use crate::Decode;
+use crate::options;
 #[cfg(feature = "alloc")]
 use crate::{Context, Encode};
+use crate::{Decode, Options};
 
 use super::de::JsonDecoder;
 use super::en::JsonEncoder;
 use super::error::Error;
 use super::parser::IntoParser;
 
+/// The default flavor used by the [`DEFAULT`] configuration.
+pub const OPTIONS: options::Options = options::new().build();
+
 /// The default configuration.
 pub const DEFAULT: Encoding = Encoding::new();
 
@@ -92,21 +96,21 @@ where
 }
 
 /// Setting up encoding with parameters.
-pub struct Encoding<M = Text>
+pub struct Encoding<M = Text, const OPT: Options = OPTIONS>
 where
     M: 'static,
 {
     _marker: marker::PhantomData<M>,
 }
 
-impl Default for Encoding<Text> {
+impl Default for Encoding<Text, OPTIONS> {
     #[inline]
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Encoding<Text> {
+impl Encoding<Text, OPTIONS> {
     /// Construct a new [`Encoding`].
     ///
     /// You can modify this using the available factory methods:
@@ -148,7 +152,7 @@ impl Encoding<Text> {
     }
 }
 
-impl<M> Encoding<M>
+impl<M, const OPT: Options> Encoding<M, OPT>
 where
     M: 'static,
 {
@@ -163,7 +167,25 @@ where
     ///
     /// const CONFIG: Encoding<Custom> = Encoding::new().with_mode();
     /// ```
-    pub const fn with_mode<T>(self) -> Encoding<T> {
+    pub const fn with_mode<T>(self) -> Encoding<T, OPT> {
+        Encoding {
+            _marker: marker::PhantomData,
+        }
+    }
+
+    /// Change the options of the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::options::{self, Options};
+    /// use musli::json::Encoding;
+    /// use musli::mode::Text;
+    ///
+    /// const OPTIONS: Options = options::new().with_raw_numbers(true).build();
+    /// const CONFIG: Encoding<Text, OPTIONS> = Encoding::new().with_options();
+    /// ```
+    pub const fn with_options<const U: Options>(self) -> Encoding<M, U> {
         Encoding {
             _marker: marker::PhantomData,
         }
@@ -173,7 +195,7 @@ where
         M,
         json,
         JsonEncoder::new,
-        JsonDecoder::new,
+        JsonDecoder::<_, OPT, _>::new,
         IntoParser::into_parser
     );
 
@@ -267,11 +289,11 @@ where
     }
 }
 
-impl<M> Clone for Encoding<M> {
+impl<M, const OPT: Options> Clone for Encoding<M, OPT> {
     #[inline]
     fn clone(&self) -> Self {
         *self
     }
 }
 
-impl<M> Copy for Encoding<M> {}
+impl<M, const OPT: Options> Copy for Encoding<M, OPT> {}
@@ -1,3 +1,4 @@
+use crate::alloc::{Allocator, Vec};
 use crate::json::error::IntegerError;
 use crate::json::parser::Parser;
 use crate::Context;
@@ -261,6 +262,78 @@ where
     Ok(())
 }
 
+/// Read a well-formed JSON number into the scratch buffer exactly as it
+/// appears in the input.
+pub(crate) fn read_number<'de, P, C>(
+    cx: &C,
+    mut p: P,
+    scratch: &mut Vec<'_, u8, impl Allocator + ?Sized>,
+) -> Result<(), C::Error>
+where
+    P: Parser<'de>,
+    C: ?Sized + Context,
+{
+    p.skip_whitespace(cx);
+
+    let start = cx.mark();
+
+    let mut push = |cx: &C, p: &mut P| -> Result<u8, C::Error> {
+        let b = p.read_byte(cx)?;
+
+        if !scratch.push(b) {
+            return Err(cx.marked_message(start, "Buffer overflow"));
+        }
+
+        Ok(b)
+    };
+
+    if p.peek() == Some(b'-') {
+        push(cx, &mut p)?;
+    }
+
+    match push(cx, &mut p)? {
+        b'0' => (),
+        b if is_digit_nonzero(b) => {
+            while matches!(p.peek(), Some(b) if is_digit(b)) {
+                push(cx, &mut p)?;
+            }
+        }
+        _ => {
+            return Err(cx.marked_message(start, IntegerError::InvalidNumeric));
+        }
+    }
+
+    if p.peek() == Some(b'.') {
+        push(cx, &mut p)?;
+
+        if !is_digit(push(cx, &mut p)?) {
+            return Err(cx.marked_message(start, IntegerError::InvalidNumeric));
+        }
+
+        while matches!(p.peek(), Some(b) if is_digit(b)) {
+            push(cx, &mut p)?;
+        }
+    }
+
+    if matches!(p.peek(), Some(b'e') | Some(b'E')) {
+        push(cx, &mut p)?;
+
+        if matches!(p.peek(), Some(b'-') | Some(b'+')) {
+            push(cx, &mut p)?;
+        }
+
+        if !is_digit(push(cx, &mut p)?) {
+            return Err(cx.marked_message(start, IntegerError::InvalidNumeric));
+        }
+
+        while matches!(p.peek(), Some(b) if is_digit(b)) {
+            push(cx, &mut p)?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Partially parse an unsigned value.
 #[cfg_attr(feature = "parse-full", allow(unused))]
 #[inline(never)]
@@ -12,6 +12,7 @@ pub use self::into_parser::IntoParser;
 
 mod parser;
 pub use self::parser::Parser;
+pub(crate) use self::parser::{visit_signed, visit_unsigned};
 
 mod slice_parser;
 pub(crate) use self::slice_parser::SliceParser;
@@ -160,27 +160,7 @@ pub trait Parser<'de>: private::Sealed {
                 }
             };
 
-            if value >= i8::MIN as i128 && value <= i8::MAX as i128 {
-                return visitor.visit_i8(cx, value as i8);
-            }
-
-            if value >= i16::MIN as i128 && value <= i16::MAX as i128 {
-                return visitor.visit_i16(cx, value as i16);
-            }
-
-            if value >= i32::MIN as i128 && value <= i32::MAX as i128 {
-                return visitor.visit_i32(cx, value as i32);
-            }
-
-            if value >= i64::MIN as i128 && value <= i64::MAX as i128 {
-                return visitor.visit_i64(cx, value as i64);
-            }
-
-            if value >= isize::MIN as i128 && value <= isize::MAX as i128 {
-                return visitor.visit_isize(cx, value as isize);
-            }
-
-            visitor.visit_i128(cx, value)
+            visit_signed(cx, visitor, value)
         } else {
             let value = match signed.unsigned.compute() {
                 Ok(value) => value,
@@ -190,29 +170,67 @@ pub trait Parser<'de>: private::Sealed {
                 }
             };
 
-            if value <= u8::MAX as u128 {
-                return visitor.visit_u8(cx, value as u8);
-            }
+            visit_unsigned(cx, visitor, value)
+        }
+    }
+}
 
-            if value <= u16::MAX as u128 {
-                return visitor.visit_u16(cx, value as u16);
-            }
+/// Visit a signed integer using the smallest type which can represent it.
+pub(crate) fn visit_signed<'de, C, V>(cx: &C, visitor: V, value: i128) -> Result<V::Ok, C::Error>
+where
+    C: ?Sized + Context,
+    V: Visitor<'de, C>,
+{
+    if value >= i8::MIN as i128 && value <= i8::MAX as i128 {
+        return visitor.visit_i8(cx, value as i8);
+    }
 
-            if value <= u32::MAX as u128 {
-                return visitor.visit_u32(cx, value as u32);
-            }
+    if value >= i16::MIN as i128 && value <= i16::MAX as i128 {
+        return visitor.visit_i16(cx, value as i16);
+    }
 
-            if value <= u64::MAX as u128 {
-                return visitor.visit_u64(cx, value as u64);
-            }
+    if value >= i32::MIN as i128 && value <= i32::MAX as i128 {
+        return visitor.visit_i32(cx, value as i32);
+    }
 
-            if value <= usize::MAX as u128 {
-                return visitor.visit_usize(cx, value as usize);
-            }
+    if value >= i64::MIN as i128 && value <= i64::MAX as i128 {
+        return visitor.visit_i64(cx, value as i64);
+    }
 
-            visitor.visit_u128(cx, value)
-        }
+    if value >= isize::MIN as i128 && value <= isize::MAX as i128 {
+        return visitor.visit_isize(cx, value as isize);
+    }
+
+    visitor.visit_i128(cx, value)
+}
+
+/// Visit an unsigned integer using the smallest type which can represent it.
+pub(crate) fn visit_unsigned<'de, C, V>(cx: &C, visitor: V, value: u128) -> Result<V::Ok, C::Error>
+where
+    C: ?Sized + Context,
+    V: Visitor<'de, C>,
+{
+    if value <= u8::MAX as u128 {
+        return visitor.visit_u8(cx, value as u8);
+    }
+
+    if value <= u16::MAX as u128 {
+        return visitor.visit_u16(cx, value as u16);
+    }
+
+    if value <= u32::MAX as u128 {
+        return visitor.visit_u32(cx, value as u32);
     }
+
+    if value <= u64::MAX as u128 {
+        return visitor.visit_u64(cx, value as u64);
+    }
+
+    if value <= usize::MAX as u128 {
+        return visitor.visit_usize(cx, value as usize);
+    }
+
+    visitor.visit_u128(cx, value)
 }
 
 impl<'de, P> Parser<'de> for &mut P
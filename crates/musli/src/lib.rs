@@ -576,6 +576,8 @@ pub mod fixed;
 #[doc(inline)]
 pub use self::fixed::FixedBytes;
 
+pub mod number;
+
 pub mod options;
 #[doc(inline)]
 pub use self::options::Options;
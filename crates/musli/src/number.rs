@@ -0,0 +1,220 @@
+//! Arbitrary-precision numbers.
+//!
+//! The [`RawNumber`] type captures a number in its exact textual
+//! representation. Formats which have a textual number representation, like
+//! JSON, will decode it without going through a lossy fixed-size numerical
+//! type and encode it verbatim. Other formats store it as a string.
+//!
+//! ```
+//! use musli::{Decode, Encode};
+//! use musli::number::RawNumber;
+//!
+//! #[derive(Debug, PartialEq, Encode, Decode)]
+//! struct Account {
+//!     balance: RawNumber,
+//! }
+//!
+//! let json = r#"{"balance":123456789012345678901234567890.000000001}"#;
+//!
+//! let account: Account = musli::json::from_str(json)?;
+//! assert_eq!(account.balance.as_str(), "123456789012345678901234567890.000000001");
+//! assert_eq!(musli::json::to_string(&account)?, json);
+//! # Ok::<_, musli::json::Error>(())
+//! ```
+
+use core::fmt;
+#[cfg(feature = "alloc")]
+use core::str::FromStr;
+
+#[cfg(feature = "alloc")]
+use rust_alloc::borrow::ToOwned;
+#[cfg(feature = "alloc")]
+use rust_alloc::string::String;
+
+#[cfg(feature = "alloc")]
+use crate::de::{Decode, Decoder, UnsizedVisitor};
+#[cfg(feature = "alloc")]
+use crate::en::{Encode, Encoder};
+#[cfg(feature = "alloc")]
+use crate::Context;
+
+/// A number stored in its exact textual representation.
+///
+/// The textual representation follows the JSON number grammar, like `-12.5e3`.
+///
+/// See the [module level documentation][self] for more details.
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct RawNumber {
+    string: String,
+}
+
+#[cfg(feature = "alloc")]
+impl RawNumber {
+    /// Construct a new raw number from its textual representation.
+    ///
+    /// # Errors
+    ///
+    /// Errors if the string is not a valid number.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::number::RawNumber;
+    ///
+    /// let number = RawNumber::new("-12.5e3").unwrap();
+    /// assert_eq!(number.as_str(), "-12.5e3");
+    ///
+    /// assert!(RawNumber::new("12.").is_err());
+    /// assert!(RawNumber::new("NaN").is_err());
+    /// ```
+    pub fn new<S>(string: S) -> Result<Self, InvalidNumber>
+    where
+        S: Into<String>,
+    {
+        let string = string.into();
+
+        if !is_valid(string.as_bytes()) {
+            return Err(InvalidNumber);
+        }
+
+        Ok(Self { string })
+    }
+
+    /// Get the textual representation of the number.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// Coerce into the underlying string.
+    #[inline]
+    pub fn into_string(self) -> String {
+        self.string
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Display for RawNumber {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.string.fmt(f)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FromStr for RawNumber {
+    type Err = InvalidNumber;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<M> Encode<M> for RawNumber {
+    #[inline]
+    fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder<Mode = M>,
+    {
+        encoder.encode_raw_number(self.as_str())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'de, M> Decode<'de, M> for RawNumber {
+    #[inline]
+    fn decode<D>(_: &D::Cx, decoder: D) -> Result<Self, D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        struct Visitor;
+
+        impl<'de, C> UnsizedVisitor<'de, C, str> for Visitor
+        where
+            C: ?Sized + Context,
+        {
+            type Ok = RawNumber;
+
+            #[inline]
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a number")
+            }
+
+            #[inline]
+            fn visit_owned(self, cx: &C, string: String) -> Result<Self::Ok, C::Error> {
+                RawNumber::new(string).map_err(cx.map())
+            }
+
+            #[inline]
+            fn visit_ref(self, cx: &C, string: &str) -> Result<Self::Ok, C::Error> {
+                RawNumber::new(string.to_owned()).map_err(cx.map())
+            }
+        }
+
+        decoder.decode_raw_number(Visitor)
+    }
+}
+
+/// Error raised when a [`RawNumber`] is constructed from an invalid number.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct InvalidNumber;
+
+impl fmt::Display for InvalidNumber {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid number")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidNumber {}
+
+/// Test if the given bytes is a valid number according to the JSON number
+/// grammar.
+pub(crate) fn is_valid(bytes: &[u8]) -> bool {
+    let mut bytes = match bytes {
+        [b'-', rest @ ..] => rest,
+        rest => rest,
+    };
+
+    bytes = match bytes {
+        [b'0', rest @ ..] => rest,
+        [b'1'..=b'9', rest @ ..] => skip_digits(rest),
+        _ => return false,
+    };
+
+    if let [b'.', rest @ ..] = bytes {
+        bytes = match rest {
+            [b'0'..=b'9', rest @ ..] => skip_digits(rest),
+            _ => return false,
+        };
+    }
+
+    if let [b'e' | b'E', rest @ ..] = bytes {
+        let rest = match rest {
+            [b'-' | b'+', rest @ ..] => rest,
+            rest => rest,
+        };
+
+        bytes = match rest {
+            [b'0'..=b'9', rest @ ..] => skip_digits(rest),
+            _ => return false,
+        };
+    }
+
+    bytes.is_empty()
+}
+
+fn skip_digits(mut bytes: &[u8]) -> &[u8] {
+    while let [b'0'..=b'9', rest @ ..] = bytes {
+        bytes = rest;
+    }
+
+    bytes
+}
@@ -28,6 +28,8 @@ const BYTEORDER_BIT: Options = 0;
 const INTEGER_BIT: Options = 1;
 const LENGTH_BIT: Options = 2;
 const MAP_KEYS_AS_NUMBERS_BIT: Options = 3;
+const DENY_UNKNOWN_FIELDS_BIT: Options = 4;
+const RAW_NUMBERS_BIT: Options = 5;
 const FLOAT_BIT: Options = 8;
 const LENGTH_WIDTH_BIT: Options = 16;
 
@@ -68,6 +70,90 @@ impl Builder {
         Self((self.0 & !MASK) | (value << MAP_KEYS_AS_NUMBERS_BIT))
     }
 
+    /// Reject fields which are not recognized by the type being decoded
+    /// instead of skipping them.
+    ///
+    /// This applies to every type decoded through the [`Decode` derive], and
+    /// behaves as if they had been marked with
+    /// `#[musli(deny_unknown_fields)]`.
+    ///
+    /// [`Decode` derive]: crate::help::derives
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::{Decode, Encode};
+    /// use musli::descriptive::Encoding;
+    /// use musli::options::{self, Options};
+    ///
+    /// const OPTIONS: Options = options::new().with_deny_unknown_fields(true).build();
+    /// const STRICT: Encoding<OPTIONS> = Encoding::new().with_options();
+    ///
+    /// #[derive(Encode)]
+    /// #[musli(name_all = "name")]
+    /// struct Config {
+    ///     name: String,
+    ///     tiemout: u32,
+    /// }
+    ///
+    /// #[derive(Decode)]
+    /// #[musli(name_all = "name")]
+    /// struct ConfigStrict {
+    ///     name: String,
+    /// }
+    ///
+    /// let bytes = musli::descriptive::to_vec(&Config {
+    ///     name: String::from("server"),
+    ///     tiemout: 10,
+    /// })?;
+    ///
+    /// assert!(musli::descriptive::from_slice::<ConfigStrict>(&bytes).is_ok());
+    /// assert!(STRICT.from_slice::<ConfigStrict>(&bytes).is_err());
+    /// # Ok::<_, musli::descriptive::Error>(())
+    /// ```
+    #[inline(always)]
+    pub const fn with_deny_unknown_fields(self, value: bool) -> Self {
+        const MASK: Options = 0b1 << DENY_UNKNOWN_FIELDS_BIT;
+        let value = if value { 1 } else { 0 };
+        Self((self.0 & !MASK) | (value << DENY_UNKNOWN_FIELDS_BIT))
+    }
+
+    /// Decode numbers which can't be represented by a primitive integer in
+    /// their exact textual form through [`Visitor::visit_raw_number`].
+    ///
+    /// This is only supported by text formats like JSON, and affects dynamic
+    /// decoding like into a [`Value`], where for example `1.10` is otherwise
+    /// decoded as an `f64`.
+    ///
+    /// [`Visitor::visit_raw_number`]: crate::de::Visitor::visit_raw_number
+    /// [`Value`]: crate::value::Value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use musli::json::Encoding;
+    /// use musli::mode::Text;
+    /// use musli::number::RawNumber;
+    /// use musli::options::{self, Options};
+    /// use musli::value::{Number, Value};
+    ///
+    /// const OPTIONS: Options = options::new().with_raw_numbers(true).build();
+    /// const EXACT: Encoding<Text, OPTIONS> = Encoding::new().with_options();
+    ///
+    /// let value: Value = musli::json::from_str("1.10")?;
+    /// assert_eq!(value, Value::Number(Number::F64(1.1)));
+    ///
+    /// let value: Value = EXACT.from_str("1.10")?;
+    /// assert_eq!(value, Value::RawNumber("1.10".parse::<RawNumber>()?));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[inline(always)]
+    pub const fn with_raw_numbers(self, value: bool) -> Self {
+        const MASK: Options = 0b1 << RAW_NUMBERS_BIT;
+        let value = if value { 1 } else { 0 };
+        Self((self.0 & !MASK) | (value << RAW_NUMBERS_BIT))
+    }
+
     /// If length is set to [`Integer::Fixed`], specify the width of the length.
     #[inline(always)]
     pub const fn with_length_width(self, width: Width) -> Self {
@@ -161,6 +247,24 @@ pub(crate) const fn is_map_keys_as_numbers<const OPT: Options>() -> bool {
     ((OPT >> MAP_KEYS_AS_NUMBERS_BIT) & 0b1) == 1
 }
 
+#[cfg(any(
+    feature = "storage",
+    feature = "wire",
+    feature = "descriptive",
+    feature = "json",
+    feature = "value"
+))]
+#[inline(always)]
+pub(crate) const fn is_deny_unknown_fields<const OPT: Options>() -> bool {
+    ((OPT >> DENY_UNKNOWN_FIELDS_BIT) & 0b1) == 1
+}
+
+#[cfg(feature = "json")]
+#[inline(always)]
+pub(crate) const fn is_raw_numbers<const OPT: Options>() -> bool {
+    ((OPT >> RAW_NUMBERS_BIT) & 0b1) == 1
+}
+
 /// Integer serialization mode.
 #[cfg_attr(test, derive(Debug, PartialEq))]
 #[repr(u8)]
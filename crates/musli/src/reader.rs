@@ -17,6 +17,10 @@ mod sealed {
 
     impl Sealed for &[u8] {}
     impl Sealed for super::SliceReader<'_> {}
+    #[cfg(feature = "bytes")]
+    impl Sealed for super::BytesReader<'_> {}
+    #[cfg(feature = "bytes")]
+    impl Sealed for &bytes::Bytes {}
     impl<'de, R> Sealed for Limit<R> where R: Reader<'de> {}
     impl<'de, R> Sealed for &mut R where R: ?Sized + Reader<'de> {}
 }
@@ -419,6 +423,136 @@ where
     }
 }
 
+/// A [`Reader`] over a shared [`Bytes`] buffer.
+///
+/// Values which support it, like [`Bytes`] itself, are decoded by slicing the
+/// shared buffer instead of copying out of it.
+///
+/// This is constructed by using `&Bytes` as an [`IntoReader`].
+///
+/// [`Bytes`]: bytes::Bytes
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Bytes;
+/// use musli::{Decode, Encode};
+///
+/// #[derive(Encode, Decode)]
+/// struct Frame {
+///     id: u32,
+///     payload: Bytes,
+/// }
+///
+/// let encoded = musli::storage::to_vec(&Frame {
+///     id: 42,
+///     payload: Bytes::from_static(b"Hello World"),
+/// })?;
+///
+/// let shared = Bytes::from(encoded);
+/// let frame: Frame = musli::storage::decode(&shared)?;
+///
+/// assert_eq!(frame.id, 42);
+/// assert_eq!(frame.payload, b"Hello World"[..]);
+/// // The payload points into the shared buffer.
+/// assert!(shared.as_ptr_range().contains(&frame.payload.as_ptr()));
+/// # Ok::<_, musli::storage::Error>(())
+/// ```
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+pub struct BytesReader<'de> {
+    shared: &'de bytes::Bytes,
+    remaining: &'de [u8],
+}
+
+#[cfg(feature = "bytes")]
+impl<'de> BytesReader<'de> {
+    /// Construct a new reader over the specified shared buffer.
+    #[inline]
+    pub fn new(shared: &'de bytes::Bytes) -> Self {
+        Self {
+            shared,
+            remaining: shared.as_ref(),
+        }
+    }
+
+    /// Get the remaining contents of the reader as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &'de [u8] {
+        self.remaining
+    }
+
+    /// Get remaining bytes in the reader.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.remaining.len()
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'de> IntoReader<'de> for &'de bytes::Bytes {
+    type Reader = BytesReader<'de>;
+
+    #[inline]
+    fn into_reader(self) -> Self::Reader {
+        BytesReader::new(self)
+    }
+}
+
+#[cfg(feature = "bytes")]
+impl<'de> Reader<'de> for BytesReader<'de> {
+    type Mut<'this>
+        = &'this mut Self
+    where
+        Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn skip<C>(&mut self, cx: &C, n: usize) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.remaining.skip(cx, n)
+    }
+
+    #[inline]
+    fn peek(&mut self) -> Option<u8> {
+        self.remaining.first().copied()
+    }
+
+    #[inline]
+    fn read_bytes<C, V>(&mut self, cx: &C, n: usize, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        C: ?Sized + Context,
+        V: UnsizedVisitor<'de, C, [u8]>,
+    {
+        if self.remaining.len() < n {
+            return Err(cx.message(SliceUnderflow {
+                n,
+                remaining: self.remaining.len(),
+            }));
+        }
+
+        let (head, tail) = self.remaining.split_at(n);
+        self.remaining = tail;
+        let ok = visitor.visit_shared(cx, self.shared, head)?;
+        cx.advance(n);
+        Ok(ok)
+    }
+
+    #[inline]
+    fn read<C>(&mut self, cx: &C, buf: &mut [u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.remaining.read(cx, buf)
+    }
+}
+
 /// Limit the number of bytes that can be read out of a reader to the specified limit.
 ///
 /// Constructed through [Reader::limit].
@@ -5,7 +5,7 @@ use rust_alloc::vec::Vec;
 
 use crate::de::{
     DecodeUnsized, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SequenceDecoder, SizeHint,
-    UnsizedVisitor, VariantDecoder,
+    UnsizedVisitor, VariantDecoder, Visitor,
 };
 use crate::{Context, Decode, Options, Reader};
 
@@ -153,6 +153,18 @@ where
                 self.0.visit_borrowed(cx, string)
             }
 
+            #[cfg(feature = "bytes")]
+            #[inline]
+            fn visit_shared(
+                self,
+                cx: &C,
+                shared: &'de bytes::Bytes,
+                bytes: &'de [u8],
+            ) -> Result<Self::Ok, C::Error> {
+                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                self.0.visit_shared(cx, shared, string)
+            }
+
             #[inline]
             fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
                 let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
@@ -315,6 +327,16 @@ where
     {
         f(&mut self)
     }
+
+    #[inline]
+    fn decode_any<V>(self, _: V) -> Result<V::Ok, C::Error>
+    where
+        V: Visitor<'de, C>,
+    {
+        Err(self.cx.message(
+            "Values of unknown type cannot be decoded since the storage format is not self-descriptive",
+        ))
+    }
 }
 
 impl<'a, 'de, R, const OPT: Options, C: ?Sized + Context> SequenceDecoder<'de>
@@ -446,6 +468,11 @@ where
     type DecodeKey<'this> = StorageDecoder<'a, R::Mut<'this>, OPT, C> where Self: 'this;
     type DecodeValue = Self;
 
+    #[inline]
+    fn deny_unknown_fields(&self) -> bool {
+        crate::options::is_deny_unknown_fields::<OPT>()
+    }
+
     #[inline]
     fn decode_key(&mut self) -> Result<Self::DecodeKey<'_>, C::Error> {
         Ok(StorageDecoder::new(self.cx, self.reader.borrow_mut()))
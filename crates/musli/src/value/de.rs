@@ -53,6 +53,14 @@ macro_rules! ensure_number {
         match $self.value {
             Value::$variant($block) => <$ty>::from_number($block).map_err($self.cx.map_message()),
             #[cfg(feature = "alloc")]
+            Value::RawNumber(number) => match <$ty>::parse_number(number.as_str()) {
+                Some(value) => Ok(value),
+                None => {
+                    let $hint = $self.value.type_hint();
+                    Err($self.cx.message(ErrorMessage::$ident $tt))
+                }
+            },
+            #[cfg(feature = "alloc")]
             Value::String(string) if crate::options::is_map_keys_as_numbers::<$opt>() && $self.map_key => {
                 match <$ty>::parse_number(string) {
                     Some(value) => Ok(value),
@@ -222,12 +230,44 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
 
     #[inline]
     fn decode_f32(self) -> Result<f32, C::Error> {
-        ensure!(self, hint, ExpectedNumber(NumberHint::F32, hint), Value::Number(Number::F32(n)) => Ok(*n))
+        match self.value {
+            Value::Number(Number::F32(n)) => Ok(*n),
+            #[cfg(feature = "alloc")]
+            Value::RawNumber(number) => match f32::parse_number(number.as_str()) {
+                Some(value) => Ok(value),
+                None => Err(self.cx.message(ErrorMessage::ExpectedNumber(
+                    NumberHint::F32,
+                    TypeHint::Number(NumberHint::Decimal),
+                ))),
+            },
+            value => {
+                let hint = value.type_hint();
+                Err(self
+                    .cx
+                    .message(ErrorMessage::ExpectedNumber(NumberHint::F32, hint)))
+            }
+        }
     }
 
     #[inline]
     fn decode_f64(self) -> Result<f64, C::Error> {
-        ensure!(self, hint, ExpectedNumber(NumberHint::F64, hint), Value::Number(Number::F64(n)) => Ok(*n))
+        match self.value {
+            Value::Number(Number::F64(n)) => Ok(*n),
+            #[cfg(feature = "alloc")]
+            Value::RawNumber(number) => match f64::parse_number(number.as_str()) {
+                Some(value) => Ok(value),
+                None => Err(self.cx.message(ErrorMessage::ExpectedNumber(
+                    NumberHint::F64,
+                    TypeHint::Number(NumberHint::Decimal),
+                ))),
+            },
+            value => {
+                let hint = value.type_hint();
+                Err(self
+                    .cx
+                    .message(ErrorMessage::ExpectedNumber(NumberHint::F64, hint)))
+            }
+        }
     }
 
     #[cfg(feature = "alloc")]
@@ -260,6 +300,27 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
         })
     }
 
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn decode_raw_number<V>(self, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        V: UnsizedVisitor<'de, C, str>,
+    {
+        match self.value {
+            Value::RawNumber(number) => visitor.visit_borrowed(self.cx, number.as_str()),
+            Value::Number(number) => {
+                visitor.visit_ref(self.cx, self.cx.collect_string(number)?.as_ref())
+            }
+            Value::String(string) => visitor.visit_borrowed(self.cx, string),
+            value => {
+                let hint = value.type_hint();
+                Err(self
+                    .cx
+                    .message(ErrorMessage::ExpectedNumber(NumberHint::Decimal, hint)))
+            }
+        }
+    }
+
     #[cfg(feature = "alloc")]
     #[inline]
     fn decode_option(self) -> Result<Option<Self::DecodeSome>, C::Error> {
@@ -358,6 +419,8 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> Decoder<'de>
                 Number::F64(value) => visitor.visit_f64(self.cx, *value),
             },
             #[cfg(feature = "alloc")]
+            Value::RawNumber(number) => visitor.visit_raw_number(self.cx, number.as_str()),
+            #[cfg(feature = "alloc")]
             Value::Bytes(bytes) => {
                 let visitor = visitor.visit_bytes(self.cx, SizeHint::exact(bytes.len()))?;
                 visitor.visit_borrowed(self.cx, bytes)
@@ -540,6 +603,11 @@ impl<'a, 'de, C: ?Sized + Context, const OPT: Options> EntryDecoder<'de>
         Self: 'this;
     type DecodeValue = ValueDecoder<'a, 'de, OPT, C>;
 
+    #[inline]
+    fn deny_unknown_fields(&self) -> bool {
+        crate::options::is_deny_unknown_fields::<OPT>()
+    }
+
     #[inline]
     fn decode_key(&mut self) -> Result<Self::DecodeKey<'_>, C::Error> {
         Ok(ValueDecoder::with_map_key(self.cx, &self.pair.0))
@@ -11,6 +11,8 @@ use crate::en::{EntriesEncoder, EntryEncoder, MapEncoder, SequenceEncoder, Varia
 #[cfg(feature = "alloc")]
 use crate::hint::{MapHint, SequenceHint};
 #[cfg(feature = "alloc")]
+use crate::number::RawNumber;
+#[cfg(feature = "alloc")]
 use crate::storage::en::StorageEncoder;
 #[cfg(feature = "alloc")]
 use crate::writer::BufWriter;
@@ -259,6 +261,14 @@ where
         Ok(())
     }
 
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn encode_raw_number(self, number: &str) -> Result<Self::Ok, C::Error> {
+        let number = RawNumber::new(number).map_err(self.cx.map())?;
+        self.output.write(Value::RawNumber(number));
+        Ok(())
+    }
+
     #[inline]
     fn collect_string<T>(self, value: &T) -> Result<Self::Ok, <Self::Cx as Context>::Error>
     where
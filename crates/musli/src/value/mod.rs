@@ -12,13 +12,19 @@ mod de;
 mod en;
 mod error;
 mod type_hint;
+#[cfg(feature = "alloc")]
+mod unknown_fields;
 mod value;
 
 /// Convenient result alias for use with `musli_value`.
 pub type Result<T, E = Error> = core::result::Result<T, E>;
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "alloc")))]
 #[doc(inline)]
-pub use self::value::{AsValueDecoder, Value};
+pub use self::unknown_fields::UnknownFields;
+#[doc(inline)]
+pub use self::value::{AsValueDecoder, Number, Value};
 #[doc(inline)]
 pub use error::Error;
 
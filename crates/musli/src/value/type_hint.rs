@@ -90,6 +90,9 @@ pub(crate) enum NumberHint {
     F32,
     /// A 64-bit float.
     F64,
+    /// An arbitrary-precision number.
+    #[cfg(feature = "alloc")]
+    Decimal,
 }
 
 impl fmt::Display for NumberHint {
@@ -109,6 +112,8 @@ impl fmt::Display for NumberHint {
             NumberHint::Isize => write!(f, "isize"),
             NumberHint::F32 => write!(f, "f32"),
             NumberHint::F64 => write!(f, "f64"),
+            #[cfg(feature = "alloc")]
+            NumberHint::Decimal => write!(f, "decimal"),
         }
     }
 }
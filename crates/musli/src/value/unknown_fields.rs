@@ -0,0 +1,182 @@
+use rust_alloc::vec::Vec;
+
+use crate::de::{DecodeFlatten, Decoder};
+use crate::en::{EncodeFlatten, Encoder, MapEncoder};
+use crate::{Context, Encode};
+
+use super::en::ValueEncoder;
+use super::{Value, OPTIONS};
+
+/// A collection of fields which were not recognized while decoding a
+/// container.
+///
+/// This is used with the `#[musli(unknown_fields)]` attribute through the
+/// [`Decode` derive] to capture fields which would otherwise be skipped, so
+/// that they can be written back by the [`Encode` derive]. This permits a
+/// value which was produced by a newer version of a type to be decoded,
+/// modified and re-encoded without losing any data.
+///
+/// Both the name and the value of every entry is stored as a [`Value`] in the
+/// order in which they were decoded. Since entries are captured without
+/// knowing their type this requires a self-descriptive format such as
+/// [`descriptive`] or [`json`], or [`wire`] which retains enough of the shape
+/// of each value to encode it again unchanged. The [`storage`] format can't
+/// capture unknown fields and reports an error when it encounters one.
+///
+/// [`Decode` derive]: crate::help::derives
+/// [`Encode` derive]: crate::help::derives
+/// [`descriptive`]: crate::descriptive
+/// [`json`]: crate::json
+/// [`storage`]: crate::storage
+/// [`wire`]: crate::wire
+///
+/// # Examples
+///
+/// ```
+/// use musli::{Decode, Encode};
+/// use musli::value::UnknownFields;
+///
+/// #[derive(Decode, Encode)]
+/// #[musli(name_all = "name")]
+/// struct PersonV2 {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// #[derive(Decode, Encode)]
+/// #[musli(name_all = "name")]
+/// struct PersonV1 {
+///     name: String,
+///     #[musli(unknown_fields)]
+///     unknown: UnknownFields,
+/// }
+///
+/// let json = musli::json::to_string(&PersonV2 {
+///     name: String::from("Aristotle"),
+///     age: 61,
+/// })?;
+///
+/// let mut person: PersonV1 = musli::json::from_str(&json)?;
+/// assert_eq!(person.unknown.len(), 1);
+/// person.name = String::from("Plato");
+///
+/// let json = musli::json::to_string(&person)?;
+/// let person: PersonV2 = musli::json::from_str(&json)?;
+/// assert_eq!(person.name, "Plato");
+/// assert_eq!(person.age, 61);
+/// # Ok::<_, musli::json::Error>(())
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct UnknownFields {
+    fields: Vec<(Value, Value)>,
+}
+
+impl UnknownFields {
+    /// Construct a new empty collection of unknown fields.
+    #[inline]
+    pub const fn new() -> Self {
+        Self { fields: Vec::new() }
+    }
+
+    /// Get the number of unknown fields.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    /// Test if there are no unknown fields.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Get the value of the unknown field with the given name.
+    pub fn get(&self, name: &Value) -> Option<&Value> {
+        self.fields
+            .iter()
+            .find_map(|(key, value)| (key == name).then_some(value))
+    }
+
+    /// Iterate over the names and values of unknown fields in the order in
+    /// which they were decoded.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (&Value, &Value)> {
+        self.fields.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Insert an unknown field.
+    #[inline]
+    pub fn insert(&mut self, name: Value, value: Value) {
+        self.fields.push((name, value));
+    }
+
+    /// Remove all unknown fields.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.fields.clear();
+    }
+}
+
+impl<M> EncodeFlatten<M> for UnknownFields {
+    #[inline]
+    fn flatten_len(&self) -> usize {
+        self.fields.len()
+    }
+
+    #[inline]
+    fn encode_flatten<E>(&self, _: &E::Cx, encoder: &mut E) -> Result<(), <E::Cx as Context>::Error>
+    where
+        E: MapEncoder<Cx: Context<Mode = M>>,
+    {
+        for (name, value) in &self.fields {
+            encoder.insert_entry(name, value)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<'de, M, N> DecodeFlatten<'de, M, N> for UnknownFields
+where
+    N: ?Sized + Encode<M>,
+{
+    type Tag = Value;
+    type State = Self;
+
+    #[inline]
+    fn flatten_state() -> Self::State {
+        Self::new()
+    }
+
+    #[inline]
+    fn flatten_tag<C>(cx: &C, name: &N) -> Result<Option<Self::Tag>, C::Error>
+    where
+        C: ?Sized + Context<Mode = M>,
+    {
+        let mut tag = Value::Unit;
+        ValueEncoder::<OPTIONS, _, _>::new(cx, &mut tag).encode(name)?;
+        Ok(Some(tag))
+    }
+
+    #[inline]
+    fn decode_flatten_entry<D>(
+        _: &D::Cx,
+        state: &mut Self::State,
+        tag: Self::Tag,
+        decoder: D,
+    ) -> Result<(), D::Error>
+    where
+        D: Decoder<'de, Mode = M>,
+    {
+        state.fields.push((tag, decoder.decode()?));
+        Ok(())
+    }
+
+    #[inline]
+    fn decode_flatten_finish<C>(_: &C, state: Self::State) -> Result<Self, C::Error>
+    where
+        C: ?Sized + Context<Mode = M>,
+    {
+        Ok(state)
+    }
+}
@@ -7,6 +7,8 @@ use rust_alloc::string::String;
 #[cfg(feature = "alloc")]
 use rust_alloc::vec::Vec;
 
+use core::fmt;
+
 use crate::de::{AsDecoder, Decode, Decoder, Visitor};
 #[cfg(feature = "alloc")]
 use crate::de::{
@@ -15,6 +17,8 @@ use crate::de::{
 use crate::en::{Encode, Encoder};
 #[cfg(feature = "alloc")]
 use crate::en::{MapEncoder, SequenceEncoder, VariantEncoder};
+#[cfg(feature = "alloc")]
+use crate::number::RawNumber;
 use crate::{Context, Options};
 
 use super::de::ValueDecoder;
@@ -35,6 +39,14 @@ pub enum Value {
     Char(char),
     /// A number.
     Number(Number),
+    /// A number in its exact textual representation.
+    ///
+    /// This is only produced by formats which support it when explicitly
+    /// requested, like JSON with [`with_raw_numbers`] enabled.
+    ///
+    /// [`with_raw_numbers`]: crate::options::Builder::with_raw_numbers
+    #[cfg(feature = "alloc")]
+    RawNumber(RawNumber),
     /// An array.
     #[cfg(feature = "alloc")]
     Bytes(Vec<u8>),
@@ -84,6 +96,8 @@ impl Value {
             Value::Char(..) => TypeHint::Char,
             Value::Number(number) => TypeHint::Number(number.type_hint()),
             #[cfg(feature = "alloc")]
+            Value::RawNumber(..) => TypeHint::Number(NumberHint::Decimal),
+            #[cfg(feature = "alloc")]
             Value::Bytes(bytes) => TypeHint::Bytes(SizeHint::exact(bytes.len())),
             #[cfg(feature = "alloc")]
             Value::String(string) => TypeHint::String(SizeHint::exact(string.len())),
@@ -99,6 +113,7 @@ impl Value {
     }
 }
 
+/// A number stored in a [`Value`].
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
 #[non_exhaustive]
 pub enum Number {
@@ -157,6 +172,27 @@ from!(isize, Isize);
 from!(f32, F32);
 from!(f64, F64);
 
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Number::U8(n) => n.fmt(f),
+            Number::U16(n) => n.fmt(f),
+            Number::U32(n) => n.fmt(f),
+            Number::U64(n) => n.fmt(f),
+            Number::U128(n) => n.fmt(f),
+            Number::I8(n) => n.fmt(f),
+            Number::I16(n) => n.fmt(f),
+            Number::I32(n) => n.fmt(f),
+            Number::I64(n) => n.fmt(f),
+            Number::I128(n) => n.fmt(f),
+            Number::Usize(n) => n.fmt(f),
+            Number::Isize(n) => n.fmt(f),
+            Number::F32(n) => n.fmt(f),
+            Number::F64(n) => n.fmt(f),
+        }
+    }
+}
+
 impl<M> Encode<M> for Number {
     fn encode<E>(&self, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
     where
@@ -303,6 +339,13 @@ impl<'de, C: ?Sized + Context> Visitor<'de, C> for AnyVisitor {
         Ok(Value::Number(Number::F64(value)))
     }
 
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn visit_raw_number(self, cx: &C, number: &str) -> Result<Self::Ok, C::Error> {
+        let number = RawNumber::new(number).map_err(cx.map())?;
+        Ok(Value::RawNumber(number))
+    }
+
     #[cfg(feature = "alloc")]
     #[inline]
     fn visit_option<D>(self, _: &C, decoder: Option<D>) -> Result<Self::Ok, C::Error>
@@ -439,6 +482,8 @@ impl<M> Encode<M> for Value {
             Value::Char(c) => encoder.encode_char(*c),
             Value::Number(n) => encoder.encode(n),
             #[cfg(feature = "alloc")]
+            Value::RawNumber(n) => encoder.encode_raw_number(n.as_str()),
+            #[cfg(feature = "alloc")]
             Value::Bytes(bytes) => encoder.encode_bytes(bytes),
             #[cfg(feature = "alloc")]
             Value::String(string) => encoder.encode_string(string),
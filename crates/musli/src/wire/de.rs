@@ -6,7 +6,7 @@ use rust_alloc::vec::Vec;
 
 use crate::de::{
     Decode, DecodeUnsized, Decoder, EntriesDecoder, EntryDecoder, MapDecoder, SequenceDecoder,
-    SizeHint, Skip, UnsizedVisitor, VariantDecoder,
+    SizeHint, Skip, UnsizedVisitor, VariantDecoder, Visitor,
 };
 use crate::hint::{MapHint, SequenceHint};
 use crate::int::continuation as c;
@@ -343,6 +343,18 @@ where
                 self.0.visit_borrowed(cx, string)
             }
 
+            #[cfg(feature = "bytes")]
+            #[inline]
+            fn visit_shared(
+                self,
+                cx: &C,
+                shared: &'de bytes::Bytes,
+                bytes: &'de [u8],
+            ) -> Result<Self::Ok, C::Error> {
+                let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
+                self.0.visit_shared(cx, shared, string)
+            }
+
             #[inline]
             fn visit_ref(self, cx: &C, bytes: &[u8]) -> Result<Self::Ok, C::Error> {
                 let string = crate::str::from_utf8(bytes).map_err(cx.map())?;
@@ -531,6 +543,55 @@ where
 
         f(&mut self)
     }
+
+    /// Decode a value of unknown type by its wire representation.
+    ///
+    /// Since the wire format only retains the shape of values, prefixed values
+    /// such as strings are visited as bytes, continuations as unsigned numbers
+    /// and sequences as sequences. Encoding them again produces the same bytes
+    /// as long as variable-width integers are used, which is the default.
+    #[inline]
+    fn decode_any<V>(mut self, visitor: V) -> Result<V::Ok, C::Error>
+    where
+        V: Visitor<'de, C>,
+    {
+        let cx = self.cx;
+
+        let Some(tag) = self.reader.peek().map(Tag::from_byte) else {
+            return Err(cx.message("Expected tag in input"));
+        };
+
+        match tag.kind() {
+            Kind::Prefix => {
+                let hint = tag
+                    .data()
+                    .map(|d| SizeHint::exact(d as usize))
+                    .unwrap_or_default();
+                let visitor = visitor.visit_bytes(cx, hint)?;
+                self.decode_bytes(visitor)
+            }
+            Kind::Sequence => {
+                let mut sequence = self.shared_decode_sequence()?;
+                let output = visitor.visit_sequence(cx, &mut sequence)?;
+                sequence.skip_sequence_remaining()?;
+                Ok(output)
+            }
+            Kind::Continuation => {
+                self.reader.skip(cx, 1)?;
+
+                let value = match tag.data() {
+                    Some(data) => u128::from(data),
+                    None => c::decode(cx, self.reader.borrow_mut())?,
+                };
+
+                match u64::try_from(value) {
+                    Ok(value) => visitor.visit_u64(cx, value),
+                    Err(..) => visitor.visit_u128(cx, value),
+                }
+            }
+            kind => Err(cx.message(format_args!("Unsupported kind {kind:?}"))),
+        }
+    }
 }
 
 impl<'a, 'de, R, const OPT: Options, C> SequenceDecoder<'de> for WireDecoder<'a, Limit<R>, OPT, C>
@@ -653,6 +714,11 @@ where
     type DecodeKey<'this> = WireDecoder<'a, R::Mut<'this>, OPT, C> where Self: 'this;
     type DecodeValue = Self;
 
+    #[inline]
+    fn deny_unknown_fields(&self) -> bool {
+        crate::options::is_deny_unknown_fields::<OPT>()
+    }
+
     #[inline]
     fn decode_key(&mut self) -> Result<Self::DecodeKey<'_>, C::Error> {
         Ok(WireDecoder::new(self.cx, self.reader.borrow_mut()))
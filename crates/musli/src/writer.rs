@@ -124,6 +124,39 @@ impl Writer for rust_alloc::vec::Vec<u8> {
     }
 }
 
+#[cfg(feature = "bytes")]
+#[cfg_attr(doc_cfg, doc(cfg(feature = "bytes")))]
+impl Writer for bytes::BytesMut {
+    type Mut<'this>
+        = &'this mut Self
+    where
+        Self: 'this;
+
+    #[inline]
+    fn borrow_mut(&mut self) -> Self::Mut<'_> {
+        self
+    }
+
+    #[inline]
+    fn extend<C>(&mut self, cx: &C, buffer: Vec<'_, u8, C::Allocator>) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        // SAFETY: the buffer never outlives this function call.
+        self.write_bytes(cx, buffer.as_slice())
+    }
+
+    #[inline]
+    fn write_bytes<C>(&mut self, cx: &C, bytes: &[u8]) -> Result<(), C::Error>
+    where
+        C: ?Sized + Context,
+    {
+        self.extend_from_slice(bytes);
+        cx.advance(bytes.len());
+        Ok(())
+    }
+}
+
 impl Writer for &mut [u8] {
     type Mut<'this> = &'this mut Self where Self: 'this;
 
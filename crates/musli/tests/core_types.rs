@@ -0,0 +1,96 @@
+#![cfg(feature = "test")]
+
+use core::cell::{Cell, RefCell};
+use core::cmp::{Ordering, Reverse};
+use core::num::Saturating;
+use core::ops::{Bound, ControlFlow};
+use core::time::Duration;
+use std::sync::{Mutex, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct CoreTypes {
+    duration: Duration,
+    cell: Cell<u32>,
+    ref_cell: RefCell<String>,
+    included: Bound<u32>,
+    excluded: Bound<u32>,
+    unbounded: Bound<u32>,
+    reverse: Reverse<u32>,
+    saturating: Saturating<u8>,
+    continue_flow: ControlFlow<String, u32>,
+    break_flow: ControlFlow<String, u32>,
+    less: Ordering,
+    equal: Ordering,
+    greater: Ordering,
+}
+
+#[test]
+fn core_types() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        CoreTypes {
+            duration: Duration::new(42, 500_000_000),
+            cell: Cell::new(42),
+            ref_cell: RefCell::new(String::from("Hello")),
+            included: Bound::Included(1),
+            excluded: Bound::Excluded(2),
+            unbounded: Bound::Unbounded,
+            reverse: Reverse(42),
+            saturating: Saturating(255),
+            continue_flow: ControlFlow::Continue(42),
+            break_flow: ControlFlow::Break(String::from("World")),
+            less: Ordering::Less,
+            equal: Ordering::Equal,
+            greater: Ordering::Greater,
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct SystemTimes {
+    epoch: SystemTime,
+    after: SystemTime,
+    before: SystemTime,
+    before_exact: SystemTime,
+}
+
+#[test]
+fn system_time() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        SystemTimes {
+            epoch: UNIX_EPOCH,
+            after: UNIX_EPOCH + Duration::new(1_700_000_000, 123_456_789),
+            before: UNIX_EPOCH - Duration::new(10, 250_000_000),
+            before_exact: UNIX_EPOCH - Duration::from_secs(10),
+        }
+    );
+}
+
+#[test]
+fn duration_text() {
+    musli::macros::assert_roundtrip_eq!(full, Duration::from_millis(1500), json = "1.5");
+}
+
+#[derive(Debug, Encode, Decode)]
+pub struct Locks {
+    mutex: Mutex<String>,
+    rw_lock: RwLock<Vec<u32>>,
+}
+
+#[test]
+fn locks() {
+    let locks = Locks {
+        mutex: Mutex::new(String::from("Hello")),
+        rw_lock: RwLock::new(vec![1, 2, 3]),
+    };
+
+    let bytes = musli::wire::to_vec(&locks).unwrap();
+    let locks: Locks = musli::wire::from_slice(&bytes).unwrap();
+
+    assert_eq!(locks.mutex.into_inner().unwrap(), "Hello");
+    assert_eq!(locks.rw_lock.into_inner().unwrap(), [1, 2, 3]);
+}
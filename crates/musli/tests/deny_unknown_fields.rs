@@ -0,0 +1,158 @@
+#![cfg(feature = "test")]
+
+use musli::mode::Text;
+use musli::options::{self, Options};
+use musli::{Decode, Encode};
+
+const OPTIONS: Options = options::new().with_deny_unknown_fields(true).build();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(deny_unknown_fields)]
+pub struct ConfigStrict {
+    name: String,
+    #[musli(default)]
+    timeout: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Extended {
+    name: String,
+    timeout: u32,
+    retries: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Basic {
+    name: String,
+    timeout: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct ExtendedNamed {
+    name: String,
+    timeout: u32,
+    retries: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct BasicNamed {
+    name: String,
+    timeout: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(deny_unknown_fields)]
+pub enum Command {
+    Start { name: String },
+}
+
+#[test]
+fn deny_unknown_fields_attribute() {
+    let error = musli::json::from_str::<ConfigStrict>(r#"{"name":"server","tiemout":10}"#)
+        .unwrap_err()
+        .to_string();
+
+    assert!(
+        error.contains(r#"Invalid field tag "tiemout", expected one of "name", "timeout""#),
+        "{error}"
+    );
+
+    let config: ConfigStrict = musli::json::from_str(r#"{"name":"server","timeout":10}"#).unwrap();
+    assert_eq!(
+        config,
+        ConfigStrict {
+            name: String::from("server"),
+            timeout: 10
+        }
+    );
+
+    let error = musli::json::from_str::<Command>(r#"{"Start":{"name":"a","extra":1}}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains(r#"Invalid field tag "extra""#), "{error}");
+}
+
+#[test]
+fn deny_unknown_fields_options() {
+    const STRICT: musli::descriptive::Encoding<OPTIONS> =
+        musli::descriptive::Encoding::new().with_options();
+
+    let bytes = STRICT
+        .to_vec(&Extended {
+            name: String::from("server"),
+            timeout: 10,
+            retries: 3,
+        })
+        .unwrap();
+
+    let basic: Basic = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(basic.timeout, 10);
+
+    let error = STRICT.from_slice::<Basic>(&bytes).unwrap_err().to_string();
+    assert!(
+        error.contains("Invalid field tag 2, expected one of 0, 1"),
+        "{error}"
+    );
+
+    let bytes = STRICT
+        .to_vec(&ExtendedNamed {
+            name: String::from("server"),
+            timeout: 10,
+            retries: 3,
+        })
+        .unwrap();
+
+    let basic: BasicNamed = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(basic.timeout, 10);
+
+    let error = STRICT
+        .from_slice::<BasicNamed>(&bytes)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains(r#"Invalid field tag "retries", expected one of "name", "timeout""#),
+        "{error}"
+    );
+}
+
+#[test]
+fn deny_unknown_fields_json_options() {
+    const STRICT: musli::json::Encoding<Text, OPTIONS> =
+        musli::json::Encoding::new().with_options();
+
+    let json = r#"{"name":"server","timeout":10,"retries":3}"#;
+
+    let basic: Basic = musli::json::from_str(json).unwrap();
+    assert_eq!(basic.timeout, 10);
+
+    let error = STRICT.from_str::<Basic>(json).unwrap_err().to_string();
+    assert!(
+        error.contains(r#"Invalid field tag "retries", expected one of "name", "timeout""#),
+        "{error}"
+    );
+
+    let basic: Basic = STRICT
+        .from_str(r#"{"name":"server","timeout":10}"#)
+        .unwrap();
+    assert_eq!(basic.timeout, 10);
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(deny_unknown_fields, name_all = "name")]
+pub struct Empty {}
+
+#[test]
+fn deny_unknown_fields_empty() {
+    let empty: Empty = musli::json::from_str("{}").unwrap();
+    assert_eq!(empty, Empty {});
+
+    let error = musli::json::from_str::<Empty>(r#"{"field":1}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(
+        error.contains(r#"Invalid field tag "field", expected no fields"#),
+        "{error}"
+    );
+}
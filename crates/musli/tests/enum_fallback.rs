@@ -1,5 +1,6 @@
 #![cfg(feature = "test")]
 
+use musli::value::Value;
 use musli::{Decode, Encode};
 
 #[derive(Debug, PartialEq, Encode, Decode)]
@@ -91,3 +92,89 @@ fn enum_pattern() {
         json = r#"3"#,
     );
 }
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum EventV2 {
+    #[musli(name_all = "name")]
+    Created { id: u32 },
+    #[musli(name_all = "name")]
+    Renamed { id: u32, name: String },
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum EventV1 {
+    #[musli(name_all = "name")]
+    Created { id: u32 },
+    #[musli(default)]
+    Unknown(String, Value),
+}
+
+#[test]
+fn enum_fallback_capture() {
+    let json = r#"{"Renamed":{"id":1,"name":"new"}}"#;
+
+    let event: EventV1 = musli::json::from_str(json).unwrap();
+    let EventV1::Unknown(tag, _) = &event else {
+        panic!("expected unknown variant, got {event:?}");
+    };
+    assert_eq!(tag, "Renamed");
+    assert_eq!(musli::json::to_string(&event).unwrap(), json);
+
+    let event: EventV1 = musli::json::from_str(r#"{"Created":{"id":1}}"#).unwrap();
+    assert_eq!(event, EventV1::Created { id: 1 });
+
+    let renamed = EventV2::Renamed {
+        id: 2,
+        name: String::from("other"),
+    };
+
+    let bytes = musli::descriptive::to_vec(&renamed).unwrap();
+    let event: EventV1 = musli::descriptive::from_slice(&bytes).unwrap();
+    assert!(matches!(&event, EventV1::Unknown(tag, _) if tag == "Renamed"));
+
+    let bytes = musli::descriptive::to_vec(&event).unwrap();
+    let decoded: EventV2 = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(decoded, renamed);
+
+    let bytes = musli::wire::to_vec(&renamed).unwrap();
+    let event: EventV1 = musli::wire::from_slice(&bytes).unwrap();
+    assert!(matches!(&event, EventV1::Unknown(tag, _) if tag == "Renamed"));
+    assert_eq!(musli::wire::to_vec(&event).unwrap(), bytes);
+
+    let decoded: EventV2 = musli::wire::from_slice(&bytes).unwrap();
+    assert_eq!(decoded, renamed);
+
+    let bytes = musli::storage::to_vec(&renamed).unwrap();
+    let error = musli::storage::from_slice::<EventV1>(&bytes).unwrap_err();
+    assert!(
+        error.to_string().contains("not self-descriptive"),
+        "{error}"
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_type = usize)]
+pub enum EnumCapture {
+    #[musli(name = 3)]
+    Variant4,
+    #[musli(default)]
+    Fallback { tag: usize, content: Value },
+}
+
+#[test]
+fn enum_fallback_capture_index() {
+    let event: EnumCapture = musli::json::from_str(r#"{"1":{"0":"hello"}}"#).unwrap();
+    let EnumCapture::Fallback { tag, .. } = &event else {
+        panic!("expected fallback variant, got {event:?}");
+    };
+    assert_eq!(*tag, 1);
+    assert_eq!(
+        musli::json::to_string(&event).unwrap(),
+        r#"{"1":{"0":"hello"}}"#
+    );
+
+    let event: EnumCapture = musli::json::from_str(r#"{"3":{}}"#).unwrap();
+    assert_eq!(event, EnumCapture::Variant4);
+}
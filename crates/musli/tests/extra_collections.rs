@@ -0,0 +1,64 @@
+#![cfg(feature = "test")]
+
+use arrayvec::{ArrayString, ArrayVec};
+use indexmap::{IndexMap, IndexSet};
+use musli::{Decode, Encode};
+use smallvec::SmallVec;
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Collections {
+    index_map: IndexMap<String, u32>,
+    index_set: IndexSet<String>,
+    small_vec: SmallVec<[u32; 4]>,
+    #[musli(bytes)]
+    small_bytes: SmallVec<[u8; 8]>,
+    array_vec: ArrayVec<u32, 4>,
+    #[musli(bytes)]
+    array_bytes: ArrayVec<u8, 8>,
+    array_string: ArrayString<16>,
+    hashbrown_map: hashbrown::HashMap<u32, String>,
+    hashbrown_set: hashbrown::HashSet<u32>,
+}
+
+#[test]
+fn collections() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Collections {
+            index_map: IndexMap::from([(String::from("zeta"), 1), (String::from("alpha"), 2)]),
+            index_set: IndexSet::from([String::from("b"), String::from("a")]),
+            small_vec: SmallVec::from_slice(&[1, 2, 3, 4, 5]),
+            small_bytes: SmallVec::from_slice(&[1, 2, 3]),
+            array_vec: ArrayVec::from([1, 2, 3, 4]),
+            array_bytes: ArrayVec::try_from(&[1, 2, 3][..]).unwrap(),
+            array_string: ArrayString::from("Hello").unwrap(),
+            hashbrown_map: hashbrown::HashMap::from([(1, String::from("one"))]),
+            hashbrown_set: hashbrown::HashSet::from([1, 2, 3]),
+        }
+    );
+}
+
+#[test]
+fn index_map_preserves_order() {
+    let mut map = IndexMap::<String, u32>::new();
+    map.insert(String::from("zeta"), 1);
+    map.insert(String::from("alpha"), 2);
+    map.insert(String::from("mu"), 3);
+
+    let json = musli::json::to_string(&map).unwrap();
+    assert_eq!(json, r#"{"zeta":1,"alpha":2,"mu":3}"#);
+
+    let decoded: IndexMap<String, u32> = musli::json::from_str(&json).unwrap();
+    assert!(decoded.keys().eq(["zeta", "alpha", "mu"]));
+}
+
+#[test]
+fn array_vec_capacity() {
+    let bytes = musli::storage::to_vec(&[1u32, 2, 3, 4, 5]).unwrap();
+    let result = musli::storage::from_slice::<ArrayVec<u32, 4>>(&bytes);
+    assert!(result.is_err());
+
+    let bytes = musli::storage::to_vec("Hello World").unwrap();
+    let result = musli::storage::from_slice::<ArrayString<4>>(&bytes);
+    assert!(result.is_err());
+}
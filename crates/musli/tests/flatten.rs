@@ -0,0 +1,201 @@
+#![cfg(feature = "test")]
+
+use std::collections::HashMap;
+
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(flattenable, name_all = "name")]
+pub struct Metadata {
+    created: u64,
+    updated: u64,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(flattenable, name_all = "name")]
+pub struct Document {
+    id: u32,
+    #[musli(flatten)]
+    metadata: Metadata,
+    title: String,
+}
+
+fn document() -> Document {
+    Document {
+        id: 42,
+        metadata: Metadata {
+            created: 1000,
+            updated: 2000,
+        },
+        title: String::from("Hello"),
+    }
+}
+
+#[test]
+fn flatten_struct() {
+    musli::macros::assert_roundtrip_eq!(full, document());
+
+    let json = musli::json::to_string(&document()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"id":42,"created":1000,"updated":2000,"title":"Hello"}"#
+    );
+
+    let decoded: Document =
+        musli::json::from_str(r#"{"updated":2000,"title":"Hello","id":42,"created":1000}"#)
+            .unwrap();
+    assert_eq!(decoded, document());
+
+    assert!(
+        musli::json::from_str::<Document>(r#"{"id":42,"created":1000,"title":"Hello"}"#).is_err()
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Extra {
+    id: u32,
+    #[musli(flatten)]
+    metadata: Metadata,
+    #[musli(flatten)]
+    other: HashMap<String, u32>,
+}
+
+fn extra() -> Extra {
+    let mut other = HashMap::new();
+    other.insert(String::from("a"), 1);
+    other.insert(String::from("b"), 2);
+
+    Extra {
+        id: 7,
+        metadata: Metadata {
+            created: 1,
+            updated: 2,
+        },
+        other,
+    }
+}
+
+#[test]
+fn flatten_remainder() {
+    musli::macros::assert_roundtrip_eq!(descriptive, extra());
+
+    let decoded: Extra =
+        musli::json::from_str(r#"{"a":1,"id":7,"created":1,"b":2,"updated":2}"#).unwrap();
+    assert_eq!(decoded, extra());
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Outer {
+    #[musli(flatten)]
+    document: Document,
+    version: u32,
+}
+
+#[test]
+fn flatten_nested() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Outer {
+            document: document(),
+            version: 3,
+        }
+    );
+
+    let outer = Outer {
+        document: document(),
+        version: 3,
+    };
+
+    let json = musli::json::to_string(&outer).unwrap();
+    assert_eq!(
+        json,
+        r#"{"id":42,"created":1000,"updated":2000,"title":"Hello","version":3}"#
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum Event {
+    #[musli(name_all = "name")]
+    Created {
+        name: String,
+        #[musli(flatten)]
+        metadata: Metadata,
+    },
+    Deleted,
+}
+
+#[test]
+fn flatten_variant() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Event::Created {
+            name: String::from("file"),
+            metadata: Metadata {
+                created: 1,
+                updated: 2,
+            },
+        }
+    );
+    musli::macros::assert_roundtrip_eq!(full, Event::Deleted);
+
+    let event = Event::Created {
+        name: String::from("file"),
+        metadata: Metadata {
+            created: 1,
+            updated: 2,
+        },
+    };
+
+    let json = musli::json::to_string(&event).unwrap();
+    assert_eq!(
+        json,
+        r#"{"Created":{"name":"file","created":1,"updated":2}}"#
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(flattenable)]
+pub struct Header {
+    #[musli(mode = Binary, name = 10)]
+    version: u32,
+    #[musli(mode = Binary, name = 11)]
+    checksum: u64,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Packet {
+    #[musli(mode = Binary, name = 0)]
+    id: u32,
+    #[musli(flatten)]
+    header: Header,
+    #[musli(mode = Binary, name = 1)]
+    payload: Vec<u8>,
+}
+
+fn packet() -> Packet {
+    Packet {
+        id: 1,
+        header: Header {
+            version: 2,
+            checksum: 3,
+        },
+        payload: vec![4, 5, 6],
+    }
+}
+
+#[test]
+fn flatten_binary_names() {
+    musli::macros::assert_roundtrip_eq!(full, packet());
+
+    let bytes = musli::storage::to_vec(&packet()).unwrap();
+    assert_eq!(
+        musli::storage::from_slice::<Packet>(&bytes).unwrap(),
+        packet()
+    );
+
+    let bytes = musli::wire::to_vec(&packet()).unwrap();
+    assert_eq!(musli::wire::from_slice::<Packet>(&bytes).unwrap(), packet());
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "test")]
+
+use std::error::Error;
+use std::fmt;
+
+use musli::context;
+use musli::{Decode, Encode};
+
+#[derive(Debug)]
+pub struct EmptyString;
+
+impl fmt::Display for EmptyString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "String must not be empty")
+    }
+}
+
+impl Error for EmptyString {}
+
+#[derive(Debug, Clone, PartialEq, Encode, Decode)]
+#[musli(try_from = "String", into = "String")]
+pub struct NonEmptyString(String);
+
+impl TryFrom<String> for NonEmptyString {
+    type Error = EmptyString;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        if value.is_empty() {
+            return Err(EmptyString);
+        }
+
+        Ok(Self(value))
+    }
+}
+
+impl From<NonEmptyString> for String {
+    fn from(value: NonEmptyString) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Encode, Decode)]
+#[musli(from = u16, into = u16)]
+pub struct Port(u16);
+
+impl From<u16> for Port {
+    fn from(value: u16) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Port> for u16 {
+    fn from(value: Port) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Server {
+    name: NonEmptyString,
+    port: Port,
+}
+
+#[test]
+fn from_into_roundtrip() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Server {
+            name: NonEmptyString(String::from("localhost")),
+            port: Port(8080),
+        },
+        json = r#"{"name":"localhost","port":8080}"#,
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct NamedServer {
+    name: NonEmptyString,
+    port: Port,
+}
+
+#[test]
+fn try_from_error() {
+    let cx = context::new();
+    let encoding = musli::json::Encoding::new();
+
+    let Err(..) = encoding.from_str_with::<_, NamedServer>(&cx, r#"{"name":"","port":80}"#) else {
+        panic!("Expected decoding to error");
+    };
+
+    let error = cx.errors().next().expect("Expected an error");
+    assert_eq!(
+        error.to_string(),
+        ".name: String must not be empty (at byte 10)"
+    );
+}
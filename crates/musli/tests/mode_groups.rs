@@ -0,0 +1,85 @@
+#![cfg(feature = "test")]
+
+use musli::json::Encoding;
+use musli::{Decode, Encode};
+
+enum Alt {}
+enum Compact {}
+enum Short {}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(modes(Alt, Compact), name_all = "name")]
+#[musli(mode = Short, inherit = Compact)]
+struct Group {
+    #[musli(modes(Alt, Compact), name = "a")]
+    #[musli(mode = Short, name = "s")]
+    alpha: u32,
+    #[musli(modes(Alt, Compact), name = "b")]
+    beta: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(mode = Compact, packed)]
+#[musli(mode = Short, inherit = Compact)]
+struct Point {
+    x: u32,
+    y: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(mode = Alt, inherit = Text)]
+struct Named {
+    x: u32,
+    y: u32,
+}
+
+const TEXT: Encoding = Encoding::new();
+const ALT: Encoding<Alt> = Encoding::new().with_mode();
+const COMPACT: Encoding<Compact> = Encoding::new().with_mode();
+const SHORT: Encoding<Short> = Encoding::new().with_mode();
+
+#[test]
+fn mode_groups() {
+    let value = Group { alpha: 1, beta: 2 };
+
+    let out = TEXT.to_string(&value).unwrap();
+    assert_eq!(out, r#"{"alpha":1,"beta":2}"#);
+
+    let out = ALT.to_string(&value).unwrap();
+    assert_eq!(out, r#"{"a":1,"b":2}"#);
+    assert_eq!(ALT.from_str::<Group>(&out).unwrap(), value);
+
+    let out = COMPACT.to_string(&value).unwrap();
+    assert_eq!(out, r#"{"a":1,"b":2}"#);
+    assert_eq!(COMPACT.from_str::<Group>(&out).unwrap(), value);
+}
+
+#[test]
+fn inherited_field_attributes() {
+    let value = Group { alpha: 1, beta: 2 };
+
+    let out = SHORT.to_string(&value).unwrap();
+    assert_eq!(out, r#"{"s":1,"b":2}"#);
+    assert_eq!(SHORT.from_str::<Group>(&out).unwrap(), value);
+}
+
+#[test]
+fn inherited_container_attributes() {
+    let value = Point { x: 1, y: 2 };
+
+    let out = TEXT.to_string(&value).unwrap();
+    assert_eq!(out, r#"{"x":1,"y":2}"#);
+
+    let out = SHORT.to_string(&value).unwrap();
+    assert_eq!(out, r#"[1,2]"#);
+    assert_eq!(SHORT.from_str::<Point>(&out).unwrap(), value);
+}
+
+#[test]
+fn inherited_default_naming() {
+    let value = Named { x: 1, y: 2 };
+
+    let out = ALT.to_string(&value).unwrap();
+    assert_eq!(out, r#"{"x":1,"y":2}"#);
+    assert_eq!(ALT.from_str::<Named>(&out).unwrap(), value);
+}
@@ -0,0 +1,116 @@
+#![cfg(feature = "test")]
+
+use musli::alloc::Disabled;
+use musli::context::Same;
+use musli::json::Encoding;
+use musli::mode::Text;
+use musli::number::RawNumber;
+use musli::options::{self, Options};
+use musli::value::{Number, Value};
+use musli::{Decode, Encode};
+use rust_decimal::Decimal;
+
+const OPTIONS: Options = options::new().with_raw_numbers(true).build();
+const EXACT: Encoding<Text, OPTIONS> = Encoding::new().with_options();
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Account {
+    id: RawNumber,
+    balance: Decimal,
+}
+
+fn account() -> Account {
+    Account {
+        id: RawNumber::new("340282366920938463463374607431768211456").unwrap(),
+        balance: Decimal::new(1_000_000_000_000_001, 4),
+    }
+}
+
+#[test]
+fn account_roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, account());
+}
+
+#[test]
+fn account_json() {
+    let json = musli::json::to_string(&account()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"id":340282366920938463463374607431768211456,"balance":100000000000.0001}"#
+    );
+
+    let decoded: Account = musli::json::from_str(&json).unwrap();
+    assert_eq!(decoded, account());
+}
+
+#[test]
+fn raw_number_verbatim() {
+    for number in ["0", "-0", "12.50", "1e400", "-1.000000000000000000001E-7"] {
+        let raw: RawNumber = musli::json::from_str(number).unwrap();
+        assert_eq!(raw.as_str(), number);
+        assert_eq!(musli::json::to_string(&raw).unwrap(), number);
+    }
+
+    let raw: RawNumber = musli::json::from_str(r#""12.5""#).unwrap();
+    assert_eq!(raw.as_str(), "12.5");
+
+    assert!(musli::json::from_str::<RawNumber>(r#""hello""#).is_err());
+    assert!(RawNumber::new("01").is_err());
+    assert!(RawNumber::new("1.e5").is_err());
+}
+
+#[test]
+fn value_float() {
+    let value: Value = musli::json::from_str("1.5").unwrap();
+    assert_eq!(value, Value::Number(Number::F64(1.5)));
+
+    let bytes = musli::storage::to_vec(&value).unwrap();
+    assert_eq!(musli::storage::from_slice::<f64>(&bytes).unwrap(), 1.5);
+
+    let bytes = musli::descriptive::to_vec(&value).unwrap();
+    let value: Value = musli::descriptive::from_slice(&bytes).unwrap();
+    assert_eq!(value, Value::Number(Number::F64(1.5)));
+}
+
+#[test]
+fn value_no_alloc() {
+    let cx = Same::<Text, musli::json::Error, _>::with_alloc(Disabled::new());
+
+    let value: Value = musli::json::DEFAULT.from_str_with(&cx, "42").unwrap();
+    assert_eq!(value, Value::Number(Number::U8(42)));
+
+    let value: Value = musli::json::DEFAULT.from_str_with(&cx, "-1.25").unwrap();
+    assert_eq!(value, Value::Number(Number::F64(-1.25)));
+}
+
+#[test]
+fn value_raw_numbers() {
+    let json = r#"[1.10,18446744073709551616000000000000000000000,-5]"#;
+    let value: Value = EXACT.from_str(json).unwrap();
+
+    let Value::Sequence(values) = &value else {
+        panic!("expected sequence, got {value:?}");
+    };
+
+    assert_eq!(values[0], Value::RawNumber(RawNumber::new("1.10").unwrap()));
+    assert_eq!(
+        values[1],
+        Value::RawNumber(RawNumber::new("18446744073709551616000000000000000000000").unwrap())
+    );
+    assert_eq!(values[2], Value::Number(Number::I8(-5)));
+
+    assert_eq!(musli::json::to_string(&value).unwrap(), json);
+
+    let float: f64 = musli::value::decode(&values[0]).unwrap();
+    assert_eq!(float, 1.1);
+
+    let decimal: Decimal = musli::json::from_str("1.10").unwrap();
+    assert_eq!(decimal.to_string(), "1.10");
+}
+
+#[test]
+fn decimal_binary() {
+    let decimal = Decimal::new(-123456789, 3);
+    let bytes = musli::storage::to_vec(&decimal).unwrap();
+    assert_eq!(bytes, decimal.serialize());
+}
@@ -0,0 +1,98 @@
+#![cfg(feature = "test")]
+
+use musli::mode::{Binary, Text};
+use musli::{Decode, Encode};
+
+mod other {
+    #[derive(Debug, PartialEq)]
+    pub struct Duration {
+        pub secs: u64,
+        pub nanos: u32,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub enum Shape {
+        Circle { radius: f32 },
+        Rectangle(f32, f32),
+        Empty,
+    }
+
+    #[derive(Debug, PartialEq)]
+    pub struct Pair<T> {
+        pub first: T,
+        pub second: T,
+    }
+}
+
+#[derive(Encode, Decode)]
+#[musli(remote = other::Duration)]
+#[allow(dead_code)]
+struct DurationDef {
+    secs: u64,
+    nanos: u32,
+}
+
+#[derive(Encode, Decode)]
+#[musli(remote = other::Shape)]
+#[allow(dead_code)]
+enum ShapeDef {
+    Circle { radius: f32 },
+    Rectangle(f32, f32),
+    Empty,
+}
+
+#[derive(Encode, Decode)]
+#[musli(remote = other::Pair<T>)]
+#[musli(mode = Binary, bound = {T: Encode<Binary>}, decode_bound = {T: Decode<'de, Binary>})]
+#[musli(mode = Text, bound = {T: Encode<Text>}, decode_bound = {T: Decode<'de, Text>})]
+#[allow(dead_code)]
+struct PairDef<T> {
+    first: T,
+    second: T,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Process {
+    #[musli(with = DurationDef)]
+    timeout: other::Duration,
+    #[musli(with = ShapeDef)]
+    shape: other::Shape,
+    #[musli(with = ShapeDef)]
+    other_shape: other::Shape,
+    #[musli(with = PairDef)]
+    pair: other::Pair<String>,
+}
+
+#[test]
+fn remote() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Process {
+            timeout: other::Duration { secs: 10, nanos: 5 },
+            shape: other::Shape::Circle { radius: 1.5 },
+            other_shape: other::Shape::Rectangle(1.0, 2.0),
+            pair: other::Pair {
+                first: String::from("a"),
+                second: String::from("b"),
+            },
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Named {
+    #[musli(with = DurationDef)]
+    timeout: other::Duration,
+}
+
+#[test]
+fn remote_json() {
+    let value = Named {
+        timeout: other::Duration { secs: 1, nanos: 2 },
+    };
+
+    let json = musli::json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"timeout":{"secs":1,"nanos":2}}"#);
+    assert_eq!(musli::json::from_str::<Named>(&json).unwrap(), value);
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "test")]
+
+use bytes::{Bytes, BytesMut};
+use bytestring::ByteString;
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Frame {
+    id: u32,
+    payload: Bytes,
+    name: ByteString,
+    scratch: BytesMut,
+}
+
+fn frame() -> Frame {
+    Frame {
+        id: 42,
+        payload: Bytes::from_static(b"Hello World"),
+        name: ByteString::from_static("frame"),
+        scratch: BytesMut::from(&b"scratch"[..]),
+    }
+}
+
+fn assert_shared(shared: &Bytes, frame: &Frame) {
+    let range = shared.as_ptr_range();
+    assert!(range.contains(&frame.payload.as_ptr()));
+    assert!(range.contains(&frame.name.as_bytes().as_ptr()));
+    assert!(!range.contains(&frame.scratch.as_ptr()));
+}
+
+#[test]
+fn roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, frame());
+}
+
+#[test]
+fn storage_zero_copy() {
+    let shared = Bytes::from(musli::storage::to_vec(&frame()).unwrap());
+    let decoded: Frame = musli::storage::decode(&shared).unwrap();
+    assert_eq!(decoded, frame());
+    assert_shared(&shared, &decoded);
+}
+
+#[test]
+fn wire_zero_copy() {
+    let shared = Bytes::from(musli::wire::to_vec(&frame()).unwrap());
+    let decoded: Frame = musli::wire::decode(&shared).unwrap();
+    assert_eq!(decoded, frame());
+    assert_shared(&shared, &decoded);
+}
+
+#[test]
+fn descriptive_zero_copy() {
+    let shared = Bytes::from(musli::descriptive::to_vec(&frame()).unwrap());
+    let decoded: Frame = musli::descriptive::decode(&shared).unwrap();
+    assert_eq!(decoded, frame());
+    assert_shared(&shared, &decoded);
+}
+
+#[test]
+fn bytes_mut_writer() {
+    let mut out = BytesMut::new();
+    musli::storage::encode(&mut out, &frame()).unwrap();
+    assert_eq!(out, musli::storage::to_vec(&frame()).unwrap());
+
+    let decoded: Frame = musli::storage::decode(&out.freeze()).unwrap();
+    assert_eq!(decoded, frame());
+}
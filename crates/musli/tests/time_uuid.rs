@@ -0,0 +1,71 @@
+#![cfg(feature = "test")]
+
+use chrono::{DateTime, FixedOffset, Utc};
+use musli::{Decode, Encode};
+use uuid::Uuid;
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Event {
+    id: Uuid,
+    chrono_utc: DateTime<Utc>,
+    chrono_fixed: DateTime<FixedOffset>,
+    time: time::OffsetDateTime,
+    jiff: jiff::Timestamp,
+}
+
+fn event() -> Event {
+    Event {
+        id: Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8),
+        chrono_utc: DateTime::from_timestamp(1_700_000_000, 123_456_789).unwrap(),
+        chrono_fixed: DateTime::parse_from_rfc3339("2023-11-14T22:13:20.5+02:00").unwrap(),
+        time: time::macros::datetime!(2023-11-14 22:13:20.5 +2),
+        jiff: jiff::Timestamp::new(-1_700_000_000, -123_456_789).unwrap(),
+    }
+}
+
+#[test]
+fn event_roundtrip() {
+    musli::macros::assert_roundtrip_eq!(full, event());
+}
+
+#[test]
+fn event_json() {
+    let json = musli::json::to_string(&event()).unwrap();
+
+    assert_eq!(
+        json,
+        r#"{"id":"67e55044-10b1-426f-9247-bb680e5fe0c8","chrono_utc":"2023-11-14T22:13:20.123456789Z","chrono_fixed":"2023-11-14T22:13:20.500+02:00","time":"2023-11-14T22:13:20.5+02:00","jiff":"1916-02-18T01:46:39.876543211Z"}"#
+    );
+}
+
+#[test]
+fn uuid_binary() {
+    let id = Uuid::from_u128(0x67e55044_10b1_426f_9247_bb680e5fe0c8);
+    let bytes = musli::storage::to_vec(&id).unwrap();
+    assert_eq!(bytes.len(), 16);
+    assert_eq!(bytes, id.as_bytes());
+}
+
+#[test]
+fn time_rfc3339() {
+    use time::macros::datetime;
+
+    let cases = [
+        (datetime!(2023-11-14 22:13:20 UTC), r#""2023-11-14T22:13:20Z""#),
+        (
+            datetime!(0001-01-01 00:00:00.000_001 -05:30),
+            r#""0001-01-01T00:00:00.000001-05:30""#,
+        ),
+        (
+            datetime!(9999-12-31 23:59:59.999_999_999 +23:59),
+            r#""9999-12-31T23:59:59.999999999+23:59""#,
+        ),
+    ];
+
+    for (date_time, expected) in cases {
+        assert_eq!(musli::json::to_string(&date_time).unwrap(), expected);
+        musli::macros::assert_roundtrip_eq!(full, date_time);
+    }
+
+    assert!(musli::json::to_string(&datetime!(2023-11-14 22:13:20 +01:00:30)).is_err());
+}
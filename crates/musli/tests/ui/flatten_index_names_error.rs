@@ -0,0 +1,17 @@
+use musli::{Encode, Decode};
+
+#[derive(Encode, Decode)]
+#[musli(flattenable)]
+struct Metadata {
+    created: u64,
+}
+
+#[derive(Encode, Decode)]
+struct Document {
+    id: u32,
+    #[musli(flatten)]
+    metadata: Metadata,
+}
+
+fn main() {
+}
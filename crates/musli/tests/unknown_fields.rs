@@ -0,0 +1,113 @@
+#![cfg(feature = "test")]
+
+use musli::value::{UnknownFields, Value};
+use musli::{Decode, Encode};
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct PersonV2 {
+    name: String,
+    age: u32,
+    email: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct PersonV1 {
+    name: String,
+    #[musli(unknown_fields)]
+    unknown: UnknownFields,
+}
+
+fn person() -> PersonV2 {
+    PersonV2 {
+        name: String::from("Aristotle"),
+        age: 61,
+        email: String::from("aristotle@example.com"),
+    }
+}
+
+macro_rules! proxy {
+    ($module:ident) => {{
+        let bytes = musli::$module::to_vec(&person()).unwrap();
+        let mut proxy: PersonV1 = musli::$module::from_slice(&bytes).unwrap();
+        assert_eq!(proxy.unknown.len(), 2);
+        proxy.name = String::from("Plato");
+
+        let bytes = musli::$module::to_vec(&proxy).unwrap();
+        let decoded: PersonV2 = musli::$module::from_slice(&bytes).unwrap();
+
+        assert_eq!(
+            decoded,
+            PersonV2 {
+                name: String::from("Plato"),
+                ..person()
+            }
+        );
+    }};
+}
+
+#[test]
+fn unknown_fields_roundtrip() {
+    proxy!(descriptive);
+    proxy!(json);
+    proxy!(wire);
+}
+
+#[test]
+fn unknown_fields_wire_unchanged() {
+    let bytes = musli::wire::to_vec(&person()).unwrap();
+    let proxy: PersonV1 = musli::wire::from_slice(&bytes).unwrap();
+    assert_eq!(musli::wire::to_vec(&proxy).unwrap(), bytes);
+}
+
+#[test]
+fn unknown_fields_storage() {
+    let bytes = musli::storage::to_vec(&person()).unwrap();
+    let error = musli::storage::from_slice::<PersonV1>(&bytes).unwrap_err();
+    assert!(
+        error.to_string().contains("not self-descriptive"),
+        "{error}"
+    );
+}
+
+#[test]
+fn unknown_fields_json() {
+    let json = r#"{"name":"Aristotle","age":61,"email":"aristotle@example.com"}"#;
+
+    let proxy: PersonV1 = musli::json::from_str(json).unwrap();
+    assert_eq!(
+        proxy.unknown.get(&Value::String(String::from("email"))),
+        Some(&Value::String(String::from("aristotle@example.com")))
+    );
+    assert_eq!(musli::json::to_string(&proxy).unwrap(), json);
+
+    let proxy: PersonV1 = musli::json::from_str(r#"{"name":"Aristotle"}"#).unwrap();
+    assert!(proxy.unknown.is_empty());
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(flattenable, name_all = "name")]
+pub struct Details {
+    age: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Mixed {
+    name: String,
+    #[musli(unknown_fields)]
+    unknown: UnknownFields,
+    #[musli(flatten)]
+    details: Details,
+}
+
+#[test]
+fn unknown_fields_after_flatten() {
+    let json = r#"{"name":"Aristotle","age":61,"email":"aristotle@example.com"}"#;
+
+    let mixed: Mixed = musli::json::from_str(json).unwrap();
+    assert_eq!(mixed.details, Details { age: 61 });
+    assert_eq!(mixed.unknown.len(), 1);
+
+    let json = musli::json::to_string(&mixed).unwrap();
+    assert_eq!(musli::json::from_str::<PersonV2>(&json).unwrap(), person());
+}
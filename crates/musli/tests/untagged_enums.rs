@@ -85,3 +85,107 @@ fn untagged_enums() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(untagged)]
+pub enum StringOrObject {
+    #[musli(transparent)]
+    Integer(u32),
+    #[musli(transparent)]
+    String(String),
+    #[musli(name_all = "name")]
+    Object { name: String, count: u32 },
+}
+
+#[test]
+fn untagged_decode() {
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        StringOrObject::Integer(42),
+        json = r#"42"#,
+    };
+
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        StringOrObject::String(String::from("Hello")),
+        json = r#""Hello""#,
+    };
+
+    musli::macros::assert_roundtrip_eq! {
+        descriptive,
+        StringOrObject::Object {
+            name: String::from("Hello"),
+            count: 42,
+        },
+        json = r#"{"name":"Hello","count":42}"#,
+    };
+}
+
+#[test]
+fn untagged_mismatch() {
+    let error = musli::json::from_str::<StringOrObject>("[1, 2]").unwrap_err();
+    let error = error.to_string();
+
+    assert!(
+        error.starts_with("Data did not match any variant of untagged enum StringOrObject"),
+        "{error}"
+    );
+    assert!(error.contains("variant `Integer`"), "{error}");
+    assert!(error.contains("variant `String`"), "{error}");
+    assert!(error.contains("variant `Object`"), "{error}");
+}
+
+#[test]
+fn untagged_default_context() {
+    let cx = musli::context::new();
+    let encoding = musli::json::Encoding::new();
+
+    let value = encoding
+        .from_str_with::<_, StringOrObject>(&cx, r#"{"name":"Hello","count":42}"#)
+        .unwrap();
+
+    assert_eq!(
+        value,
+        StringOrObject::Object {
+            name: String::from("Hello"),
+            count: 42,
+        }
+    );
+
+    assert!(cx.errors().next().is_none(), "{}", cx.report());
+
+    let Err(..) = encoding.from_str_with::<_, StringOrObject>(&cx, "[1, 2]") else {
+        panic!("Expected decoding to error");
+    };
+
+    let errors = cx.errors().map(|e| e.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(
+        errors,
+        [concat!(
+            ": Data did not match any variant of untagged enum StringOrObject: ",
+            "variant `Integer`: Value buffer expected u32, but found sequence with 2 items, ",
+            "variant `String`: Value buffer expected string, but found sequence with 2 items, ",
+            "variant `Object`: Value buffer expected map, but found sequence with 2 items ",
+            "(at byte 6)"
+        )]
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(untagged)]
+pub enum WithFallback {
+    #[musli(transparent)]
+    Integer(u32),
+    #[musli(default)]
+    Unknown,
+}
+
+#[test]
+fn untagged_fallback() {
+    let value: WithFallback = musli::json::from_str("42").unwrap();
+    assert_eq!(value, WithFallback::Integer(42));
+
+    let value: WithFallback = musli::json::from_str(r#"{"a":[1,2,3]}"#).unwrap();
+    assert_eq!(value, WithFallback::Unknown);
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "test")]
+
+use musli::context;
+use musli::{Context, Decode, Encode};
+
+fn non_empty<C>(_: &C, value: &str) -> Result<(), &'static str>
+where
+    C: ?Sized + Context,
+{
+    if value.is_empty() {
+        return Err("String must not be empty");
+    }
+
+    Ok(())
+}
+
+fn ordered<C>(_: &C, range: &Range) -> Result<(), String>
+where
+    C: ?Sized + Context,
+{
+    if range.start > range.end {
+        return Err(format!("Start {} is after end {}", range.start, range.end));
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name", validate = ordered)]
+pub struct Range {
+    start: u32,
+    end: u32,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Server {
+    #[musli(validate = non_empty)]
+    name: String,
+    range: Range,
+}
+
+fn decode_error(json: &str) -> String {
+    let cx = context::new();
+    let encoding = musli::json::Encoding::new();
+
+    let Err(..) = encoding.from_str_with::<_, Server>(&cx, json) else {
+        panic!("Expected decoding to error");
+    };
+
+    let error = cx.errors().next().expect("Expected an error");
+    error.to_string()
+}
+
+#[test]
+fn validate_roundtrip() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Server {
+            name: String::from("localhost"),
+            range: Range { start: 1, end: 2 },
+        }
+    );
+}
+
+#[test]
+fn validate_field() {
+    assert_eq!(
+        decode_error(r#"{"name":"","range":{"start":1,"end":2}}"#),
+        ".name: String must not be empty (at bytes 8-10)"
+    );
+}
+
+#[test]
+fn validate_container() {
+    assert_eq!(
+        decode_error(r#"{"name":"a","range":{"start":2,"end":1}}"#),
+        ".range: Start 2 is after end 1 (at bytes 20-39)"
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(packed)]
+pub struct Packed {
+    #[musli(validate = non_empty)]
+    name: String,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub enum Enum {
+    Variant {
+        #[musli(validate = non_empty)]
+        name: String,
+    },
+}
+
+#[test]
+fn validate_other_containers() {
+    let bytes = musli::storage::to_vec(&Packed {
+        name: String::new(),
+    })
+    .unwrap();
+
+    let error = musli::storage::from_slice::<Packed>(&bytes)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("String must not be empty"), "{error}");
+
+    let bytes = musli::wire::to_vec(&Enum::Variant {
+        name: String::new(),
+    })
+    .unwrap();
+
+    let error = musli::wire::from_slice::<Enum>(&bytes)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("String must not be empty"), "{error}");
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub enum Event {
+    #[musli(name_all = "name")]
+    Created { id: u32 },
+    #[musli(default)]
+    Unknown(String, #[musli(validate = non_empty)] String),
+}
+
+#[test]
+fn validate_fallback_content() {
+    let event = musli::json::from_str::<Event>(r#"{"Renamed":"new"}"#).unwrap();
+    assert_eq!(
+        event,
+        Event::Unknown(String::from("Renamed"), String::from("new"))
+    );
+
+    let error = musli::json::from_str::<Event>(r#"{"Renamed":""}"#)
+        .unwrap_err()
+        .to_string();
+    assert!(error.contains("String must not be empty"), "{error}");
+}
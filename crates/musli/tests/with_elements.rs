@@ -0,0 +1,159 @@
+#![cfg(feature = "test")]
+
+use std::collections::{BTreeMap, HashSet, VecDeque};
+
+use musli::mode::{Binary, Text};
+use musli::{Decode, Encode};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CustomUuid(u128);
+
+mod custom_uuid {
+    use musli::{Decoder, Encoder};
+
+    use super::CustomUuid;
+
+    pub fn encode<E>(uuid: &CustomUuid, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder,
+    {
+        encoder.encode_u128(uuid.0)
+    }
+
+    pub fn decode<'de, D>(_: &D::Cx, decoder: D) -> Result<CustomUuid, D::Error>
+    where
+        D: Decoder<'de>,
+    {
+        Ok(CustomUuid(decoder.decode_u128()?))
+    }
+}
+
+mod as_string {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use musli::{Context, Decoder, Encoder};
+
+    pub fn encode<E, T>(value: &T, _: &E::Cx, encoder: E) -> Result<E::Ok, E::Error>
+    where
+        E: Encoder,
+        T: Display,
+    {
+        encoder.collect_string(value)
+    }
+
+    pub fn decode<'de, D, T>(cx: &D::Cx, decoder: D) -> Result<T, D::Error>
+    where
+        D: Decoder<'de>,
+        T: FromStr,
+    {
+        decoder.decode_unsized(|string: &str| {
+            string
+                .parse()
+                .map_err(|_| cx.message(format_args!("Invalid number {string:?}")))
+        })
+    }
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Elements {
+    #[musli(with_elements = self::custom_uuid)]
+    ids: Vec<CustomUuid>,
+    #[musli(with_elements = self::custom_uuid)]
+    queue: VecDeque<CustomUuid>,
+    #[musli(with_elements = self::custom_uuid)]
+    set: HashSet<CustomUuid>,
+    #[musli(with_elements = self::custom_uuid)]
+    parent: Option<CustomUuid>,
+    #[musli(with_elements = self::custom_uuid)]
+    missing: Option<CustomUuid>,
+}
+
+#[test]
+fn with_elements() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Elements {
+            ids: vec![CustomUuid(1), CustomUuid(u128::MAX)],
+            queue: VecDeque::from([CustomUuid(2)]),
+            set: HashSet::from([CustomUuid(3)]),
+            parent: Some(CustomUuid(4)),
+            missing: None,
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub struct Entries {
+    #[musli(with_keys = self::custom_uuid)]
+    keys: BTreeMap<CustomUuid, String>,
+    #[musli(with_values = self::custom_uuid)]
+    values: BTreeMap<String, CustomUuid>,
+    #[musli(with_keys = self::as_string, with_values = self::custom_uuid)]
+    both: BTreeMap<u32, CustomUuid>,
+}
+
+#[test]
+fn with_entries() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Entries {
+            keys: BTreeMap::from([(CustomUuid(1), String::from("one"))]),
+            values: BTreeMap::from([(String::from("two"), CustomUuid(2))]),
+            both: BTreeMap::from([(3, CustomUuid(3)), (4, CustomUuid(4))]),
+        }
+    );
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(name_all = "name")]
+pub struct Strings {
+    #[musli(with_elements = self::as_string)]
+    numbers: Vec<u64>,
+}
+
+#[test]
+fn with_elements_json() {
+    let value = Strings {
+        numbers: vec![1, 18446744073709551615],
+    };
+
+    let json = musli::json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"numbers":["1","18446744073709551615"]}"#);
+    assert_eq!(musli::json::from_str::<Strings>(&json).unwrap(), value);
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+#[musli(mode = Binary, bound = {T: Encode<Binary>}, decode_bound = {T: Decode<'de, Binary>})]
+#[musli(mode = Text, bound = {T: Encode<Text>}, decode_bound = {T: Decode<'de, Text>})]
+pub struct Generic<T> {
+    #[musli(with_elements = self::as_string)]
+    numbers: Vec<u32>,
+    values: Vec<T>,
+}
+
+#[derive(Debug, PartialEq, Encode, Decode)]
+pub enum Enum {
+    Variant {
+        #[musli(with_elements = self::custom_uuid)]
+        ids: Vec<CustomUuid>,
+    },
+}
+
+#[test]
+fn with_elements_generic() {
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Generic {
+            numbers: vec![1, 2],
+            values: vec![String::from("a")],
+        }
+    );
+
+    musli::macros::assert_roundtrip_eq!(
+        full,
+        Enum::Variant {
+            ids: vec![CustomUuid(1)],
+        }
+    );
+}